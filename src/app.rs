@@ -1,18 +1,39 @@
 pub mod address;
 pub mod block;
+pub mod block_transactions;
+pub mod chain_store;
+pub mod dashboard;
 pub mod event_handling;
+pub mod filter;
+pub mod logs;
+pub mod simulation;
 pub mod statistics;
 pub mod transaction;
 use crate::{
-    ethers::types::{BlockWithTransactionReceipts, ERC20Token, TransactionWithReceipt},
+    beacon::ValidatorId,
+    ethers::{
+        fee_history::{DEFAULT_BLOCK_COUNT, MAX_BLOCK_COUNT},
+        types::{BlockWithTransactionReceipts, ERC20Token, TransactionWithReceipt},
+        withdrawals::WithdrawalScan,
+    },
     network::IoEvent,
     route::{ActiveBlock, Route, RouteId},
-    widget::StatefulList,
+    widget::{Form, StatefulList},
 };
-use ethers::core::types::{Address, NameOrAddress, Transaction, TransactionReceipt, TxHash, U64};
+use chain_store::ChainStore;
+use dashboard::Dashboard;
+use ethers::core::types::{
+    Address, Block, NameOrAddress, Transaction, TransactionReceipt, TxHash, U64,
+};
+use filter::{filter_indices, FilterContext, FilterState, FilterTarget, TransactionRow};
 use ratatui::widgets::{ListState, ScrollbarState, TableState};
 use statistics::Statistics;
-use std::{collections::HashMap, fs::File, io::Read, sync::mpsc::Sender};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::Read,
+    sync::mpsc::Sender,
+};
 
 pub enum InputMode {
     Normal,
@@ -27,6 +48,7 @@ pub struct App {
     pub is_toggled: bool,
     pub show_popup: bool,
     pub statistics: Statistics,
+    pub dashboard: Dashboard,
     pub latest_blocks: Option<StatefulList<BlockWithTransactionReceipts<Transaction>>>,
     pub latest_transactions: Option<StatefulList<TransactionWithReceipt>>,
     pub address2ens_id: HashMap<Address, Option<String>>,
@@ -38,26 +60,52 @@ pub struct App {
     //Block Detail
     pub block_detail_list_state: ListState,
     pub transactions_table_state: TableState,
+    pub block_transactions: block_transactions::BlockTransactionsView,
     pub withdrawals_table_state: TableState,
+    pub epoch_table_state: TableState,
+    pub withdrawal_scan_table_state: TableState,
+    pub validator_withdrawals_table_state: TableState,
+    pub chain_store: ChainStore,
+    pub reorgs_table_state: TableState,
     //Address Detail
     pub contract_list_state: ListState,
     pub source_code_scroll_state: ScrollbarState,
     pub source_code_scroll: u16,
+    pub source_code: address::SourceCodeState,
     pub abi_scroll_state: ScrollbarState,
     pub abi_scroll: u16,
+    pub contract_call: address::ContractCallState,
+    pub bytecode: address::BytecodeState,
+    pub storage: address::StorageState,
     //Transaction Detail
     pub transaction_detail_list_state: ListState,
+    pub is_access_list_expanded: bool,
     pub input_data_detail_list_state: ListState,
     pub input_data_scroll_state: ScrollbarState,
     pub input_data_scroll: u16,
     pub decoded_input_data_scroll_state: ScrollbarState,
     pub decoded_input_data_scroll: u16,
+    pub state_changes_scroll_state: ScrollbarState,
+    pub state_changes_scroll: u16,
+    pub blobs_scroll_state: ScrollbarState,
+    pub blobs_scroll: u16,
+    //Call Trace
+    pub call_trace_list_state: ListState,
+    pub collapsed_call_trace_nodes: HashSet<Vec<usize>>,
+    //Simulation
+    pub simulation: simulation::SimulationState,
+    //Logs
+    pub logs: logs::LogsState,
+    //Filters
+    pub filter: FilterState,
     //Token Data
     pub erc20_tokens: Vec<ERC20Token>,
+    /// Function signatures looked up for the selectors of the listed transactions.
+    pub signatures: HashMap<[u8; 4], Vec<String>>,
 }
 
 impl App {
-    pub fn new(io_tx: Sender<IoEvent>, endpoint: &str) -> App {
+    pub fn new(io_tx: Sender<IoEvent>, endpoint: &str, dashboard: Dashboard) -> App {
         let erc20_tokens = File::open("./data/tokens.json").map_or(vec![], |file| {
             let mut buffer = String::new();
             let mut file = std::io::BufReader::new(file);
@@ -78,6 +126,7 @@ impl App {
             show_popup: false,
             io_tx: Some(io_tx),
             statistics: Statistics::new(),
+            dashboard,
             latest_blocks: None,
             latest_transactions: None,
             address2ens_id: HashMap::new(),
@@ -87,7 +136,13 @@ impl App {
             //Block Detail
             block_detail_list_state: ListState::default(),
             transactions_table_state: TableState::default(),
+            block_transactions: block_transactions::BlockTransactionsView::default(),
             withdrawals_table_state: TableState::default(),
+            epoch_table_state: TableState::default(),
+            withdrawal_scan_table_state: TableState::default(),
+            validator_withdrawals_table_state: TableState::default(),
+            chain_store: ChainStore::default(),
+            reorgs_table_state: TableState::default(),
             //Address Detail
             contract_list_state: ListState::default().with_selected(Some(
                 address::SelectableContractDetailItem::ContractSourceCode.into(),
@@ -95,16 +150,35 @@ impl App {
             source_code_scroll_state: ScrollbarState::default(),
             abi_scroll_state: ScrollbarState::default(),
             source_code_scroll: 0,
+            source_code: address::SourceCodeState::default(),
             abi_scroll: 0,
+            contract_call: address::ContractCallState::default(),
+            bytecode: address::BytecodeState::default(),
+            storage: address::StorageState::default(),
             //Transaction Detail
             transaction_detail_list_state: ListState::default(),
+            is_access_list_expanded: false,
             input_data_detail_list_state: ListState::default(),
             input_data_scroll_state: ScrollbarState::default(),
             input_data_scroll: 0,
             decoded_input_data_scroll_state: ScrollbarState::default(),
             decoded_input_data_scroll: 0,
+            state_changes_scroll_state: ScrollbarState::default(),
+            state_changes_scroll: 0,
+            blobs_scroll_state: ScrollbarState::default(),
+            blobs_scroll: 0,
+            //Call Trace
+            call_trace_list_state: ListState::default(),
+            collapsed_call_trace_nodes: HashSet::new(),
+            //Simulation
+            simulation: simulation::SimulationState::default(),
+            //Logs
+            logs: logs::LogsState::default(),
+            //Filters
+            filter: FilterState::default(),
             //Token Data
             erc20_tokens,
+            signatures: HashMap::new(),
         }
     }
 
@@ -131,6 +205,134 @@ impl App {
             .push(Route::new(current_route.get_id(), active_block));
     }
 
+    /// Returns the form of the current route, if any, so that key events can be forwarded to it.
+    pub fn get_active_form_mut(&mut self) -> Option<&mut Form> {
+        if self.filter.bar.is_some() {
+            return self.filter.bar.as_mut().map(|(_, bar)| bar);
+        }
+        match self.get_current_route().get_id() {
+            RouteId::AddressInfo(_) => match self
+                .contract_list_state
+                .selected()
+                .map(address::SelectableContractDetailItem::from)
+            {
+                Some(address::SelectableContractDetailItem::ContractSourceCode) => {
+                    self.source_code.search.as_mut()
+                }
+                Some(address::SelectableContractDetailItem::ReadContract) => {
+                    self.contract_call.form.as_mut()
+                }
+                Some(address::SelectableContractDetailItem::Storage) => {
+                    Some(&mut self.storage.form)
+                }
+                _ => None,
+            },
+            RouteId::Simulation => Some(&mut self.simulation.form),
+            RouteId::Logs => Some(&mut self.logs.form),
+            _ => None,
+        }
+    }
+
+    /// The table that `/` filters: the latest transactions when they are focused, or the table
+    /// of the current route.
+    pub fn filter_target(&self) -> Option<FilterTarget> {
+        let route = self.get_current_route();
+        match route.get_active_block() {
+            ActiveBlock::LatestTransactions => Some(FilterTarget::LatestTransactions),
+            ActiveBlock::Main => match route.get_id() {
+                RouteId::TransactionsOfBlock(Some(_)) => Some(FilterTarget::BlockTransactions),
+                RouteId::WithdrawalsOfBlock(Some(_)) => Some(FilterTarget::BlockWithdrawals),
+                RouteId::Withdrawals(Some(_)) => Some(FilterTarget::Withdrawals),
+                RouteId::Logs => Some(FilterTarget::Logs),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Applies the text of the filter bar to its table and moves the cursor back to the top.
+    pub fn submit_filter(&mut self) {
+        let abis = self
+            .routes
+            .iter()
+            .filter_map(|route| match route.get_id() {
+                RouteId::AddressInfo(Some(address_info)) => address_info.contract_abi,
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let context = FilterContext {
+            erc20_tokens: &self.erc20_tokens,
+            address2ens_id: &self.address2ens_id,
+            abis: abis.iter().collect(),
+            signatures: &self.signatures,
+        };
+        self.filter.submit(&context);
+        if self.filter.bar.is_some() {
+            return;
+        }
+
+        self.apply_filters();
+        self.transactions_table_state.select(None);
+        self.withdrawals_table_state.select(None);
+        self.withdrawal_scan_table_state.select(None);
+        self.logs.page = 0;
+        self.logs.table_state.select(None);
+    }
+
+    /// Updates the rows shown by the lists that keep their filtered rows, after the filters or
+    /// the rows change.
+    pub fn apply_filters(&mut self) {
+        if let Some(latest_transactions) = self.latest_transactions.as_mut() {
+            let visible = self
+                .filter
+                .get(FilterTarget::LatestTransactions)
+                .map(|filter| {
+                    latest_transactions
+                        .items
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, transaction)| {
+                            filter.matches(&TransactionRow {
+                                transaction: &transaction.transaction,
+                                receipt: Some(&transaction.transaction_receipt),
+                                base_fee_per_gas: transaction.base_fee_per_gas,
+                            })
+                        })
+                        .map(|(i, _)| i)
+                        .collect()
+                });
+            latest_transactions.set_visible(visible);
+        }
+
+        let visible = match (
+            self.filter.get(FilterTarget::Logs),
+            self.logs.result.as_ref(),
+        ) {
+            (Some(filter), Some(Ok(result))) => Some(filter_indices(&result.logs, Some(filter))),
+            _ => None,
+        };
+        self.logs.visible = visible;
+    }
+
+    /// Indices of the withdrawals of `block` that match the filter.
+    pub fn block_withdrawal_indices(&self, block: &Block<Transaction>) -> Vec<usize> {
+        block.withdrawals.as_ref().map_or(vec![], |withdrawals| {
+            filter_indices(withdrawals, self.filter.get(FilterTarget::BlockWithdrawals))
+        })
+    }
+
+    /// The scan with only the withdrawals that match the filter, so that the totals and the
+    /// validators are counted over them.
+    pub fn filter_withdrawal_scan(&self, withdrawal_scan: &WithdrawalScan) -> WithdrawalScan {
+        let mut withdrawal_scan = withdrawal_scan.to_owned();
+        if let Some(filter) = self.filter.get(FilterTarget::Withdrawals) {
+            withdrawal_scan
+                .withdrawals
+                .retain(|withdrawal| filter.matches(withdrawal));
+        }
+        withdrawal_scan
+    }
+
     pub fn update_block_with_transaction_receipts(
         &mut self,
         transaction_receipts: Vec<TransactionReceipt>,
@@ -243,17 +445,76 @@ impl App {
         self.cursor_position = 0;
     }
 
-    pub fn submit_message(&mut self) -> String {
+    /// Returns the message to show while searching, or `None` if the input opened a screen by itself.
+    pub fn submit_message(&mut self) -> Option<String> {
+        let route_id = match self.input.trim() {
+            "simulate" => Some(RouteId::Simulation),
+            "reorgs" => Some(RouteId::Reorgs),
+            "logs" => Some(RouteId::Logs),
+            _ => None,
+        };
+
+        if let Some(route_id) = route_id {
+            self.set_route(Route::new(route_id, ActiveBlock::Main));
+            self.input.clear();
+            self.reset_cursor();
+            return None;
+        }
+
         if let Some(token) = ERC20Token::find_by_ticker(&self.erc20_tokens, &self.input) {
             self.dispatch(IoEvent::GetNameOrAddressInfo {
                 name_or_address: NameOrAddress::Address(token.contract_address),
                 is_searching: true,
             })
+        } else if let Some(id) = ValidatorId::parse_pubkey(&self.input).or_else(|| {
+            self.input
+                .strip_prefix("validator")
+                .and_then(|index| index.trim().parse::<u64>().ok())
+                .map(ValidatorId::Index)
+        }) {
+            self.dispatch(IoEvent::GetValidator {
+                id,
+                is_searching: true,
+            });
+        } else if let Some(epoch) = self
+            .input
+            .strip_prefix("epoch")
+            .map(str::trim)
+            .filter(|epoch| epoch.is_empty() || epoch.parse::<u64>().is_ok())
+        {
+            self.dispatch(IoEvent::GetEpoch {
+                epoch: epoch.parse::<u64>().ok(),
+            });
+        } else if let Some(block_count) = self
+            .input
+            .strip_prefix("gas")
+            .map(str::trim)
+            .filter(|block_count| block_count.is_empty() || block_count.parse::<u64>().is_ok())
+        {
+            self.dispatch(IoEvent::GetFeeHistory {
+                block_count: block_count
+                    .parse::<u64>()
+                    .map_or(DEFAULT_BLOCK_COUNT, |block_count| {
+                        block_count.clamp(1, MAX_BLOCK_COUNT)
+                    }),
+            });
+        } else if let Some(address) = self
+            .input
+            .strip_prefix("withdrawals")
+            .map(str::trim)
+            .filter(|address| address.is_empty() || address.parse::<Address>().is_ok())
+        {
+            self.dispatch(IoEvent::GetWithdrawals {
+                address: address.parse::<Address>().ok(),
+            });
         } else if let Ok(transaction_hash) = self.input.parse::<TxHash>() {
             self.dispatch(IoEvent::GetTransactionWithReceipt { transaction_hash });
         } else if let Ok(i) = self.input.parse::<u64>() {
             let number = U64::from(i);
-            self.dispatch(IoEvent::GetBlock { number });
+            self.dispatch(IoEvent::GetBlock {
+                number,
+                is_searching: true,
+            });
         } else if let Ok(name_or_address) = self.input.parse::<NameOrAddress>() {
             self.dispatch(IoEvent::GetNameOrAddressInfo {
                 name_or_address,
@@ -264,6 +525,6 @@ impl App {
         let message = self.input.to_owned();
         self.input.clear();
         self.reset_cursor();
-        message
+        Some(message)
     }
 }
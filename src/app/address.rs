@@ -1,48 +1,58 @@
-use crate::ethers::types::AddressInfo;
+use crate::{
+    ethers::{
+        contract_call::{parse_block_id, split_arguments},
+        source_files::{FileTreeRow, SourceFile},
+        storage::{StorageQuery, StorageSnapshot},
+        types::AddressInfo,
+    },
+    widget::{Form, TextField},
+};
+use anyhow::Result;
+use ratatui::{
+    text::Span,
+    widgets::{ListState, ScrollbarState},
+};
 
 #[derive(Copy, Clone)]
 pub enum SelectableContractDetailItem {
     ContractSourceCode, //0
     ContractAbi,        //1
+    ReadContract,       //2
+    Bytecode,           //3
+    Storage,            //4
 }
 
 impl SelectableContractDetailItem {
-    pub fn next(&self, address_info: &AddressInfo) -> Self {
+    const ITEMS: [Self; 5] = [
+        Self::ContractSourceCode,
+        Self::ContractAbi,
+        Self::ReadContract,
+        Self::Bytecode,
+        Self::Storage,
+    ];
+
+    fn is_available(&self, address_info: &AddressInfo) -> bool {
         match self {
-            Self::ContractAbi => {
-                if address_info.contract_source_code.is_some() {
-                    Self::ContractSourceCode
-                } else {
-                    Self::ContractAbi
-                }
-            }
-            Self::ContractSourceCode => {
-                if address_info.contract_abi.is_some() {
-                    Self::ContractAbi
-                } else {
-                    Self::ContractSourceCode
-                }
-            }
+            Self::ContractSourceCode => !address_info.source_files.is_empty(),
+            Self::ContractAbi | Self::ReadContract => address_info.contract_abi.is_some(),
+            Self::Bytecode | Self::Storage => address_info.bytecode.is_some(),
         }
     }
 
+    pub fn next(&self, address_info: &AddressInfo) -> Self {
+        let i = usize::from(*self);
+        (1..Self::ITEMS.len())
+            .map(|offset| Self::ITEMS[(i + offset) % Self::ITEMS.len()])
+            .find(|item| item.is_available(address_info))
+            .unwrap_or(*self)
+    }
+
     pub fn previous(&self, address_info: &AddressInfo) -> Self {
-        match self {
-            Self::ContractAbi => {
-                if address_info.contract_source_code.is_some() {
-                    Self::ContractSourceCode
-                } else {
-                    Self::ContractAbi
-                }
-            }
-            Self::ContractSourceCode => {
-                if address_info.contract_abi.is_some() {
-                    Self::ContractAbi
-                } else {
-                    Self::ContractSourceCode
-                }
-            }
-        }
+        let i = usize::from(*self);
+        (1..Self::ITEMS.len())
+            .map(|offset| Self::ITEMS[(i + Self::ITEMS.len() - offset) % Self::ITEMS.len()])
+            .find(|item| item.is_available(address_info))
+            .unwrap_or(*self)
     }
 }
 
@@ -58,6 +68,12 @@ impl From<usize> for SelectableContractDetailItem {
             Self::ContractSourceCode
         } else if i == 1 {
             Self::ContractAbi
+        } else if i == 2 {
+            Self::ReadContract
+        } else if i == 3 {
+            Self::Bytecode
+        } else if i == 4 {
+            Self::Storage
         } else {
             unreachable!()
         }
@@ -69,6 +85,151 @@ impl From<SelectableContractDetailItem> for usize {
         match val {
             SelectableContractDetailItem::ContractSourceCode => 0,
             SelectableContractDetailItem::ContractAbi => 1,
+            SelectableContractDetailItem::ReadContract => 2,
+            SelectableContractDetailItem::Bytecode => 3,
+            SelectableContractDetailItem::Storage => 4,
+        }
+    }
+}
+
+/// State of the "Read Contract" tab.
+/// `form` holds the arguments of the opened function followed by the block to call at.
+#[derive(Default)]
+pub struct ContractCallState {
+    pub functions_list_state: ListState,
+    pub form: Option<Form>,
+    pub result: Option<Result<Vec<String>, String>>,
+}
+
+impl ContractCallState {
+    pub fn reset(&mut self) {
+        self.functions_list_state = ListState::default();
+        self.form = None;
+        self.result = None;
+    }
+}
+
+/// State of the "Source Code" tab.
+/// `files_list_state` selects a row of the file tree, `search` holds the query typed after `/`.
+#[derive(Default)]
+pub struct SourceCodeState {
+    pub files_list_state: ListState,
+    pub is_file_tree_focused: bool,
+    pub search: Option<Form>,
+    pub cache: SourceCodeCache,
+}
+
+/// Highlighted lines of the shown file and the lines matching the search query, recomputed only
+/// when the file or the query changes.
+#[derive(Default)]
+pub struct SourceCodeCache {
+    pub file: Option<SourceFile>,
+    pub lines: Vec<Vec<Span<'static>>>,
+    pub query: Option<String>,
+    pub matches: Vec<usize>,
+}
+
+impl SourceCodeState {
+    pub fn reset(&mut self) {
+        self.files_list_state = ListState::default();
+        self.is_file_tree_focused = false;
+        self.search = None;
+        self.cache = SourceCodeCache::default();
+    }
+
+    /// Index of the selected file, defaulting to the first one.
+    pub fn selected_file_index(&self, rows: &[FileTreeRow]) -> usize {
+        self.files_list_state
+            .selected()
+            .and_then(|i| rows.get(i))
+            .and_then(|row| row.file_index)
+            .unwrap_or(0)
+    }
+
+    /// Selects the next (or previous) file in the tree, skipping directories.
+    pub fn select_file(&mut self, rows: &[FileTreeRow], is_forward: bool) {
+        if rows.is_empty() {
+            return;
+        }
+        let len = rows.len();
+        let i = self
+            .files_list_state
+            .selected()
+            .unwrap_or(if is_forward { len - 1 } else { 0 });
+        if let Some(i) = (1..=len)
+            .map(|offset| {
+                if is_forward {
+                    (i + offset) % len
+                } else {
+                    (i + len - offset) % len
+                }
+            })
+            .find(|&i| rows[i].file_index.is_some())
+        {
+            self.files_list_state.select(Some(i));
         }
     }
+
+    pub fn query(&self) -> Option<String> {
+        self.search
+            .as_ref()
+            .and_then(|search| search.values().first().cloned())
+            .filter(|query| !query.is_empty())
+    }
+}
+
+/// State of the "Bytecode" tab.
+/// `is_raw` switches the disassembly to a hex dump of the runtime bytecode.
+#[derive(Default)]
+pub struct BytecodeState {
+    pub scroll: u16,
+    pub scroll_state: ScrollbarState,
+    pub is_raw: bool,
+}
+
+impl BytecodeState {
+    pub fn reset(&mut self) {
+        self.scroll = 0;
+        self.scroll_state = ScrollbarState::default();
+        self.is_raw = false;
+    }
+}
+
+/// State of the "Storage" tab.
+/// Every read is kept in `snapshots` so that values can be compared across blocks.
+pub struct StorageState {
+    pub form: Form,
+    pub snapshots: Vec<StorageSnapshot>,
+    pub error: Option<String>,
+    pub scroll: u16,
+}
+
+impl Default for StorageState {
+    fn default() -> Self {
+        Self {
+            form: Form::with_fields(vec![
+                TextField::new("slot / variable", ""),
+                TextField::new("keys / [indexes]", ""),
+                TextField::new("block", "latest"),
+            ]),
+            snapshots: vec![],
+            error: None,
+            scroll: 0,
+        }
+    }
+}
+
+impl StorageState {
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn query(&self) -> Result<StorageQuery> {
+        let values = self.form.values();
+        Ok(StorageQuery {
+            slot: values[0].to_owned(),
+            keys: split_arguments(&values[1]),
+            block: parse_block_id(&values[2])?,
+        })
+    }
 }
@@ -5,6 +5,7 @@ pub enum SelectableBlockDetailItem {
     Withdrawls,
     FeeRecipient,
     ParentHash,
+    BeaconBlock,
 }
 
 impl SelectableBlockDetailItem {
@@ -13,11 +14,20 @@ impl SelectableBlockDetailItem {
             Self::Transactions => {
                 if block.withdrawals.is_some() {
                     Self::Withdrawls
+                } else if Self::has_beacon_block(block) {
+                    Self::BeaconBlock
                 } else {
                     Self::FeeRecipient
                 }
             }
-            Self::Withdrawls => Self::FeeRecipient,
+            Self::Withdrawls => {
+                if Self::has_beacon_block(block) {
+                    Self::BeaconBlock
+                } else {
+                    Self::FeeRecipient
+                }
+            }
+            Self::BeaconBlock => Self::FeeRecipient,
             Self::FeeRecipient => {
                 if block.author.is_some() {
                     Self::ParentHash
@@ -39,16 +49,30 @@ impl SelectableBlockDetailItem {
                 }
             }
             Self::Withdrawls => Self::Transactions,
-            Self::FeeRecipient => {
+            Self::BeaconBlock => {
                 if block.withdrawals.is_some() {
                     Self::Withdrawls
                 } else {
                     Self::Transactions
                 }
             }
+            Self::FeeRecipient => {
+                if Self::has_beacon_block(block) {
+                    Self::BeaconBlock
+                } else if block.withdrawals.is_some() {
+                    Self::Withdrawls
+                } else {
+                    Self::Transactions
+                }
+            }
             Self::ParentHash => Self::FeeRecipient,
         }
     }
+
+    /// Blocks after the merge are proposed by the beacon chain and have no difficulty.
+    pub fn has_beacon_block<T>(block: &Block<T>) -> bool {
+        block.number.is_some() && block.difficulty.is_zero()
+    }
 }
 
 impl From<usize> for SelectableBlockDetailItem {
@@ -61,6 +85,8 @@ impl From<usize> for SelectableBlockDetailItem {
             Self::FeeRecipient
         } else if i == 3 {
             Self::ParentHash
+        } else if i == 4 {
+            Self::BeaconBlock
         } else {
             unreachable!()
         }
@@ -74,6 +100,7 @@ impl From<SelectableBlockDetailItem> for usize {
             SelectableBlockDetailItem::Withdrawls => 1,
             SelectableBlockDetailItem::FeeRecipient => 2,
             SelectableBlockDetailItem::ParentHash => 3,
+            SelectableBlockDetailItem::BeaconBlock => 4,
         }
     }
 }
@@ -0,0 +1,318 @@
+use crate::{
+    app::filter::{Filter, TransactionRow},
+    ethers::{
+        transaction::{effective_gas_price, TransactionFee},
+        types::BlockWithTransactionReceipts,
+    },
+};
+use ethers::core::{
+    types::{Address, Transaction, TransactionReceipt, H256, U256, U64},
+    utils::hex,
+};
+use std::{cmp::Ordering, collections::HashMap, fmt};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SortColumn {
+    Value,
+    GasPrice,
+    Fee,
+    GasUsed,
+    Status,
+}
+
+impl SortColumn {
+    fn next(column: Option<Self>) -> Option<Self> {
+        match column {
+            None => Some(Self::Value),
+            Some(Self::Value) => Some(Self::GasPrice),
+            Some(Self::GasPrice) => Some(Self::Fee),
+            Some(Self::Fee) => Some(Self::GasUsed),
+            Some(Self::GasUsed) => Some(Self::Status),
+            Some(Self::Status) => None,
+        }
+    }
+}
+
+impl fmt::Display for SortColumn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Value => write!(f, "value"),
+            Self::GasPrice => write!(f, "gas price"),
+            Self::Fee => write!(f, "fee"),
+            Self::GasUsed => write!(f, "gas used"),
+            Self::Status => write!(f, "status"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StatusFilter {
+    Failed,
+    ContractCreation,
+}
+
+/// Transactions of a block sent to the same address.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransactionGroup {
+    /// `None` for contract creations.
+    pub to: Option<Address>,
+    pub count: usize,
+    pub gas_used: U256,
+    pub value: U256,
+}
+
+/// Sorting, filters and grouping of the transactions table of a block.
+#[derive(Clone, Debug, Default)]
+pub struct BlockTransactionsView {
+    pub sort: Option<SortColumn>,
+    pub is_descending: bool,
+    pub status_filter: Option<StatusFilter>,
+    pub transaction_type: Option<U64>,
+    pub to: Option<Address>,
+    pub selector: Option<[u8; 4]>,
+    pub is_grouped: bool,
+}
+
+impl BlockTransactionsView {
+    pub fn next_sort(&mut self) {
+        self.sort = SortColumn::next(self.sort);
+    }
+
+    pub fn toggle_order(&mut self) {
+        self.is_descending = !self.is_descending;
+    }
+
+    pub fn next_status_filter(&mut self) {
+        self.status_filter = match self.status_filter {
+            None => Some(StatusFilter::Failed),
+            Some(StatusFilter::Failed) => Some(StatusFilter::ContractCreation),
+            Some(StatusFilter::ContractCreation) => None,
+        };
+    }
+
+    /// Cycles through legacy, access list, EIP-1559 and blob transactions.
+    pub fn next_transaction_type(&mut self) {
+        self.transaction_type = match self.transaction_type.map(|i| i.as_u64()) {
+            None => Some(U64::zero()),
+            Some(i) if i < 3 => Some(U64::from(i + 1)),
+            Some(_) => None,
+        };
+    }
+
+    /// Filters by the method selector of `transaction`, or clears the filter if it is set.
+    pub fn toggle_selector(&mut self, transaction: &Transaction) {
+        self.selector = match self.selector {
+            Some(_) => None,
+            None => selector(transaction),
+        };
+    }
+
+    pub fn clear_filters(&mut self) {
+        self.status_filter = None;
+        self.transaction_type = None;
+        self.to = None;
+        self.selector = None;
+        self.is_grouped = false;
+    }
+
+    pub fn has_filters(&self) -> bool {
+        self.status_filter.is_some()
+            || self.transaction_type.is_some()
+            || self.to.is_some()
+            || self.selector.is_some()
+    }
+
+    /// Indices into `block.transactions` of the rows in display order. `filter` is the filter
+    /// expression typed into the filter bar, applied on top of the view's own filters.
+    pub fn indices(
+        &self,
+        block: &BlockWithTransactionReceipts<Transaction>,
+        filter: Option<&Filter>,
+    ) -> Vec<usize> {
+        let receipts = receipts_by_hash(block);
+        let transactions = &block.block.transactions;
+
+        let mut indices = transactions
+            .iter()
+            .enumerate()
+            .filter(|(_, transaction)| {
+                let receipt = receipts.get(&transaction.hash).copied();
+                self.matches(transaction, receipt)
+                    && filter.is_none_or(|filter| {
+                        filter.matches(&TransactionRow {
+                            transaction,
+                            receipt,
+                            base_fee_per_gas: block.block.base_fee_per_gas,
+                        })
+                    })
+            })
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        if let Some(sort) = self.sort {
+            let key = |i: &usize| {
+                let transaction = &transactions[*i];
+                let receipt = receipts.get(&transaction.hash).copied();
+                sort_key(sort, transaction, receipt, block.block.base_fee_per_gas)
+            };
+            // Rows whose receipt is not loaded yet stay at the bottom in both orders
+            indices.sort_by(|a, b| match (key(a), key(b)) {
+                (Some(a), Some(b)) if self.is_descending => b.cmp(&a),
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            });
+        }
+        indices
+    }
+
+    /// The filtered transactions grouped by `to`, with the busiest contracts first.
+    pub fn groups(
+        &self,
+        block: &BlockWithTransactionReceipts<Transaction>,
+        filter: Option<&Filter>,
+    ) -> Vec<TransactionGroup> {
+        let receipts = receipts_by_hash(block);
+        let mut groups: Vec<TransactionGroup> = vec![];
+        for i in self.indices(block, filter) {
+            let transaction = &block.block.transactions[i];
+            let gas_used = receipts
+                .get(&transaction.hash)
+                .and_then(|receipt| receipt.gas_used)
+                .unwrap_or_default();
+            match groups.iter_mut().find(|group| group.to == transaction.to) {
+                Some(group) => {
+                    group.count += 1;
+                    group.gas_used += gas_used;
+                    group.value += transaction.value;
+                }
+                None => groups.push(TransactionGroup {
+                    to: transaction.to,
+                    count: 1,
+                    gas_used,
+                    value: transaction.value,
+                }),
+            }
+        }
+        groups.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| b.gas_used.cmp(&a.gas_used))
+        });
+        groups
+    }
+
+    pub fn row_count(
+        &self,
+        block: &BlockWithTransactionReceipts<Transaction>,
+        filter: Option<&Filter>,
+    ) -> usize {
+        if self.is_grouped {
+            self.groups(block, filter).len()
+        } else {
+            self.indices(block, filter).len()
+        }
+    }
+
+    fn matches(&self, transaction: &Transaction, receipt: Option<&TransactionReceipt>) -> bool {
+        let status = match self.status_filter {
+            Some(StatusFilter::Failed) => {
+                receipt.and_then(|receipt| receipt.status) == Some(U64::zero())
+            }
+            Some(StatusFilter::ContractCreation) => transaction.to.is_none(),
+            None => true,
+        };
+        let transaction_type = self.transaction_type.is_none_or(|transaction_type| {
+            transaction.transaction_type.unwrap_or_default() == transaction_type
+        });
+        let to = self.to.is_none_or(|to| transaction.to == Some(to));
+        let selector = self
+            .selector
+            .is_none_or(|method| selector(transaction) == Some(method));
+        status && transaction_type && to && selector
+    }
+}
+
+/// Sort and filter settings shown in the title of the table.
+impl fmt::Display for BlockTransactionsView {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut labels = vec![];
+        if let Some(sort) = self.sort {
+            labels.push(format!(
+                "sort: {sort} {}",
+                if self.is_descending { "↓" } else { "↑" }
+            ));
+        }
+        match self.status_filter {
+            Some(StatusFilter::Failed) => labels.push("failed".to_string()),
+            Some(StatusFilter::ContractCreation) => labels.push("contract creations".to_string()),
+            None => {}
+        }
+        if let Some(transaction_type) = self.transaction_type {
+            labels.push(format!(
+                "type: {}",
+                transaction_type_label(Some(transaction_type))
+            ));
+        }
+        if let Some(to) = self.to {
+            labels.push(format!("to: {to:?}"));
+        }
+        if let Some(selector) = self.selector {
+            labels.push(format!("method: 0x{}", hex::encode(selector)));
+        }
+        if self.is_grouped {
+            labels.push("grouped by to".to_string());
+        }
+        write!(f, "{}", labels.join(", "))
+    }
+}
+
+pub fn transaction_type_label(transaction_type: Option<U64>) -> &'static str {
+    match transaction_type.map(|i| i.as_u64()) {
+        None | Some(0) => "Legacy",
+        Some(1) => "AccessList",
+        Some(2) => "EIP-1559",
+        Some(3) => "Blob",
+        Some(_) => "Unknown",
+    }
+}
+
+pub fn selector(transaction: &Transaction) -> Option<[u8; 4]> {
+    transaction
+        .input
+        .get(..4)
+        .filter(|_| transaction.to.is_some())
+        .and_then(|selector| selector.try_into().ok())
+}
+
+fn receipts_by_hash(
+    block: &BlockWithTransactionReceipts<Transaction>,
+) -> HashMap<H256, &TransactionReceipt> {
+    block
+        .transaction_receipts
+        .iter()
+        .flatten()
+        .map(|receipt| (receipt.transaction_hash, receipt))
+        .collect()
+}
+
+fn sort_key(
+    sort: SortColumn,
+    transaction: &Transaction,
+    receipt: Option<&TransactionReceipt>,
+    base_fee_per_gas: Option<U256>,
+) -> Option<U256> {
+    match sort {
+        SortColumn::Value => Some(transaction.value),
+        SortColumn::GasPrice => match receipt {
+            Some(receipt) => effective_gas_price(transaction, receipt, base_fee_per_gas),
+            None => transaction.gas_price,
+        },
+        SortColumn::Fee => TransactionFee::new(transaction, receipt?, base_fee_per_gas)
+            .ok()
+            .map(|fee| fee.total()),
+        SortColumn::GasUsed => receipt?.gas_used,
+        SortColumn::Status => receipt?.status.map(|status| U256::from(status.as_u64())),
+    }
+}
@@ -0,0 +1,222 @@
+use crate::ethers::types::BlockWithTransactionReceipts;
+use ethers::core::types::{Transaction, H256, U64};
+use std::collections::{BTreeMap, HashMap};
+
+/// Number of canonical blocks remembered to detect reorgs, and of reorgs and orphaned blocks
+/// kept.
+const CAPACITY: usize = 128;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reorg {
+    /// Head of the new chain when the reorg was detected.
+    pub new_head: U64,
+    pub depth: usize,
+    /// Hashes of the blocks reorged out, the lowest first.
+    pub orphaned: Vec<H256>,
+}
+
+/// Remembers the canonical chain seen in the "Latest Blocks" panel and the blocks that were
+/// reorged out of it.
+#[derive(Clone, Debug, Default)]
+pub struct ChainStore {
+    canonical: BTreeMap<U64, BlockWithTransactionReceipts<Transaction>>,
+    orphaned: HashMap<H256, BlockWithTransactionReceipts<Transaction>>,
+    pub reorgs: Vec<Reorg>,
+}
+
+impl ChainStore {
+    /// Adds newly fetched blocks, recording a reorg if they do not extend the known chain.
+    pub fn insert_blocks(&mut self, blocks: &[BlockWithTransactionReceipts<Transaction>]) {
+        let mut blocks = blocks
+            .iter()
+            .filter(|block| block.block.number.is_some() && block.block.hash.is_some())
+            .collect::<Vec<_>>();
+        blocks.sort_by_key(|block| block.block.number);
+
+        let mut orphaned = vec![];
+        for block in blocks {
+            let number = block.block.number.unwrap_or_default();
+            let hash = block.block.hash.unwrap_or_default();
+
+            // A different block at the same height replaces it and every block built on it
+            if self
+                .canonical
+                .get(&number)
+                .is_some_and(|known| known.block.hash != Some(hash))
+            {
+                orphaned.append(&mut self.canonical.split_off(&number).into_values().collect());
+            }
+            // A parent that does not match means the previous block was reorged out as well
+            let parent_number = number.saturating_sub(U64::one());
+            if let Some(parent) = self.canonical.get(&parent_number) {
+                if parent.block.hash != Some(block.block.parent_hash) {
+                    orphaned.append(
+                        &mut self
+                            .canonical
+                            .split_off(&parent_number)
+                            .into_values()
+                            .collect(),
+                    );
+                }
+            }
+
+            self.canonical.insert(number, block.to_owned());
+        }
+
+        while self.canonical.len() > CAPACITY {
+            self.canonical.pop_first();
+        }
+
+        if !orphaned.is_empty() {
+            orphaned.sort_by_key(|block| block.block.number);
+            self.reorgs.push(Reorg {
+                new_head: self.canonical.keys().last().copied().unwrap_or_default(),
+                depth: orphaned.len(),
+                orphaned: orphaned
+                    .iter()
+                    .filter_map(|block| block.block.hash)
+                    .collect(),
+            });
+            for block in orphaned {
+                if let Some(hash) = block.block.hash {
+                    self.orphaned.insert(hash, block);
+                }
+            }
+        }
+
+        // The oldest reorgs are forgotten along with their blocks
+        while (self.reorgs.len() > CAPACITY || self.orphaned.len() > CAPACITY)
+            && !self.reorgs.is_empty()
+        {
+            let reorg = self.reorgs.remove(0);
+            for hash in reorg.orphaned {
+                if !self
+                    .reorgs
+                    .iter()
+                    .any(|reorg| reorg.orphaned.contains(&hash))
+                {
+                    self.orphaned.remove(&hash);
+                }
+            }
+        }
+    }
+
+    pub fn is_orphaned(&self, hash: H256) -> bool {
+        self.orphaned.contains_key(&hash)
+    }
+
+    pub fn get_orphaned(&self, hash: H256) -> Option<&BlockWithTransactionReceipts<Transaction>> {
+        self.orphaned.get(&hash)
+    }
+
+    /// Orphaned blocks with the reorg that removed them, the latest reorg first.
+    pub fn orphaned_blocks(&self) -> Vec<(&Reorg, &BlockWithTransactionReceipts<Transaction>)> {
+        self.reorgs
+            .iter()
+            .rev()
+            .flat_map(|reorg| {
+                reorg
+                    .orphaned
+                    .iter()
+                    .filter_map(|hash| self.orphaned.get(hash))
+                    .map(move |block| (reorg, block))
+            })
+            .collect()
+    }
+
+    /// Whether a block at `number` was replaced by a reorg.
+    pub fn is_reorged(&self, number: U64) -> bool {
+        self.orphaned
+            .values()
+            .any(|block| block.block.number == Some(number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::core::types::Block;
+
+    fn block(
+        number: u64,
+        hash: u64,
+        parent_hash: u64,
+    ) -> BlockWithTransactionReceipts<Transaction> {
+        BlockWithTransactionReceipts {
+            block: Block {
+                number: Some(U64::from(number)),
+                hash: Some(H256::from_low_u64_be(hash)),
+                parent_hash: H256::from_low_u64_be(parent_hash),
+                ..Default::default()
+            },
+            transaction_receipts: None,
+        }
+    }
+
+    fn chain_store() -> ChainStore {
+        let mut chain_store = ChainStore::default();
+        chain_store.insert_blocks(&[block(1, 1, 0), block(2, 2, 1), block(3, 3, 2)]);
+        chain_store
+    }
+
+    #[test]
+    fn extending_the_chain_is_not_a_reorg() {
+        let mut chain_store = chain_store();
+        chain_store.insert_blocks(&[block(4, 4, 3)]);
+
+        assert!(chain_store.reorgs.is_empty());
+        assert!(!chain_store.is_reorged(U64::from(3)));
+    }
+
+    #[test]
+    fn same_height_replacement() {
+        let mut chain_store = chain_store();
+        chain_store.insert_blocks(&[block(3, 33, 2)]);
+
+        assert_eq!(
+            chain_store.reorgs,
+            vec![Reorg {
+                new_head: U64::from(3),
+                depth: 1,
+                orphaned: vec![H256::from_low_u64_be(3)],
+            }]
+        );
+        assert!(chain_store.is_orphaned(H256::from_low_u64_be(3)));
+        assert!(!chain_store.is_orphaned(H256::from_low_u64_be(33)));
+        assert!(chain_store.is_reorged(U64::from(3)));
+        assert!(!chain_store.is_reorged(U64::from(2)));
+    }
+
+    #[test]
+    fn parent_mismatch() {
+        let mut chain_store = chain_store();
+        chain_store.insert_blocks(&[block(4, 44, 33)]);
+
+        assert_eq!(
+            chain_store.reorgs,
+            vec![Reorg {
+                new_head: U64::from(4),
+                depth: 1,
+                orphaned: vec![H256::from_low_u64_be(3)],
+            }]
+        );
+        assert_eq!(
+            chain_store
+                .get_orphaned(H256::from_low_u64_be(3))
+                .and_then(|block| block.block.number),
+            Some(U64::from(3))
+        );
+    }
+
+    #[test]
+    fn reorgs_are_capped() {
+        let mut chain_store = chain_store();
+        for i in 0..CAPACITY as u64 * 2 {
+            chain_store.insert_blocks(&[block(3, 100 + i, 2)]);
+        }
+
+        assert_eq!(chain_store.reorgs.len(), CAPACITY);
+        assert_eq!(chain_store.orphaned.len(), CAPACITY);
+        assert!(!chain_store.is_orphaned(H256::from_low_u64_be(3)));
+    }
+}
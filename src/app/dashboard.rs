@@ -0,0 +1,289 @@
+use crate::app::statistics::StatisticsSource;
+use anyhow::{Context, Result};
+use ethers::core::types::Address;
+use serde::Deserialize;
+use std::{
+    collections::VecDeque,
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+const DEFAULT_REFRESH_INTERVAL: u64 = 60;
+const DEFAULT_BLOCKS: u64 = 10;
+const HISTORY_CAPACITY: usize = 60;
+const TILE_HEIGHT: u16 = 3;
+const SPARKLINE_HEIGHT: u16 = 2;
+
+/// What a tile shows. Tiles are configured as JSON objects tagged by `metric`, e.g.
+/// `{"metric": "balance", "address": "0x...", "sparkline": true}`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "metric", rename_all = "snake_case")]
+pub enum Metric {
+    EtherPrice,
+    SuggestedBaseFee,
+    MedGasPrice,
+    NodeCount,
+    LastSafeBlock,
+    LastFinalizedBlock,
+    BlobBaseFee,
+    PendingTransactionCount,
+    /// Transactions per second over the last `blocks` blocks.
+    Tps {
+        #[serde(default = "default_blocks")]
+        blocks: u64,
+    },
+    /// Ether burnt by base fees over the last `blocks` blocks, scaled to an hour.
+    BurntEthPerHour {
+        #[serde(default = "default_blocks")]
+        blocks: u64,
+    },
+    Balance {
+        address: Address,
+    },
+}
+
+fn default_blocks() -> u64 {
+    DEFAULT_BLOCKS
+}
+
+impl Metric {
+    fn title(&self) -> String {
+        match self {
+            Self::EtherPrice => "ETHER PRICE".to_string(),
+            Self::SuggestedBaseFee => "SUGGESTED BASE FEE".to_string(),
+            Self::MedGasPrice => "MED GAS PRICE".to_string(),
+            Self::NodeCount => "NODE COUNT".to_string(),
+            Self::LastSafeBlock => "LAST SAFE BLOCK".to_string(),
+            Self::LastFinalizedBlock => "LAST FINALIZED BLOCK".to_string(),
+            Self::BlobBaseFee => "BLOB BASE FEE".to_string(),
+            Self::PendingTransactionCount => "PENDING TXNS".to_string(),
+            Self::Tps { blocks } => format!("TPS ({blocks} BLOCKS)"),
+            Self::BurntEthPerHour { blocks } => format!("BURNT / HOUR ({blocks} BLOCKS)"),
+            Self::Balance { address } => format!("BALANCE ({address:?})"),
+        }
+    }
+
+    fn format(&self) -> &'static str {
+        match self {
+            Self::EtherPrice => "{value} USD/ETH",
+            Self::SuggestedBaseFee | Self::MedGasPrice => "{value} Gwei",
+            Self::NodeCount => "{value} nodes",
+            Self::LastSafeBlock | Self::LastFinalizedBlock => "#{value}",
+            Self::BlobBaseFee => "{value} wei",
+            Self::PendingTransactionCount => "{value} txns",
+            Self::Tps { .. } => "{value} tx/s",
+            Self::BurntEthPerHour { .. } | Self::Balance { .. } => "{value} ETH",
+        }
+    }
+
+    fn decimals(&self) -> usize {
+        match self {
+            Self::EtherPrice | Self::BurntEthPerHour { .. } | Self::Balance { .. } => 4,
+            Self::SuggestedBaseFee | Self::MedGasPrice => 9,
+            Self::Tps { .. } => 2,
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct TileConfig {
+    #[serde(flatten)]
+    pub metric: Metric,
+    pub title: Option<String>,
+    /// Overrides the source given on the command line for this tile.
+    pub source: Option<StatisticsSource>,
+    /// Seconds between refreshes.
+    pub refresh_interval: Option<u64>,
+    /// Text of the tile, where `{value}` is replaced by the value.
+    pub format: Option<String>,
+    pub decimals: Option<usize>,
+    #[serde(default)]
+    pub sparkline: bool,
+}
+
+impl TileConfig {
+    fn new(metric: Metric) -> Self {
+        Self {
+            metric,
+            title: None,
+            source: None,
+            refresh_interval: None,
+            format: None,
+            decimals: None,
+            sparkline: false,
+        }
+    }
+}
+
+/// A value read for a tile. Some sources change what the value means, e.g. the node source of
+/// `node_count` reads the peers of the node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reading {
+    pub value: f64,
+    pub title: Option<String>,
+    pub format: Option<&'static str>,
+}
+
+impl Reading {
+    pub fn new(value: f64) -> Self {
+        Self {
+            value,
+            title: None,
+            format: None,
+        }
+    }
+}
+
+pub struct Tile {
+    pub config: TileConfig,
+    pub reading: Option<Result<Reading, String>>,
+    /// Recent values for the sparkline, oldest first.
+    pub history: VecDeque<f64>,
+    refreshed_at: Option<Instant>,
+}
+
+impl Tile {
+    fn new(config: TileConfig) -> Self {
+        Self {
+            config,
+            reading: None,
+            history: VecDeque::new(),
+            refreshed_at: None,
+        }
+    }
+
+    pub fn title(&self) -> String {
+        self.config
+            .title
+            .to_owned()
+            .unwrap_or_else(|| match self.reading.as_ref() {
+                Some(Ok(Reading {
+                    title: Some(title), ..
+                })) => title.to_owned(),
+                _ => self.config.metric.title(),
+            })
+    }
+
+    /// The formatted value, or `None` while it is loading.
+    pub fn text(&self) -> Option<String> {
+        match self.reading.as_ref()? {
+            Ok(reading) => {
+                let format = self
+                    .config
+                    .format
+                    .as_deref()
+                    .or(reading.format)
+                    .unwrap_or(self.config.metric.format());
+                let decimals = self
+                    .config
+                    .decimals
+                    .unwrap_or(self.config.metric.decimals());
+                Some(format.replace("{value}", &format!("{:.*}", decimals, reading.value)))
+            }
+            Err(err) => Some(err.to_owned()),
+        }
+    }
+
+    pub fn height(&self) -> u16 {
+        if self.config.sparkline {
+            TILE_HEIGHT + SPARKLINE_HEIGHT
+        } else {
+            TILE_HEIGHT
+        }
+    }
+
+    fn is_due(&self, now: Instant) -> bool {
+        self.refreshed_at.is_none_or(|refreshed_at| {
+            now.duration_since(refreshed_at)
+                >= Duration::from_secs(
+                    self.config
+                        .refresh_interval
+                        .unwrap_or(DEFAULT_REFRESH_INTERVAL),
+                )
+        })
+    }
+}
+
+/// The statistics tiles on the sidebar, laid out in two columns.
+pub struct Dashboard {
+    pub tiles: Vec<Tile>,
+}
+
+impl Default for Dashboard {
+    /// Ether price, node count, gas prices and the last safe and finalized blocks.
+    fn default() -> Self {
+        Self::new(
+            [
+                Metric::EtherPrice,
+                Metric::NodeCount,
+                Metric::SuggestedBaseFee,
+                Metric::MedGasPrice,
+                Metric::LastSafeBlock,
+                Metric::LastFinalizedBlock,
+            ]
+            .into_iter()
+            .map(TileConfig::new)
+            .collect(),
+        )
+    }
+}
+
+impl Dashboard {
+    pub fn new(configs: Vec<TileConfig>) -> Self {
+        Self {
+            tiles: configs.into_iter().map(Tile::new).collect(),
+        }
+    }
+
+    /// Reads the tile definitions from a JSON array.
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let configs = serde_json::from_str::<Vec<TileConfig>>(&json)
+            .with_context(|| format!("Invalid dashboard in {}", path.display()))?;
+        Ok(Self::new(configs))
+    }
+
+    pub fn height(&self) -> u16 {
+        self.tiles
+            .chunks(2)
+            .map(|row| row.iter().map(Tile::height).max().unwrap_or_default())
+            .sum()
+    }
+
+    /// Tiles whose refresh interval has passed. They are marked as refreshed, so the same tile
+    /// is not requested again while its reading is on the way.
+    pub fn due_tiles(&mut self) -> Vec<(usize, TileConfig)> {
+        let now = Instant::now();
+        self.tiles
+            .iter_mut()
+            .enumerate()
+            .filter(|(_, tile)| tile.is_due(now))
+            .map(|(i, tile)| {
+                tile.refreshed_at = Some(now);
+                (i, tile.config.to_owned())
+            })
+            .collect()
+    }
+
+    pub fn refresh_all(&mut self) {
+        for tile in self.tiles.iter_mut() {
+            tile.refreshed_at = None;
+        }
+    }
+
+    pub fn update(&mut self, index: usize, reading: Result<Reading>) {
+        let Some(tile) = self.tiles.get_mut(index) else {
+            return;
+        };
+        if let Ok(reading) = reading.as_ref() {
+            if tile.history.len() == HISTORY_CAPACITY {
+                tile.history.pop_front();
+            }
+            tile.history.push_back(reading.value);
+        }
+        tile.reading = Some(reading.map_err(|err| err.to_string()));
+    }
+}
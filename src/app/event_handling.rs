@@ -2,16 +2,26 @@ use crate::{
     app::{
         address::SelectableContractDetailItem,
         block::SelectableBlockDetailItem,
+        block_transactions::{BlockTransactionsView, StatusFilter},
+        filter::FilterTarget,
         statistics::Statistics,
         transaction::{SelectableInputDataDetailItem, SelectableTransactionDetailItem},
         App, InputMode,
     },
-    ethers::types::BlockWithTransactionReceipts,
+    beacon::{BeaconBlockId, SlotStatus, ValidatorId},
+    ethers::{
+        contract_call::{param_label, parse_arguments, parse_block_id, read_functions},
+        logs::to_csv,
+        source_files::file_tree,
+        types::BlockWithTransactionReceipts,
+    },
     network::IoEvent,
     route::{ActiveBlock, Route, RouteId},
+    widget::{Form, TextField},
 };
+use chrono::Utc;
 use crossterm::event;
-use ethers::core::types::NameOrAddress;
+use ethers::core::types::{NameOrAddress, Transaction, U64};
 use log::debug;
 use ratatui::{prelude::*, Terminal};
 
@@ -60,12 +70,13 @@ where
                     InputMode::Editing if key.kind == event::KeyEventKind::Press => {
                         match key.code {
                             event::KeyCode::Enter => {
-                                let message = app.submit_message();
                                 app.input_mode = InputMode::Normal;
-                                app.set_route(Route::new(
-                                    RouteId::Searching(message),
-                                    ActiveBlock::Main,
-                                ));
+                                if let Some(message) = app.submit_message() {
+                                    app.set_route(Route::new(
+                                        RouteId::Searching(message),
+                                        ActiveBlock::Main,
+                                    ));
+                                }
                             }
                             event::KeyCode::Char(to_insert) => {
                                 app.enter_char(to_insert);
@@ -87,6 +98,53 @@ where
                     }
                     _ => {}
                 }
+            } else if app
+                .get_active_form_mut()
+                .is_some_and(|form| form.is_editing)
+            {
+                if key.kind == event::KeyEventKind::Press {
+                    if let Some(form) = app.get_active_form_mut() {
+                        match key.code {
+                            event::KeyCode::Esc | event::KeyCode::Enter => {
+                                form.is_editing = false;
+                            }
+                            event::KeyCode::Tab => {
+                                form.next();
+                            }
+                            event::KeyCode::BackTab => {
+                                form.previous();
+                            }
+                            code => {
+                                if let Some(field) = form.selected_field_mut() {
+                                    match code {
+                                        event::KeyCode::Char(to_insert) => {
+                                            field.enter_char(to_insert);
+                                        }
+                                        event::KeyCode::Backspace => {
+                                            field.delete_char();
+                                        }
+                                        event::KeyCode::Left => {
+                                            field.move_cursor_left();
+                                        }
+                                        event::KeyCode::Right => {
+                                            field.move_cursor_right();
+                                        }
+                                        _ => {}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if app.filter.bar.is_some() {
+                        match key.code {
+                            event::KeyCode::Enter => app.submit_filter(),
+                            event::KeyCode::Esc => app.filter.bar = None,
+                            _ => {}
+                        }
+                    } else if let event::KeyCode::Enter = key.code {
+                        jump_to_source_match(app, true, true);
+                    }
+                }
             } else {
                 match key.code {
                     event::KeyCode::Enter => match app.get_current_route().get_active_block() {
@@ -128,6 +186,15 @@ where
                                     app.dispatch(IoEvent::GetDecodedInputData {
                                         transaction: transactions.items[i].transaction.to_owned(),
                                     });
+                                    if transactions.items[i].transaction_receipt.status
+                                        == Some(U64::from(0))
+                                    {
+                                        app.dispatch(IoEvent::GetRevertReason {
+                                            transaction: transactions.items[i]
+                                                .transaction
+                                                .to_owned(),
+                                        });
+                                    }
                                 }
                             }
                         }
@@ -136,6 +203,7 @@ where
                                 if let Some(i) = app.block_detail_list_state.selected() {
                                     match SelectableBlockDetailItem::from(i) {
                                         SelectableBlockDetailItem::Transactions => {
+                                            app.block_transactions.clear_filters();
                                             app.set_route(Route::new(
                                                 RouteId::TransactionsOfBlock(block.to_owned()),
                                                 ActiveBlock::Main,
@@ -171,24 +239,88 @@ where
                                             {
                                                 app.dispatch(IoEvent::GetBlockByHash {
                                                     hash: block.parent_hash,
+                                                    is_searching: false,
+                                                });
+                                            }
+                                        }
+                                        SelectableBlockDetailItem::BeaconBlock => {
+                                            if let Some(BlockWithTransactionReceipts {
+                                                block,
+                                                transaction_receipts: _,
+                                            }) = block.as_ref()
+                                            {
+                                                app.dispatch(IoEvent::GetBeaconBlock {
+                                                    id: BeaconBlockId::Timestamp(
+                                                        block.timestamp.as_u64(),
+                                                    ),
                                                 });
                                             }
                                         }
                                     }
                                 }
                             }
-                            RouteId::TransactionsOfBlock(block) => {
+                            RouteId::TransactionsOfBlock(Some(block)) => {
+                                if let Some(i) = app.transactions_table_state.selected() {
+                                    if app.block_transactions.is_grouped {
+                                        // Opening a group shows its transactions
+                                        if let Some(group) = app
+                                            .block_transactions
+                                            .groups(
+                                                &block,
+                                                app.filter.get(FilterTarget::BlockTransactions),
+                                            )
+                                            .get(i)
+                                        {
+                                            match group.to {
+                                                Some(to) => app.block_transactions.to = Some(to),
+                                                None => {
+                                                    app.block_transactions.status_filter =
+                                                        Some(StatusFilter::ContractCreation)
+                                                }
+                                            }
+                                            app.block_transactions.is_grouped = false;
+                                            app.transactions_table_state.select(Some(0));
+                                        }
+                                    } else if let Some(transaction) = app
+                                        .block_transactions
+                                        .indices(
+                                            &block,
+                                            app.filter.get(FilterTarget::BlockTransactions),
+                                        )
+                                        .get(i)
+                                        .and_then(|&i| block.block.transactions.get(i))
+                                    {
+                                        app.dispatch(IoEvent::GetTransactionWithReceipt {
+                                            transaction_hash: transaction.hash,
+                                        });
+                                    }
+                                }
+                            }
+                            RouteId::WithdrawalsOfBlock(block) => {
                                 if let Some(BlockWithTransactionReceipts {
                                     block,
                                     transaction_receipts: _,
                                 }) = block.as_ref()
                                 {
-                                    if let Some(i) = app.transactions_table_state.selected() {
-                                        if let Some(transaction) = block.transactions.get(i) {
-                                            app.dispatch(IoEvent::GetTransactionWithReceipt {
-                                                transaction_hash: transaction.hash,
-                                            });
-                                        }
+                                    if let Some(withdrawal) = app
+                                        .withdrawals_table_state
+                                        .selected()
+                                        .and_then(|i| {
+                                            app.block_withdrawal_indices(block).get(i).copied()
+                                        })
+                                        .and_then(|i| {
+                                            block
+                                                .withdrawals
+                                                .as_ref()
+                                                .and_then(|withdrawals| withdrawals.get(i))
+                                        })
+                                    {
+                                        app.dispatch(IoEvent::GetValidator {
+                                            id: ValidatorId::Index(
+                                                withdrawal.validator_index.as_u64(),
+                                            ),
+                                            is_searching: false,
+                                        });
                                     }
                                 }
                             }
@@ -225,9 +357,255 @@ where
                                                 ActiveBlock::Main,
                                             ));
                                         }
+                                        SelectableTransactionDetailItem::StateChanges => {
+                                            app.state_changes_scroll = 0;
+                                            app.state_changes_scroll_state =
+                                                app.state_changes_scroll_state.position(0);
+                                            app.set_route(Route::new(
+                                                RouteId::StateChangesOfTransaction(
+                                                    transaction.to_owned(),
+                                                ),
+                                                ActiveBlock::Main,
+                                            ));
+                                            if let Some(transaction) = transaction {
+                                                if transaction.state_changes.is_none() {
+                                                    app.dispatch(IoEvent::GetStateChanges {
+                                                        transaction,
+                                                    });
+                                                }
+                                            }
+                                        }
+                                        SelectableTransactionDetailItem::Blobs => {
+                                            app.blobs_scroll = 0;
+                                            app.blobs_scroll_state =
+                                                app.blobs_scroll_state.position(0);
+                                            app.set_route(Route::new(
+                                                RouteId::BlobsOfTransaction(transaction.to_owned()),
+                                                ActiveBlock::Main,
+                                            ));
+                                            if let Some(transaction) = transaction {
+                                                if transaction.blob_sidecars.is_none() {
+                                                    app.dispatch(IoEvent::GetBlobSidecars {
+                                                        transaction,
+                                                    });
+                                                }
+                                            }
+                                        }
+                                        SelectableTransactionDetailItem::AccessList => {
+                                            app.is_access_list_expanded =
+                                                !app.is_access_list_expanded;
+                                        }
+                                        SelectableTransactionDetailItem::CallTrace => {
+                                            if let Some(transaction) = transaction.as_ref() {
+                                                app.call_trace_list_state.select(Some(0));
+                                                app.collapsed_call_trace_nodes.clear();
+                                                app.dispatch(IoEvent::GetCallTrace {
+                                                    transaction_hash: transaction.transaction.hash,
+                                                });
+                                            }
+                                        }
                                     }
                                 }
                             }
+                            RouteId::AddressInfo(Some(address_info)) => {
+                                if let (
+                                    Some(SelectableContractDetailItem::ReadContract),
+                                    Some(abi),
+                                ) = (
+                                    app.contract_list_state
+                                        .selected()
+                                        .map(SelectableContractDetailItem::from),
+                                    address_info.contract_abi.as_ref(),
+                                ) {
+                                    let functions = read_functions(abi);
+                                    if let Some(function) = app
+                                        .contract_call
+                                        .functions_list_state
+                                        .selected()
+                                        .and_then(|i| functions.get(i))
+                                    {
+                                        if let Some(form) = app.contract_call.form.as_ref() {
+                                            let values = form.values();
+                                            let (arguments, block) =
+                                                values.split_at(function.inputs.len());
+                                            match parse_arguments(&function.inputs, arguments)
+                                                .and_then(|arguments| {
+                                                    Ok((
+                                                        arguments,
+                                                        parse_block_id(
+                                                            block.first().map_or("", |block| block),
+                                                        )?,
+                                                    ))
+                                                }) {
+                                                Ok((arguments, block)) => {
+                                                    app.contract_call.result = None;
+                                                    app.dispatch(IoEvent::CallContractFunction {
+                                                        address: address_info.address,
+                                                        function: function.to_owned(),
+                                                        arguments,
+                                                        block,
+                                                    });
+                                                }
+                                                Err(err) => {
+                                                    app.contract_call.result =
+                                                        Some(Err(err.to_string()));
+                                                }
+                                            }
+                                        } else {
+                                            let mut fields = function
+                                                .inputs
+                                                .iter()
+                                                .enumerate()
+                                                .map(|(i, param)| {
+                                                    TextField::new(&param_label(i, param), "")
+                                                })
+                                                .collect::<Vec<_>>();
+                                            fields.push(TextField::new("block", "latest"));
+                                            app.contract_call.form =
+                                                Some(Form::with_fields(fields));
+                                            app.contract_call.result = None;
+                                        }
+                                    }
+                                } else if let Some(SelectableContractDetailItem::Storage) = app
+                                    .contract_list_state
+                                    .selected()
+                                    .map(SelectableContractDetailItem::from)
+                                {
+                                    match app.storage.query() {
+                                        Ok(query) => app.dispatch(IoEvent::GetStorage {
+                                            address: address_info.address,
+                                            query,
+                                            storage_layout: address_info.storage_layout.to_owned(),
+                                        }),
+                                        Err(err) => app.storage.error = Some(err.to_string()),
+                                    }
+                                } else if let Some(proxy) = address_info.proxy.as_ref() {
+                                    app.dispatch(IoEvent::GetNameOrAddressInfo {
+                                        name_or_address: NameOrAddress::Address(
+                                            proxy.implementation,
+                                        ),
+                                        is_searching: false,
+                                    });
+                                }
+                            }
+                            RouteId::Logs => {
+                                if let Some(transaction_hash) = app
+                                    .logs
+                                    .selected_index()
+                                    .and_then(|i| match app.logs.result.as_ref() {
+                                        Some(Ok(result)) => result.logs.get(i),
+                                        _ => None,
+                                    })
+                                    .and_then(|entry| entry.transaction_hash)
+                                {
+                                    app.dispatch(IoEvent::GetTransactionWithReceipt {
+                                        transaction_hash,
+                                    });
+                                } else {
+                                    match app.logs.request() {
+                                        Ok(request) => {
+                                            app.logs.result = None;
+                                            app.logs.message = None;
+                                            app.dispatch(IoEvent::GetLogs { request });
+                                        }
+                                        Err(err) => {
+                                            app.logs.result = Some(Err(err.to_string()));
+                                        }
+                                    }
+                                }
+                            }
+                            RouteId::Simulation => match app.simulation.request() {
+                                Ok(request) => {
+                                    app.simulation.result = None;
+                                    app.simulation.result_scroll = 0;
+                                    app.dispatch(IoEvent::Simulate { request });
+                                }
+                                Err(err) => {
+                                    app.simulation.result = Some(Err(err.to_string()));
+                                }
+                            },
+                            RouteId::CallTraceOfTransaction(Ok(call_trace)) => {
+                                if let Some(i) = app.call_trace_list_state.selected() {
+                                    let nodes =
+                                        call_trace.root.flatten(&app.collapsed_call_trace_nodes);
+                                    if let Some((path, node)) = nodes.get(i) {
+                                        if !node.calls.is_empty()
+                                            && !app.collapsed_call_trace_nodes.remove(path)
+                                        {
+                                            app.collapsed_call_trace_nodes.insert(path.to_owned());
+                                        }
+                                    }
+                                }
+                            }
+                            RouteId::Validator(Ok(validator)) => {
+                                if let Some(withdrawal) = app
+                                    .validator_withdrawals_table_state
+                                    .selected()
+                                    .zip(validator.withdrawals.as_ref())
+                                    .and_then(|(i, withdrawals)| {
+                                        withdrawals.as_ref().ok()?.withdrawals.get(i)
+                                    })
+                                {
+                                    app.dispatch(IoEvent::GetWithdrawalsOfBlock {
+                                        number: withdrawal.block_number,
+                                        index: withdrawal.withdrawal.index,
+                                    });
+                                } else if let Some(address) = validator.withdrawal_address() {
+                                    app.dispatch(IoEvent::GetNameOrAddressInfo {
+                                        name_or_address: NameOrAddress::Address(address),
+                                        is_searching: false,
+                                    });
+                                }
+                            }
+                            RouteId::Epoch(Some(epoch)) => {
+                                if let Some(slot) = app
+                                    .epoch_table_state
+                                    .selected()
+                                    .and_then(|i| epoch.slots.get(i))
+                                {
+                                    if let SlotStatus::Proposed = slot.status {
+                                        app.dispatch(IoEvent::GetBeaconBlock {
+                                            id: BeaconBlockId::Slot(slot.slot),
+                                        });
+                                    }
+                                }
+                            }
+                            RouteId::Reorgs => {
+                                if let Some(block) =
+                                    app.reorgs_table_state.selected().and_then(|i| {
+                                        app.chain_store
+                                            .orphaned_blocks()
+                                            .get(i)
+                                            .map(|(_, block)| (*block).to_owned())
+                                    })
+                                {
+                                    app.set_route(Route::new(
+                                        RouteId::Block(Some(block)),
+                                        ActiveBlock::Main,
+                                    ));
+                                }
+                            }
+                            RouteId::Withdrawals(Some(withdrawal_scan)) => {
+                                if let Some(validator_index) =
+                                    app.withdrawal_scan_table_state.selected().and_then(|i| {
+                                        app.filter_withdrawal_scan(&withdrawal_scan)
+                                            .validator_index(i)
+                                    })
+                                {
+                                    app.dispatch(IoEvent::GetValidator {
+                                        id: ValidatorId::Index(validator_index.as_u64()),
+                                        is_searching: false,
+                                    });
+                                }
+                            }
+                            RouteId::BeaconBlock(Some(beacon_block)) => {
+                                if let Some(hash) = beacon_block.execution_block_hash {
+                                    app.dispatch(IoEvent::GetBlockByHash {
+                                        hash,
+                                        is_searching: false,
+                                    });
+                                }
+                            }
                             _ => {}
                         },
                         _ => {}
@@ -324,19 +702,16 @@ where
                                     }
                                 }
                             }
-                            RouteId::TransactionsOfBlock(block) => {
-                                if let Some(BlockWithTransactionReceipts {
-                                    block,
-                                    transaction_receipts: _,
-                                }) = block.as_ref()
-                                {
-                                    if !block.transactions.is_empty() {
-                                        if let Some(i) = app.transactions_table_state.selected() {
-                                            app.transactions_table_state
-                                                .select(Some((i + 1) % block.transactions.len()));
-                                        } else {
-                                            app.transactions_table_state.select(Some(0));
-                                        }
+                            RouteId::TransactionsOfBlock(Some(block)) => {
+                                let len = app.block_transactions.row_count(
+                                    &block,
+                                    app.filter.get(FilterTarget::BlockTransactions),
+                                );
+                                if len > 0 {
+                                    if let Some(i) = app.transactions_table_state.selected() {
+                                        app.transactions_table_state.select(Some((i + 1) % len));
+                                    } else {
+                                        app.transactions_table_state.select(Some(0));
                                     }
                                 }
                             }
@@ -346,16 +721,73 @@ where
                                     transaction_receipts: _,
                                 }) = block.as_ref()
                                 {
-                                    if let Some(withdrawals) = block.withdrawals.as_ref() {
+                                    let len = app.block_withdrawal_indices(block).len();
+                                    if len > 0 {
                                         if let Some(i) = app.withdrawals_table_state.selected() {
-                                            app.withdrawals_table_state
-                                                .select(Some((i + 1) % withdrawals.len()));
+                                            app.withdrawals_table_state.select(Some((i + 1) % len));
                                         } else {
                                             app.withdrawals_table_state.select(Some(0));
                                         }
                                     }
                                 }
                             }
+                            RouteId::Epoch(Some(epoch)) => {
+                                let len = epoch.slots.len();
+                                if len > 0 {
+                                    if let Some(i) = app.epoch_table_state.selected() {
+                                        app.epoch_table_state.select(Some((i + 1) % len));
+                                    } else {
+                                        app.epoch_table_state.select(Some(0));
+                                    }
+                                }
+                            }
+                            RouteId::Validator(Ok(validator)) => {
+                                let len = validator
+                                    .withdrawals
+                                    .as_ref()
+                                    .and_then(|withdrawals| withdrawals.as_ref().ok())
+                                    .map_or(0, |withdrawals| withdrawals.withdrawals.len());
+                                if len > 0 {
+                                    if let Some(i) =
+                                        app.validator_withdrawals_table_state.selected()
+                                    {
+                                        app.validator_withdrawals_table_state
+                                            .select(Some((i + 1) % len));
+                                    } else {
+                                        app.validator_withdrawals_table_state.select(Some(0));
+                                    }
+                                }
+                            }
+                            RouteId::Logs => {
+                                let len = app.logs.page_len();
+                                if len > 0 {
+                                    if let Some(i) = app.logs.table_state.selected() {
+                                        app.logs.table_state.select(Some((i + 1) % len));
+                                    } else {
+                                        app.logs.table_state.select(Some(0));
+                                    }
+                                }
+                            }
+                            RouteId::Reorgs => {
+                                let len = app.chain_store.orphaned_blocks().len();
+                                if len > 0 {
+                                    if let Some(i) = app.reorgs_table_state.selected() {
+                                        app.reorgs_table_state.select(Some((i + 1) % len));
+                                    } else {
+                                        app.reorgs_table_state.select(Some(0));
+                                    }
+                                }
+                            }
+                            RouteId::Withdrawals(Some(withdrawal_scan)) => {
+                                let len = app.filter_withdrawal_scan(&withdrawal_scan).row_count();
+                                if len > 0 {
+                                    if let Some(i) = app.withdrawal_scan_table_state.selected() {
+                                        app.withdrawal_scan_table_state.select(Some((i + 1) % len));
+                                    } else {
+                                        app.withdrawal_scan_table_state.select(Some(0));
+                                    }
+                                }
+                            }
                             RouteId::Transaction(transaction) => {
                                 if let Some(transaction) = transaction.as_ref() {
                                     if let Some(i) = app.transaction_detail_list_state.selected() {
@@ -393,24 +825,95 @@ where
                                     }
                                 }
                             }
-                            RouteId::AddressInfo(_) => match SelectableContractDetailItem::from(
-                                app.contract_list_state.selected().unwrap_or(
-                                    SelectableContractDetailItem::ContractSourceCode.into(),
-                                ),
-                            ) {
-                                SelectableContractDetailItem::ContractSourceCode => {
-                                    app.source_code_scroll =
-                                        app.source_code_scroll.saturating_add(1);
-                                    app.source_code_scroll_state = app
-                                        .source_code_scroll_state
-                                        .position(app.source_code_scroll);
+                            RouteId::AddressInfo(address_info) => {
+                                match SelectableContractDetailItem::from(
+                                    app.contract_list_state.selected().unwrap_or(
+                                        SelectableContractDetailItem::ContractSourceCode.into(),
+                                    ),
+                                ) {
+                                    SelectableContractDetailItem::ContractSourceCode => {
+                                        if app.source_code.is_file_tree_focused {
+                                            if let Some(address_info) = address_info.as_ref() {
+                                                app.source_code.select_file(
+                                                    &file_tree(&address_info.source_files),
+                                                    true,
+                                                );
+                                                app.source_code_scroll = 0;
+                                            }
+                                        } else {
+                                            app.source_code_scroll =
+                                                app.source_code_scroll.saturating_add(1);
+                                        }
+                                        app.source_code_scroll_state = app
+                                            .source_code_scroll_state
+                                            .position(app.source_code_scroll);
+                                    }
+                                    SelectableContractDetailItem::ContractAbi => {
+                                        app.abi_scroll = app.abi_scroll.saturating_add(1);
+                                        app.abi_scroll_state =
+                                            app.abi_scroll_state.position(app.abi_scroll);
+                                    }
+                                    SelectableContractDetailItem::Bytecode => {
+                                        app.bytecode.scroll = app.bytecode.scroll.saturating_add(1);
+                                        app.bytecode.scroll_state =
+                                            app.bytecode.scroll_state.position(app.bytecode.scroll);
+                                    }
+                                    SelectableContractDetailItem::Storage => {
+                                        app.storage.scroll = app.storage.scroll.saturating_add(1);
+                                    }
+                                    SelectableContractDetailItem::ReadContract => {
+                                        if let Some(form) = app.contract_call.form.as_mut() {
+                                            form.next();
+                                        } else if let Some(abi) = address_info
+                                            .and_then(|address_info| address_info.contract_abi)
+                                        {
+                                            let len = read_functions(&abi).len();
+                                            if len > 0 {
+                                                if let Some(i) = app
+                                                    .contract_call
+                                                    .functions_list_state
+                                                    .selected()
+                                                {
+                                                    app.contract_call
+                                                        .functions_list_state
+                                                        .select(Some((i + 1) % len));
+                                                } else {
+                                                    app.contract_call
+                                                        .functions_list_state
+                                                        .select(Some(0));
+                                                }
+                                            }
+                                        }
+                                    }
                                 }
-                                SelectableContractDetailItem::ContractAbi => {
-                                    app.abi_scroll = app.abi_scroll.saturating_add(1);
-                                    app.abi_scroll_state =
-                                        app.abi_scroll_state.position(app.abi_scroll);
+                            }
+                            RouteId::StateChangesOfTransaction(_) => {
+                                app.state_changes_scroll =
+                                    app.state_changes_scroll.saturating_add(1);
+                                app.state_changes_scroll_state = app
+                                    .state_changes_scroll_state
+                                    .position(app.state_changes_scroll);
+                            }
+                            RouteId::BlobsOfTransaction(_) => {
+                                app.blobs_scroll = app.blobs_scroll.saturating_add(1);
+                                app.blobs_scroll_state =
+                                    app.blobs_scroll_state.position(app.blobs_scroll);
+                            }
+                            RouteId::CallTraceOfTransaction(Ok(call_trace)) => {
+                                let len = call_trace
+                                    .root
+                                    .flatten(&app.collapsed_call_trace_nodes)
+                                    .len();
+                                if let Some(i) = app.call_trace_list_state.selected() {
+                                    app.call_trace_list_state.select(Some((i + 1) % len));
+                                } else {
+                                    app.call_trace_list_state.select(Some(0));
                                 }
-                            },
+                            }
+                            RouteId::Simulation => {
+                                app.simulation.result_scroll =
+                                    app.simulation.result_scroll.saturating_add(1);
+                            }
                             _ => {}
                         },
                         _ => {}
@@ -466,21 +969,17 @@ where
                                     }
                                 }
                             }
-                            RouteId::TransactionsOfBlock(block) => {
-                                if let Some(BlockWithTransactionReceipts {
-                                    block,
-                                    transaction_receipts: _,
-                                }) = block.as_ref()
-                                {
-                                    if !block.transactions.is_empty() {
-                                        if let Some(i) = app.transactions_table_state.selected() {
-                                            app.transactions_table_state.select(Some(
-                                                (i + block.transactions.len() - 1)
-                                                    % block.transactions.len(),
-                                            ));
-                                        } else {
-                                            app.transactions_table_state.select(Some(0));
-                                        }
+                            RouteId::TransactionsOfBlock(Some(block)) => {
+                                let len = app.block_transactions.row_count(
+                                    &block,
+                                    app.filter.get(FilterTarget::BlockTransactions),
+                                );
+                                if len > 0 {
+                                    if let Some(i) = app.transactions_table_state.selected() {
+                                        app.transactions_table_state
+                                            .select(Some((i + len - 1) % len));
+                                    } else {
+                                        app.transactions_table_state.select(Some(0));
                                     }
                                 }
                             }
@@ -490,17 +989,75 @@ where
                                     transaction_receipts: _,
                                 }) = block.as_ref()
                                 {
-                                    if let Some(withdrawals) = block.withdrawals.as_ref() {
+                                    let len = app.block_withdrawal_indices(block).len();
+                                    if len > 0 {
                                         if let Some(i) = app.withdrawals_table_state.selected() {
-                                            app.withdrawals_table_state.select(Some(
-                                                (i + withdrawals.len() - 1) % withdrawals.len(),
-                                            ));
+                                            app.withdrawals_table_state
+                                                .select(Some((i + len - 1) % len));
                                         } else {
                                             app.withdrawals_table_state.select(Some(0));
                                         }
                                     }
                                 }
                             }
+                            RouteId::Epoch(Some(epoch)) => {
+                                let len = epoch.slots.len();
+                                if len > 0 {
+                                    if let Some(i) = app.epoch_table_state.selected() {
+                                        app.epoch_table_state.select(Some((i + len - 1) % len));
+                                    } else {
+                                        app.epoch_table_state.select(Some(0));
+                                    }
+                                }
+                            }
+                            RouteId::Validator(Ok(validator)) => {
+                                let len = validator
+                                    .withdrawals
+                                    .as_ref()
+                                    .and_then(|withdrawals| withdrawals.as_ref().ok())
+                                    .map_or(0, |withdrawals| withdrawals.withdrawals.len());
+                                if len > 0 {
+                                    if let Some(i) =
+                                        app.validator_withdrawals_table_state.selected()
+                                    {
+                                        app.validator_withdrawals_table_state
+                                            .select(Some((i + len - 1) % len));
+                                    } else {
+                                        app.validator_withdrawals_table_state.select(Some(0));
+                                    }
+                                }
+                            }
+                            RouteId::Logs => {
+                                let len = app.logs.page_len();
+                                if len > 0 {
+                                    if let Some(i) = app.logs.table_state.selected() {
+                                        app.logs.table_state.select(Some((i + len - 1) % len));
+                                    } else {
+                                        app.logs.table_state.select(Some(0));
+                                    }
+                                }
+                            }
+                            RouteId::Reorgs => {
+                                let len = app.chain_store.orphaned_blocks().len();
+                                if len > 0 {
+                                    if let Some(i) = app.reorgs_table_state.selected() {
+                                        app.reorgs_table_state.select(Some((i + len - 1) % len));
+                                    } else {
+                                        app.reorgs_table_state.select(Some(0));
+                                    }
+                                }
+                            }
+                            RouteId::Withdrawals(Some(withdrawal_scan)) => {
+                                let len = app.filter_withdrawal_scan(&withdrawal_scan).row_count();
+                                if len > 0 {
+                                    if let Some(i) = app.withdrawal_scan_table_state.selected() {
+                                        app.withdrawal_scan_table_state
+                                            .select(Some((i + len - 1) % len));
+                                    } else {
+                                        app.withdrawal_scan_table_state.select(Some(0));
+                                    }
+                                }
+                            }
                             RouteId::Transaction(Some(transaction)) => {
                                 if let Some(i) = app.transaction_detail_list_state.selected() {
                                     app.transaction_detail_list_state.select(Some(
@@ -535,24 +1092,95 @@ where
                                     }
                                 }
                             }
-                            RouteId::AddressInfo(_) => match SelectableContractDetailItem::from(
-                                app.contract_list_state.selected().unwrap_or(
-                                    SelectableContractDetailItem::ContractSourceCode.into(),
-                                ),
-                            ) {
-                                SelectableContractDetailItem::ContractSourceCode => {
-                                    app.source_code_scroll =
-                                        app.source_code_scroll.saturating_sub(1);
-                                    app.source_code_scroll_state = app
-                                        .source_code_scroll_state
-                                        .position(app.source_code_scroll);
+                            RouteId::AddressInfo(address_info) => {
+                                match SelectableContractDetailItem::from(
+                                    app.contract_list_state.selected().unwrap_or(
+                                        SelectableContractDetailItem::ContractSourceCode.into(),
+                                    ),
+                                ) {
+                                    SelectableContractDetailItem::ContractSourceCode => {
+                                        if app.source_code.is_file_tree_focused {
+                                            if let Some(address_info) = address_info.as_ref() {
+                                                app.source_code.select_file(
+                                                    &file_tree(&address_info.source_files),
+                                                    false,
+                                                );
+                                                app.source_code_scroll = 0;
+                                            }
+                                        } else {
+                                            app.source_code_scroll =
+                                                app.source_code_scroll.saturating_sub(1);
+                                        }
+                                        app.source_code_scroll_state = app
+                                            .source_code_scroll_state
+                                            .position(app.source_code_scroll);
+                                    }
+                                    SelectableContractDetailItem::ContractAbi => {
+                                        app.abi_scroll = app.abi_scroll.saturating_sub(1);
+                                        app.abi_scroll_state =
+                                            app.abi_scroll_state.position(app.abi_scroll);
+                                    }
+                                    SelectableContractDetailItem::Bytecode => {
+                                        app.bytecode.scroll = app.bytecode.scroll.saturating_sub(1);
+                                        app.bytecode.scroll_state =
+                                            app.bytecode.scroll_state.position(app.bytecode.scroll);
+                                    }
+                                    SelectableContractDetailItem::Storage => {
+                                        app.storage.scroll = app.storage.scroll.saturating_sub(1);
+                                    }
+                                    SelectableContractDetailItem::ReadContract => {
+                                        if let Some(form) = app.contract_call.form.as_mut() {
+                                            form.previous();
+                                        } else if let Some(abi) = address_info
+                                            .and_then(|address_info| address_info.contract_abi)
+                                        {
+                                            let len = read_functions(&abi).len();
+                                            if len > 0 {
+                                                if let Some(i) = app
+                                                    .contract_call
+                                                    .functions_list_state
+                                                    .selected()
+                                                {
+                                                    app.contract_call
+                                                        .functions_list_state
+                                                        .select(Some((i + len - 1) % len));
+                                                } else {
+                                                    app.contract_call
+                                                        .functions_list_state
+                                                        .select(Some(0));
+                                                }
+                                            }
+                                        }
+                                    }
                                 }
-                                SelectableContractDetailItem::ContractAbi => {
-                                    app.abi_scroll = app.abi_scroll.saturating_sub(1);
-                                    app.abi_scroll_state =
-                                        app.abi_scroll_state.position(app.abi_scroll);
+                            }
+                            RouteId::StateChangesOfTransaction(_) => {
+                                app.state_changes_scroll =
+                                    app.state_changes_scroll.saturating_sub(1);
+                                app.state_changes_scroll_state = app
+                                    .state_changes_scroll_state
+                                    .position(app.state_changes_scroll);
+                            }
+                            RouteId::BlobsOfTransaction(_) => {
+                                app.blobs_scroll = app.blobs_scroll.saturating_sub(1);
+                                app.blobs_scroll_state =
+                                    app.blobs_scroll_state.position(app.blobs_scroll);
+                            }
+                            RouteId::CallTraceOfTransaction(Ok(call_trace)) => {
+                                let len = call_trace
+                                    .root
+                                    .flatten(&app.collapsed_call_trace_nodes)
+                                    .len();
+                                if let Some(i) = app.call_trace_list_state.selected() {
+                                    app.call_trace_list_state.select(Some((i + len - 1) % len));
+                                } else {
+                                    app.call_trace_list_state.select(Some(0));
                                 }
-                            },
+                            }
+                            RouteId::Simulation => {
+                                app.simulation.result_scroll =
+                                    app.simulation.result_scroll.saturating_sub(1);
+                            }
                             _ => {}
                         },
                         _ => {}
@@ -561,6 +1189,7 @@ where
                         ActiveBlock::LatestBlocks => {
                             let height = terminal.size().unwrap().height as usize;
                             app.statistics = Statistics::new();
+                            app.dashboard.refresh_all();
                             app.latest_blocks = None;
                             app.dispatch(IoEvent::GetStatistics);
                             app.dispatch(IoEvent::GetLatestBlocks {
@@ -574,6 +1203,15 @@ where
                                 n: (height - 3 * 4) / 2 - 4,
                             });
                         }
+                        ActiveBlock::Main => {
+                            if let RouteId::GasTracker(Some(fee_history)) =
+                                app.get_current_route().get_id()
+                            {
+                                app.dispatch(IoEvent::GetFeeHistory {
+                                    block_count: fee_history.block_count() as u64,
+                                });
+                            }
+                        }
                         _ => {}
                     },
                     event::KeyCode::Right => {
@@ -603,6 +1241,15 @@ where
                                         .into(),
                                     ));
                                 }
+                                RouteId::Logs => {
+                                    app.logs.page = (app.logs.page + 1) % app.logs.page_count();
+                                    app.logs.table_state.select(None);
+                                }
+                                RouteId::Epoch(Some(epoch)) => {
+                                    app.dispatch(IoEvent::GetEpoch {
+                                        epoch: Some(epoch.epoch + 1),
+                                    });
+                                }
                                 _ => {}
                             }
                         }
@@ -634,15 +1281,179 @@ where
                                         .into(),
                                     ));
                                 }
+                                RouteId::Logs => {
+                                    app.logs.page = (app.logs.page + app.logs.page_count() - 1)
+                                        % app.logs.page_count();
+                                    app.logs.table_state.select(None);
+                                }
+                                RouteId::Epoch(Some(epoch)) => {
+                                    app.dispatch(IoEvent::GetEpoch {
+                                        epoch: Some(epoch.epoch.saturating_sub(1)),
+                                    });
+                                }
                                 _ => {}
                             }
                         }
                     }
+                    event::KeyCode::Char('b') => {
+                        if let RouteId::Epoch(Some(epoch)) = app.get_current_route().get_id() {
+                            if let Some(number) = app
+                                .epoch_table_state
+                                .selected()
+                                .and_then(|i| epoch.slots.get(i))
+                                .and_then(|slot| slot.execution_block_number)
+                            {
+                                app.dispatch(IoEvent::GetBlock {
+                                    number,
+                                    is_searching: false,
+                                });
+                            }
+                        }
+                    }
                     event::KeyCode::Char('?') => {
                         app.show_popup = true;
                     }
+                    event::KeyCode::Char('i') => {
+                        if let Some(form) = app.get_active_form_mut() {
+                            form.is_editing = true;
+                        }
+                    }
+                    event::KeyCode::Tab => {
+                        if is_source_code_tab(app) {
+                            app.source_code.is_file_tree_focused =
+                                !app.source_code.is_file_tree_focused;
+                        } else if let Some(form) = app.get_active_form_mut() {
+                            form.next();
+                        }
+                    }
+                    event::KeyCode::Char('/') => {
+                        if is_source_code_tab(app) {
+                            let mut search = Form::with_fields(vec![TextField::new("/", "")]);
+                            search.is_editing = true;
+                            app.source_code.search = Some(search);
+                        } else if let Some(target) = app.filter_target() {
+                            app.filter.open(target);
+                        }
+                    }
+                    event::KeyCode::Char('x') => {
+                        if let RouteId::AddressInfo(Some(_)) = app.get_current_route().get_id() {
+                            if let Some(SelectableContractDetailItem::Bytecode) = app
+                                .contract_list_state
+                                .selected()
+                                .map(SelectableContractDetailItem::from)
+                            {
+                                app.bytecode.is_raw = !app.bytecode.is_raw;
+                                app.bytecode.scroll = 0;
+                            }
+                        }
+                    }
+                    event::KeyCode::Char('t') => match app.get_current_route().get_id() {
+                        RouteId::Logs => app.logs.next_event(),
+                        RouteId::TransactionsOfBlock(Some(block)) => {
+                            update_block_transactions_view(app, &block, |view, _| {
+                                view.next_transaction_type()
+                            });
+                        }
+                        _ => {}
+                    },
+                    event::KeyCode::Char('o') => {
+                        if let RouteId::TransactionsOfBlock(Some(block)) =
+                            app.get_current_route().get_id()
+                        {
+                            update_block_transactions_view(app, &block, |view, _| view.next_sort());
+                        }
+                    }
+                    event::KeyCode::Char('O') => {
+                        if let RouteId::TransactionsOfBlock(Some(block)) =
+                            app.get_current_route().get_id()
+                        {
+                            update_block_transactions_view(app, &block, |view, _| {
+                                view.toggle_order()
+                            });
+                        }
+                    }
+                    event::KeyCode::Char('f') => {
+                        if let RouteId::TransactionsOfBlock(Some(block)) =
+                            app.get_current_route().get_id()
+                        {
+                            update_block_transactions_view(app, &block, |view, _| {
+                                view.next_status_filter()
+                            });
+                        }
+                    }
+                    event::KeyCode::Char('m') => {
+                        if let RouteId::TransactionsOfBlock(Some(block)) =
+                            app.get_current_route().get_id()
+                        {
+                            update_block_transactions_view(app, &block, |view, transaction| {
+                                match transaction {
+                                    Some(transaction) => view.toggle_selector(transaction),
+                                    None => view.selector = None,
+                                }
+                            });
+                        }
+                    }
+                    event::KeyCode::Char('g') => {
+                        if let RouteId::TransactionsOfBlock(Some(_)) =
+                            app.get_current_route().get_id()
+                        {
+                            app.block_transactions.is_grouped = !app.block_transactions.is_grouped;
+                            app.transactions_table_state.select(None);
+                        }
+                    }
+                    event::KeyCode::Char('w') => {
+                        if let RouteId::Logs = app.get_current_route().get_id() {
+                            if let Some(Ok(_)) = app.logs.result.as_ref() {
+                                let logs = app
+                                    .logs
+                                    .visible_logs()
+                                    .into_iter()
+                                    .cloned()
+                                    .collect::<Vec<_>>();
+                                let path =
+                                    format!("logs-{}.csv", Utc::now().format("%Y%m%d%H%M%S"));
+                                app.logs.message =
+                                    Some(match std::fs::write(&path, to_csv(&logs)) {
+                                        Ok(_) => format!("Exported to {path}"),
+                                        Err(err) => format!("Failed to export: {err}"),
+                                    });
+                            }
+                        }
+                    }
+                    event::KeyCode::Char('n') => {
+                        jump_to_source_match(app, true, false);
+                    }
+                    event::KeyCode::Char('N') => {
+                        jump_to_source_match(app, false, false);
+                    }
+                    event::KeyCode::BackTab => {
+                        if let Some(form) = app.get_active_form_mut() {
+                            form.previous();
+                        }
+                    }
                     event::KeyCode::Esc => {
-                        app.show_popup = false;
+                        if app.show_popup {
+                            app.show_popup = false;
+                        } else if is_source_code_tab(app) {
+                            app.source_code.search = None;
+                        } else if let RouteId::Logs = app.get_current_route().get_id() {
+                            app.logs.table_state.select(None);
+                        } else if let RouteId::TransactionsOfBlock(Some(block)) =
+                            app.get_current_route().get_id()
+                        {
+                            if app.block_transactions.has_filters()
+                                || app.block_transactions.is_grouped
+                            {
+                                update_block_transactions_view(app, &block, |view, _| {
+                                    view.clear_filters()
+                                });
+                            }
+                        } else if let RouteId::AddressInfo(_) = app.get_current_route().get_id() {
+                            if app.get_active_form_mut().is_some() {
+                                app.contract_call.form = None;
+                                app.contract_call.result = None;
+                            }
+                        }
                     }
                     _ => {}
                 }
@@ -656,9 +1467,110 @@ where
                         app.paste(data);
                     }
                 }
+            } else if let Some(form) = app.get_active_form_mut() {
+                if form.is_editing {
+                    if let Some(field) = form.selected_field_mut() {
+                        field.paste(&data);
+                    }
+                }
             }
         }
         _ => {}
     }
     false
 }
+
+/// Applies `update` to the sorting and filters of the transactions of `block`, given the
+/// transaction under the cursor. The cursor stays on that transaction while it is shown.
+fn update_block_transactions_view(
+    app: &mut App,
+    block: &BlockWithTransactionReceipts<Transaction>,
+    update: impl FnOnce(&mut BlockTransactionsView, Option<&Transaction>),
+) {
+    let selected = if app.block_transactions.is_grouped {
+        None
+    } else {
+        app.transactions_table_state.selected().and_then(|i| {
+            app.block_transactions
+                .indices(block, app.filter.get(FilterTarget::BlockTransactions))
+                .get(i)
+                .copied()
+        })
+    };
+
+    update(
+        &mut app.block_transactions,
+        selected.and_then(|i| block.block.transactions.get(i)),
+    );
+
+    let position = selected.and_then(|selected| {
+        app.block_transactions
+            .indices(block, app.filter.get(FilterTarget::BlockTransactions))
+            .iter()
+            .position(|&i| i == selected)
+    });
+    app.transactions_table_state.select(
+        position.or(
+            if app
+                .block_transactions
+                .row_count(block, app.filter.get(FilterTarget::BlockTransactions))
+                > 0
+            {
+                Some(0)
+            } else {
+                None
+            },
+        ),
+    );
+}
+
+fn is_source_code_tab(app: &App) -> bool {
+    if let RouteId::AddressInfo(Some(address_info)) = app.get_current_route().get_id() {
+        !address_info.source_files.is_empty()
+            && matches!(
+                app.contract_list_state
+                    .selected()
+                    .map(SelectableContractDetailItem::from),
+                Some(SelectableContractDetailItem::ContractSourceCode)
+            )
+    } else {
+        false
+    }
+}
+
+/// Scrolls the source code to the next (or previous) line matching the search query.
+fn jump_to_source_match(app: &mut App, is_forward: bool, is_inclusive: bool) {
+    if !is_source_code_tab(app) {
+        return;
+    }
+    if let (RouteId::AddressInfo(Some(address_info)), Some(query)) =
+        (app.get_current_route().get_id(), app.source_code.query())
+    {
+        let rows = file_tree(&address_info.source_files);
+        if let Some(file) = address_info
+            .source_files
+            .get(app.source_code.selected_file_index(&rows))
+        {
+            let matches = file.matches(&query);
+            let current = app.source_code_scroll as usize;
+            let line = if is_forward {
+                matches
+                    .iter()
+                    .find(|&&i| i > current || (is_inclusive && i == current))
+                    .or(matches.first())
+            } else {
+                matches
+                    .iter()
+                    .rev()
+                    .find(|&&i| i < current)
+                    .or(matches.last())
+            };
+            if let Some(&line) = line {
+                app.source_code_scroll = line as u16;
+                app.source_code_scroll_state = app
+                    .source_code_scroll_state
+                    .position(app.source_code_scroll);
+            }
+        }
+    }
+}
@@ -0,0 +1,990 @@
+use crate::{
+    app::block_transactions::{selector, transaction_type_label},
+    ethers::{
+        logs::LogEntry,
+        transaction::{effective_gas_price, TransactionFee},
+        types::ERC20Token,
+        withdrawals::ScannedWithdrawal,
+    },
+    widget::{Form, TextField},
+};
+use anyhow::{bail, Context, Result};
+use ethers::core::{
+    abi::{Abi, AbiParser},
+    types::{Address, Transaction, TransactionReceipt, Withdrawal, U256, U64},
+    utils::{id, parse_units},
+};
+use std::collections::HashMap;
+
+/// Fields compared with an address, which only accept addresses, tickers, ENS names or `null`.
+const ADDRESS_FIELDS: [&str; 3] = ["from", "to", "address"];
+
+/// Functions matched by name in `method == <name>` before their signatures are looked up.
+const KNOWN_FUNCTIONS: [&str; 12] = [
+    "transfer(address,uint256)",
+    "transferFrom(address,address,uint256)",
+    "approve(address,uint256)",
+    "deposit()",
+    "withdraw(uint256)",
+    "multicall(bytes[])",
+    "multicall(uint256,bytes[])",
+    "execute(bytes,bytes[])",
+    "execute(bytes,bytes[],uint256)",
+    "swapExactTokensForTokens(uint256,uint256,address[],address,uint256)",
+    "swapExactETHForTokens(uint256,address[],address,uint256)",
+    "swapExactTokensForETH(uint256,uint256,address[],address,uint256)",
+];
+
+const TRANSACTION_FIELDS: [&str; 12] = [
+    "hash",
+    "block",
+    "from",
+    "to",
+    "value",
+    "gas_price",
+    "gas_used",
+    "fee",
+    "status",
+    "type",
+    "method",
+    "nonce",
+];
+const WITHDRAWAL_FIELDS: [&str; 5] = ["index", "validator", "address", "amount", "block"];
+
+/// The tables a filter can be typed into. Each table keeps its own filter. The address screen
+/// lists no transactions of the address, so there is no address history to filter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FilterTarget {
+    BlockTransactions,
+    LatestTransactions,
+    BlockWithdrawals,
+    Withdrawals,
+    Logs,
+}
+
+impl FilterTarget {
+    /// Fields of the rows, or `None` when the rows have fields of their own such as decoded
+    /// event parameters.
+    fn fields(&self) -> Option<&'static [&'static str]> {
+        match self {
+            Self::BlockTransactions | Self::LatestTransactions => Some(&TRANSACTION_FIELDS[..]),
+            Self::BlockWithdrawals | Self::Withdrawals => Some(&WITHDRAWAL_FIELDS[..]),
+            Self::Logs => None,
+        }
+    }
+}
+
+/// A value of a row field or a literal of a filter.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Number(U256),
+    Address(Address),
+    Text(String),
+    Selector([u8; 4]),
+    /// Any of the selectors, from a method name or signature.
+    Selectors(Vec<[u8; 4]>),
+    Null,
+}
+
+impl Value {
+    fn from_option<T>(value: Option<T>, f: impl FnOnce(T) -> Value) -> Self {
+        value.map_or(Value::Null, f)
+    }
+
+    /// Decoded parameters are formatted as text, so numbers and addresses are parsed back.
+    fn parse(value: &str) -> Self {
+        if let Ok(number) = U256::from_dec_str(value) {
+            Value::Number(number)
+        } else if let Ok(address) = value.parse::<Address>() {
+            Value::Address(address)
+        } else {
+            Value::Text(value.to_owned())
+        }
+    }
+}
+
+/// A row of a table that a filter is evaluated against.
+pub trait FilterRow {
+    fn field(&self, name: &str) -> Value;
+}
+
+pub struct TransactionRow<'a> {
+    pub transaction: &'a Transaction,
+    pub receipt: Option<&'a TransactionReceipt>,
+    pub base_fee_per_gas: Option<U256>,
+}
+
+impl FilterRow for TransactionRow<'_> {
+    fn field(&self, name: &str) -> Value {
+        let transaction = self.transaction;
+        match name {
+            "hash" => Value::Text(format!("{:?}", transaction.hash)),
+            "block" => Value::from_option(transaction.block_number, |number| {
+                Value::Number(U256::from(number.as_u64()))
+            }),
+            "from" => Value::Address(transaction.from),
+            "to" => Value::from_option(transaction.to, Value::Address),
+            "value" => Value::Number(transaction.value),
+            "gas_price" => Value::from_option(
+                match self.receipt {
+                    Some(receipt) => {
+                        effective_gas_price(transaction, receipt, self.base_fee_per_gas)
+                    }
+                    None => transaction.gas_price,
+                },
+                Value::Number,
+            ),
+            "gas_used" => Value::from_option(
+                self.receipt.and_then(|receipt| receipt.gas_used),
+                Value::Number,
+            ),
+            "fee" => Value::from_option(
+                self.receipt.and_then(|receipt| {
+                    TransactionFee::new(transaction, receipt, self.base_fee_per_gas).ok()
+                }),
+                |fee| Value::Number(fee.total()),
+            ),
+            "status" => {
+                Value::from_option(self.receipt.and_then(|receipt| receipt.status), |status| {
+                    Value::Text(if status.is_zero() { "fail" } else { "success" }.to_string())
+                })
+            }
+            "type" => {
+                Value::Text(transaction_type_label(transaction.transaction_type).to_lowercase())
+            }
+            "method" => Value::from_option(selector(transaction), Value::Selector),
+            "nonce" => Value::Number(transaction.nonce),
+            _ => Value::Null,
+        }
+    }
+}
+
+impl FilterRow for Withdrawal {
+    fn field(&self, name: &str) -> Value {
+        match name {
+            "index" => Value::Number(U256::from(self.index.as_u64())),
+            "validator" => Value::Number(U256::from(self.validator_index.as_u64())),
+            "address" => Value::Address(self.address),
+            // Amounts are in Gwei, so that `amount > 32 ether` works like other values
+            "amount" => Value::Number(self.amount * U256::exp10(9)),
+            _ => Value::Null,
+        }
+    }
+}
+
+impl FilterRow for ScannedWithdrawal {
+    fn field(&self, name: &str) -> Value {
+        match name {
+            "block" => Value::Number(U256::from(self.block_number.as_u64())),
+            name => self.withdrawal.field(name),
+        }
+    }
+}
+
+impl FilterRow for LogEntry {
+    fn field(&self, name: &str) -> Value {
+        match name {
+            "address" => Value::Address(self.log.address),
+            "event" => Value::from_option(self.log.event.as_ref(), |(event, _)| {
+                Value::Text(event.to_owned())
+            }),
+            "block" => Value::from_option(self.block_number, |number: U64| {
+                Value::Number(U256::from(number.as_u64()))
+            }),
+            "tx" | "hash" => Value::from_option(self.transaction_hash, |hash| {
+                Value::Text(format!("{hash:?}"))
+            }),
+            "index" => Value::from_option(self.log_index, Value::Number),
+            name => Value::from_option(
+                self.log.event.as_ref().and_then(|(_, params)| {
+                    params
+                        .iter()
+                        .find(|(param, _)| param == name)
+                        .map(|(_, value)| value)
+                }),
+                |value| Value::parse(value),
+            ),
+        }
+    }
+}
+
+/// Names that literals can refer to.
+pub struct FilterContext<'a> {
+    pub erc20_tokens: &'a [ERC20Token],
+    pub address2ens_id: &'a HashMap<Address, Option<String>>,
+    /// Verified ABIs of the contracts opened, whose functions are matched by name.
+    pub abis: Vec<&'a Abi>,
+    /// Signatures looked up for the selectors of the listed transactions.
+    pub signatures: &'a HashMap<[u8; 4], Vec<String>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operator {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Compare {
+        field: String,
+        operator: Operator,
+        value: Value,
+    },
+}
+
+impl Expr {
+    fn eval(&self, row: &dyn FilterRow) -> bool {
+        match self {
+            Self::And(a, b) => a.eval(row) && b.eval(row),
+            Self::Or(a, b) => a.eval(row) || b.eval(row),
+            Self::Not(a) => !a.eval(row),
+            Self::Compare {
+                field,
+                operator,
+                value,
+            } => compare(&row.field(field), *operator, value),
+        }
+    }
+}
+
+fn compare(field: &Value, operator: Operator, value: &Value) -> bool {
+    let is_equal = match (field, value) {
+        (Value::Number(a), Value::Number(b)) => {
+            return match operator {
+                Operator::Eq => a == b,
+                Operator::Ne => a != b,
+                Operator::Gt => a > b,
+                Operator::Ge => a >= b,
+                Operator::Lt => a < b,
+                Operator::Le => a <= b,
+            }
+        }
+        (Value::Text(a), Value::Text(b)) => a.eq_ignore_ascii_case(b),
+        (Value::Selector(a), Value::Selectors(b)) => b.contains(a),
+        (a, b) => a == b,
+    };
+    match operator {
+        Operator::Eq => is_equal,
+        Operator::Ne => !is_equal,
+        // Only numbers are ordered
+        _ => false,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(String),
+    Hex(String),
+    Text(String),
+    Operator(Operator),
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>> {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let take_while = |i: &mut usize, f: &dyn Fn(char) -> bool| {
+            let start = *i;
+            while *i < chars.len() && f(chars[*i]) {
+                *i += 1;
+            }
+            chars[start..*i].iter().collect::<String>()
+        };
+        match (c, next) {
+            (c, _) if c.is_whitespace() => i += 1,
+            ('&', Some('&')) => {
+                tokens.push(Token::And);
+                i += 2;
+            }
+            ('|', Some('|')) => {
+                tokens.push(Token::Or);
+                i += 2;
+            }
+            ('=', Some('=')) | ('!', Some('=')) | ('>', Some('=')) | ('<', Some('=')) => {
+                tokens.push(Token::Operator(match c {
+                    '=' => Operator::Eq,
+                    '!' => Operator::Ne,
+                    '>' => Operator::Ge,
+                    _ => Operator::Le,
+                }));
+                i += 2;
+            }
+            ('>', _) => {
+                tokens.push(Token::Operator(Operator::Gt));
+                i += 1;
+            }
+            ('<', _) => {
+                tokens.push(Token::Operator(Operator::Lt));
+                i += 1;
+            }
+            ('!', _) => {
+                tokens.push(Token::Not);
+                i += 1;
+            }
+            ('(', _) => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            (')', _) => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            ('"', _) | ('\'', _) => {
+                i += 1;
+                let text = take_while(&mut i, &|ch| ch != c);
+                if i == chars.len() {
+                    bail!("Unterminated string: {c}{text}");
+                }
+                i += 1;
+                tokens.push(Token::Text(text));
+            }
+            ('0', Some('x')) | ('0', Some('X')) => {
+                i += 2;
+                tokens.push(Token::Hex(format!(
+                    "0x{}",
+                    take_while(&mut i, &|ch| ch.is_ascii_hexdigit())
+                )));
+            }
+            (c, _) if c.is_ascii_digit() => {
+                tokens.push(Token::Number(take_while(&mut i, &|ch| {
+                    ch.is_ascii_digit() || ch == '.' || ch == '_'
+                })));
+            }
+            (c, _) if c.is_alphanumeric() || c == '_' => {
+                // Dots and hyphens are kept for ENS names and transaction types such as `eip-1559`
+                let mut ident = take_while(&mut i, &|ch| {
+                    ch.is_alphanumeric() || ch == '_' || ch == '.' || ch == '-'
+                });
+                // A parenthesis right after a name starts the parameters of a function signature
+                // such as `transfer(address,uint256)`
+                if chars.get(i) == Some(&'(') {
+                    let start = i;
+                    let mut depth = 0;
+                    while i < chars.len() {
+                        match chars[i] {
+                            '(' => depth += 1,
+                            ')' => depth -= 1,
+                            _ => {}
+                        }
+                        i += 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    if depth != 0 {
+                        bail!("Missing `)` in {ident}");
+                    }
+                    ident.extend(&chars[start..i]);
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            (c, _) => bail!("Unexpected character: {c}"),
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    position: usize,
+    target: FilterTarget,
+    context: &'a FilterContext<'a>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<Expr> {
+        let mut expr = self.parse_and()?;
+        while let Some(Token::Or) = self.peek() {
+            self.next();
+            expr = Expr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr> {
+        let mut expr = self.parse_unary()?;
+        while let Some(Token::And) = self.peek() {
+            self.next();
+            expr = Expr::And(Box::new(expr), Box::new(self.parse_unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr> {
+        match self.next() {
+            Some(Token::Not) => Ok(Expr::Not(Box::new(self.parse_unary()?))),
+            Some(Token::LParen) => {
+                let expr = self.parse_or()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(expr),
+                    _ => bail!("Missing `)`"),
+                }
+            }
+            Some(Token::Ident(field)) => {
+                let field = field.to_lowercase();
+                if let Some(fields) = self.target.fields() {
+                    if !fields.contains(&field.as_str()) {
+                        bail!("Unknown field `{field}`. Fields: {}", fields.join(", "));
+                    }
+                }
+                let operator = match self.next() {
+                    Some(Token::Operator(operator)) => operator,
+                    _ => bail!("Expected an operator after `{field}`"),
+                };
+                let value = self.parse_value(&field)?;
+                Ok(Expr::Compare {
+                    field,
+                    operator,
+                    value,
+                })
+            }
+            Some(token) => bail!("Unexpected {token:?}"),
+            None => bail!("Unexpected end of the filter"),
+        }
+    }
+
+    fn parse_value(&mut self, field: &str) -> Result<Value> {
+        let token = self.next().context("Expected a value")?;
+        if field == "method" {
+            return self.parse_method(&token);
+        }
+        if field == "type" && self.target.fields() == Some(&TRANSACTION_FIELDS[..]) {
+            return parse_transaction_type(&token);
+        }
+
+        Ok(match token {
+            Token::Number(number) => {
+                let number = number.replace('_', "");
+                match self.peek() {
+                    Some(Token::Ident(unit))
+                        if ["wei", "gwei", "ether", "eth"]
+                            .contains(&unit.to_lowercase().as_str()) =>
+                    {
+                        let unit = match unit.to_lowercase().as_str() {
+                            "eth" => "ether".to_string(),
+                            unit => unit.to_string(),
+                        };
+                        self.next();
+                        Value::Number(
+                            parse_units(&number, unit.as_str())
+                                .with_context(|| format!("Invalid amount: {number} {unit}"))?
+                                .into(),
+                        )
+                    }
+                    _ => Value::Number(
+                        U256::from_dec_str(&number)
+                            .with_context(|| format!("Invalid number: {number}"))?,
+                    ),
+                }
+            }
+            Token::Hex(hex) => match hex.parse::<Address>() {
+                Ok(address) if hex.len() == 42 => Value::Address(address),
+                _ if ADDRESS_FIELDS.contains(&field) => bail!("Invalid address: {hex}"),
+                _ => Value::Text(hex),
+            },
+            Token::Text(text) if ADDRESS_FIELDS.contains(&field) => self.resolve(field, &text)?,
+            Token::Text(text) => Value::Text(text),
+            Token::Ident(ident) => self.resolve(field, &ident)?,
+            token => bail!("Expected a value, found {token:?}"),
+        })
+    }
+
+    /// Resolves a bare word: `null`, a status, a token ticker or an ENS name. Fails for the
+    /// address fields when the word is none of them.
+    fn resolve(&self, field: &str, ident: &str) -> Result<Value> {
+        let lowercase = ident.to_lowercase();
+        if lowercase == "null" {
+            return Ok(Value::Null);
+        }
+        if field == "status" {
+            match lowercase.as_str() {
+                "fail" | "failed" | "failure" => return Ok(Value::Text("fail".to_string())),
+                "success" | "succeeded" | "ok" => return Ok(Value::Text("success".to_string())),
+                _ => {}
+            }
+        }
+        if let Some(token) = ERC20Token::find_by_ticker(self.context.erc20_tokens, ident) {
+            return Ok(Value::Address(token.contract_address));
+        }
+        if let Some(address) = self
+            .context
+            .address2ens_id
+            .iter()
+            .find_map(|(address, ens_id)| {
+                ens_id
+                    .as_ref()
+                    .filter(|ens_id| ens_id.eq_ignore_ascii_case(ident))
+                    .map(|_| *address)
+            })
+        {
+            return Ok(Value::Address(address));
+        }
+        if ADDRESS_FIELDS.contains(&field) {
+            bail!(
+                "Unknown address `{ident}`: not an address, a token ticker or a resolved ENS name"
+            );
+        }
+        Ok(Value::Text(ident.to_owned()))
+    }
+
+    /// A selector, a function signature such as `transfer(address,uint256)`, or the name of a
+    /// function of a well-known signature, an opened ABI or the looked up signatures.
+    fn parse_method(&self, token: &Token) -> Result<Value> {
+        let selector = |signature: &str| -> Result<[u8; 4]> {
+            let function = AbiParser::default()
+                .parse_function(signature)
+                .with_context(|| format!("Invalid function signature: {signature}"))?;
+            Ok(function.short_signature())
+        };
+
+        match token {
+            Token::Hex(hex) => {
+                let bytes = ethers::core::utils::hex::decode(hex)
+                    .ok()
+                    .filter(|bytes| bytes.len() == 4)
+                    .with_context(|| format!("Invalid selector: {hex}"))?;
+                Ok(Value::Selectors(vec![[
+                    bytes[0], bytes[1], bytes[2], bytes[3],
+                ]]))
+            }
+            Token::Ident(name) | Token::Text(name) if name.contains('(') => {
+                Ok(Value::Selectors(vec![selector(name)?]))
+            }
+            Token::Ident(name) | Token::Text(name) => {
+                let is_named = |signature: &str| signature.split('(').next() == Some(name.as_str());
+                let mut selectors = KNOWN_FUNCTIONS
+                    .iter()
+                    .filter(|signature| is_named(signature))
+                    .map(id)
+                    .chain(self.context.abis.iter().flat_map(|abi| {
+                        abi.functions
+                            .get(name)
+                            .into_iter()
+                            .flatten()
+                            .map(|function| function.short_signature())
+                    }))
+                    .chain(
+                        self.context
+                            .signatures
+                            .iter()
+                            .filter(|(_, signatures)| {
+                                signatures.iter().any(|signature| is_named(signature))
+                            })
+                            .map(|(selector, _)| *selector),
+                    )
+                    .collect::<Vec<_>>();
+                selectors.sort();
+                selectors.dedup();
+                if selectors.is_empty() {
+                    bail!("Unknown method `{name}`. Use a selector or a signature");
+                }
+                Ok(Value::Selectors(selectors))
+            }
+            token => bail!("Expected a method, found {token:?}"),
+        }
+    }
+}
+
+/// A transaction type by its number such as `2`, or by its name such as `eip-1559`.
+fn parse_transaction_type(token: &Token) -> Result<Value> {
+    let types = (0..4)
+        .map(|i| transaction_type_label(Some(U64::from(i))).to_lowercase())
+        .collect::<Vec<_>>();
+    let transaction_type = match token {
+        Token::Number(number) => number
+            .parse::<usize>()
+            .ok()
+            .and_then(|i| types.get(i).cloned()),
+        Token::Ident(name) | Token::Text(name) => types
+            .iter()
+            .find(|transaction_type| transaction_type.eq_ignore_ascii_case(name))
+            .cloned(),
+        _ => None,
+    };
+    transaction_type.map(Value::Text).with_context(|| {
+        format!(
+            "Unknown transaction type {token:?}. Types: {}",
+            types
+                .iter()
+                .enumerate()
+                .map(|(i, transaction_type)| format!("{i} ({transaction_type})"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    })
+}
+
+/// A parsed filter expression, e.g. `value > 1 ether && to == USDT && status == fail`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Filter {
+    pub text: String,
+    expr: Expr,
+}
+
+impl Filter {
+    pub fn parse(text: &str, target: FilterTarget, context: &FilterContext) -> Result<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(text)?,
+            position: 0,
+            target,
+            context,
+        };
+        let expr = parser.parse_or()?;
+        if let Some(token) = parser.peek() {
+            bail!("Unexpected {token:?}");
+        }
+        Ok(Self {
+            text: text.to_owned(),
+            expr,
+        })
+    }
+
+    pub fn matches(&self, row: &dyn FilterRow) -> bool {
+        self.expr.eval(row)
+    }
+}
+
+/// Indices of the rows matching `filter`, or all of them without a filter.
+pub fn filter_indices<T: FilterRow>(rows: &[T], filter: Option<&Filter>) -> Vec<usize> {
+    rows.iter()
+        .enumerate()
+        .filter(|(_, row)| filter.is_none_or(|filter| filter.matches(*row)))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// The filter bar and the filters of each table.
+#[derive(Default)]
+pub struct FilterState {
+    /// The bar opened with `/`, and the table it filters.
+    pub bar: Option<(FilterTarget, Form)>,
+    pub filters: HashMap<FilterTarget, Filter>,
+    pub error: Option<String>,
+}
+
+impl FilterState {
+    pub fn get(&self, target: FilterTarget) -> Option<&Filter> {
+        self.filters.get(&target)
+    }
+
+    pub fn open(&mut self, target: FilterTarget) {
+        let text = self
+            .filters
+            .get(&target)
+            .map_or("", |filter| filter.text.as_str());
+        let mut form = Form::with_fields(vec![TextField::new("/", text)]);
+        form.is_editing = true;
+        self.bar = Some((target, form));
+        self.error = None;
+    }
+
+    /// Parses the text of the bar into the filter of its table. An empty text clears the filter.
+    /// The bar stays open while the text is invalid.
+    pub fn submit(&mut self, context: &FilterContext) {
+        let Some((target, form)) = self.bar.as_mut() else {
+            return;
+        };
+        let text = form.values().first().cloned().unwrap_or_default();
+        if text.trim().is_empty() {
+            self.filters.remove(target);
+        } else {
+            match Filter::parse(&text, *target, context) {
+                Ok(filter) => {
+                    self.filters.insert(*target, filter);
+                }
+                Err(err) => {
+                    self.error = Some(err.to_string());
+                    form.is_editing = true;
+                    return;
+                }
+            }
+        }
+        self.bar = None;
+        self.error = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Row(HashMap<&'static str, Value>);
+
+    impl FilterRow for Row {
+        fn field(&self, name: &str) -> Value {
+            self.0.get(name).cloned().unwrap_or(Value::Null)
+        }
+    }
+
+    struct Fixture {
+        erc20_tokens: Vec<ERC20Token>,
+        address2ens_id: HashMap<Address, Option<String>>,
+        abi: Abi,
+        signatures: HashMap<[u8; 4], Vec<String>>,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self {
+                erc20_tokens: vec![ERC20Token {
+                    name: "Tether USD".to_string(),
+                    ticker: "USDT".to_string(),
+                    contract_address: Address::from_low_u64_be(1),
+                    decimals: Some(6),
+                }],
+                address2ens_id: HashMap::from([
+                    (Address::from_low_u64_be(2), Some("vitalik.eth".to_string())),
+                    (Address::from_low_u64_be(3), None),
+                ]),
+                abi: AbiParser::default()
+                    .parse(&["function deposit(uint256 amount)"])
+                    .unwrap(),
+                signatures: HashMap::from([(
+                    id("claimRewards(address)"),
+                    vec!["claimRewards(address)".to_string()],
+                )]),
+            }
+        }
+
+        fn parse(&self, text: &str, target: FilterTarget) -> Result<Filter> {
+            let context = FilterContext {
+                erc20_tokens: &self.erc20_tokens,
+                address2ens_id: &self.address2ens_id,
+                abis: vec![&self.abi],
+                signatures: &self.signatures,
+            };
+            Filter::parse(text, target, &context)
+        }
+
+        /// The value of a filter of a single comparison.
+        fn value(&self, text: &str) -> Result<Value> {
+            match self.parse(text, FilterTarget::LatestTransactions)?.expr {
+                Expr::Compare { value, .. } => Ok(value),
+                expr => panic!("Expected a comparison, found {expr:?}"),
+            }
+        }
+    }
+
+    fn eq(field: &str, number: u64) -> Expr {
+        Expr::Compare {
+            field: field.to_string(),
+            operator: Operator::Eq,
+            value: Value::Number(U256::from(number)),
+        }
+    }
+
+    fn row(a: u64, b: u64, c: u64) -> Row {
+        Row(HashMap::from([
+            ("a", Value::Number(U256::from(a))),
+            ("b", Value::Number(U256::from(b))),
+            ("c", Value::Number(U256::from(c))),
+        ]))
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        let filter = Fixture::new()
+            .parse("a == 1 || b == 2 && c == 3", FilterTarget::Logs)
+            .unwrap();
+        assert_eq!(
+            filter.expr,
+            Expr::Or(
+                Box::new(eq("a", 1)),
+                Box::new(Expr::And(Box::new(eq("b", 2)), Box::new(eq("c", 3))))
+            )
+        );
+        assert!(filter.matches(&row(1, 0, 0)));
+        assert!(filter.matches(&row(0, 2, 3)));
+        assert!(!filter.matches(&row(0, 2, 0)));
+    }
+
+    #[test]
+    fn not_and_parentheses() {
+        let filter = Fixture::new()
+            .parse("!(a == 1 || b == 2) && c == 3", FilterTarget::Logs)
+            .unwrap();
+        assert_eq!(
+            filter.expr,
+            Expr::And(
+                Box::new(Expr::Not(Box::new(Expr::Or(
+                    Box::new(eq("a", 1)),
+                    Box::new(eq("b", 2))
+                )))),
+                Box::new(eq("c", 3))
+            )
+        );
+        assert!(filter.matches(&row(0, 0, 3)));
+        assert!(!filter.matches(&row(1, 0, 3)));
+        assert!(!filter.matches(&row(0, 0, 0)));
+
+        let filter = Fixture::new()
+            .parse("!a == 1 && c == 3", FilterTarget::Logs)
+            .unwrap();
+        assert_eq!(
+            filter.expr,
+            Expr::And(
+                Box::new(Expr::Not(Box::new(eq("a", 1)))),
+                Box::new(eq("c", 3))
+            )
+        );
+    }
+
+    #[test]
+    fn units() {
+        let fixture = Fixture::new();
+        let number = |text: &str| fixture.value(text).unwrap();
+        assert_eq!(
+            number("value == 1.5 ether"),
+            Value::Number(U256::from(1_500_000_000_000_000_000u64))
+        );
+        assert_eq!(
+            number("value == 1.5 ETH"),
+            Value::Number(U256::from(1_500_000_000_000_000_000u64))
+        );
+        assert_eq!(
+            number("gas_price > 1_000 gwei"),
+            Value::Number(U256::from(1_000_000_000_000u64))
+        );
+        assert_eq!(number("value == 42 wei"), Value::Number(U256::from(42)));
+        assert_eq!(number("nonce == 1_000"), Value::Number(U256::from(1000)));
+        assert!(fixture.value("value == 1.5").is_err());
+    }
+
+    #[test]
+    fn tickers_and_ens_names() {
+        let fixture = Fixture::new();
+        assert_eq!(
+            fixture.value("to == USDT").unwrap(),
+            Value::Address(Address::from_low_u64_be(1))
+        );
+        assert_eq!(
+            fixture.value("from == vitalik.eth").unwrap(),
+            Value::Address(Address::from_low_u64_be(2))
+        );
+        assert_eq!(
+            fixture.value("to == 'VITALIK.ETH'").unwrap(),
+            Value::Address(Address::from_low_u64_be(2))
+        );
+        assert_eq!(
+            fixture
+                .value("to == 0x0000000000000000000000000000000000000003")
+                .unwrap(),
+            Value::Address(Address::from_low_u64_be(3))
+        );
+        assert_eq!(fixture.value("to == null").unwrap(), Value::Null);
+        assert_eq!(
+            fixture.value("status == failed").unwrap(),
+            Value::Text("fail".to_string())
+        );
+
+        assert!(fixture.value("to == unknown.eth").is_err());
+        assert!(fixture.value("from == 'usdt'").is_err());
+        assert!(fixture.value("to == 0x1234").is_err());
+    }
+
+    #[test]
+    fn methods() {
+        let fixture = Fixture::new();
+        let transfer = Value::Selectors(vec![id("transfer(address,uint256)")]);
+        assert_eq!(fixture.value("method == 0xa9059cbb").unwrap(), transfer);
+        assert_eq!(
+            fixture
+                .value("method == transfer(address,uint256)")
+                .unwrap(),
+            transfer
+        );
+        assert_eq!(fixture.value("method == transfer").unwrap(), transfer);
+        // Every overload is matched, from the well-known functions and the opened ABIs
+        assert_eq!(
+            fixture.value("method == deposit").unwrap(),
+            Value::Selectors(vec![id("deposit(uint256)"), id("deposit()")])
+        );
+        assert_eq!(
+            fixture.value("method == claimRewards").unwrap(),
+            Value::Selectors(vec![id("claimRewards(address)")])
+        );
+
+        let Value::Selectors(selectors) = fixture.value("method == execute").unwrap() else {
+            panic!("Expected selectors");
+        };
+        assert_eq!(selectors.len(), 2);
+
+        assert!(fixture.value("method == unknownFunction").is_err());
+        assert!(fixture.value("method == 0x1234").is_err());
+    }
+
+    #[test]
+    fn transaction_types() {
+        let fixture = Fixture::new();
+        let eip1559 = Value::Text("eip-1559".to_string());
+        assert_eq!(fixture.value("type == 2").unwrap(), eip1559);
+        assert_eq!(fixture.value("type == EIP-1559").unwrap(), eip1559);
+        assert_eq!(
+            fixture.value("type != 0").unwrap(),
+            Value::Text("legacy".to_string())
+        );
+        assert!(fixture.value("type == 7").is_err());
+        assert!(fixture.value("type == eip-4844").is_err());
+
+        // Decoded event parameters named `type` are compared as they are
+        let filter = fixture.parse("type == 7", FilterTarget::Logs).unwrap();
+        assert!(filter.matches(&Row(HashMap::from([(
+            "type",
+            Value::Number(U256::from(7))
+        )]))));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let fixture = Fixture::new();
+        let error = |text: &str| {
+            fixture
+                .parse(text, FilterTarget::LatestTransactions)
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(error("amount > 1").starts_with("Unknown field `amount`"));
+        assert_eq!(error("(value > 1 || nonce == 2"), "Missing `)`");
+        assert_eq!(error("value 1"), "Expected an operator after `value`");
+        assert_eq!(error("value >"), "Expected a value");
+        assert_eq!(
+            error("method == 'transfer"),
+            "Unterminated string: 'transfer"
+        );
+        assert_eq!(error("value > 1 nonce == 2"), "Unexpected Ident(\"nonce\")");
+        assert_eq!(error("value > 1 &&"), "Unexpected end of the filter");
+        assert_eq!(error("value # 1"), "Unexpected character: #");
+        assert_eq!(
+            error("method == transfer(address"),
+            "Missing `)` in transfer"
+        );
+        assert!(error("method == frobnicate").starts_with("Unknown method `frobnicate`"));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{
+    ethers::{
+        contract_call::{parse_block_id, split_arguments},
+        event_log::KNOWN_EVENTS,
+        logs::{encode_topic, LogEntry, LogsRequest, LogsResult},
+    },
+    widget::{Form, TextField},
+};
+use anyhow::{bail, Context, Result};
+use ethers::core::types::{Address, BlockId, BlockNumber, H256};
+use ratatui::widgets::TableState;
+
+const ADDRESSES: usize = 0;
+const EVENT: usize = 1;
+const TOPICS: [usize; 3] = [2, 3, 4];
+const FROM_BLOCK: usize = 5;
+const TO_BLOCK: usize = 6;
+
+pub const PAGE_SIZE: usize = 20;
+
+/// State of the "Logs" screen.
+pub struct LogsState {
+    pub form: Form,
+    /// Event signatures that `t` cycles through as topic0.
+    pub events: Vec<String>,
+    pub result: Option<Result<LogsResult, String>>,
+    /// Indices of the logs shown when the result is filtered.
+    pub visible: Option<Vec<usize>>,
+    pub page: usize,
+    pub table_state: TableState,
+    pub message: Option<String>,
+}
+
+impl Default for LogsState {
+    fn default() -> Self {
+        Self {
+            form: Form::with_fields(vec![
+                TextField::new("addresses", ""),
+                TextField::new("event / topic0", ""),
+                TextField::new("topic1", ""),
+                TextField::new("topic2", ""),
+                TextField::new("topic3", ""),
+                TextField::new("from block", "latest"),
+                TextField::new("to block", "latest"),
+            ]),
+            events: KNOWN_EVENTS
+                .iter()
+                .map(|event| event.trim_start_matches("event ").to_owned())
+                .collect(),
+            result: None,
+            visible: None,
+            page: 0,
+            table_state: TableState::default(),
+            message: None,
+        }
+    }
+}
+
+impl LogsState {
+    pub fn request(&self) -> Result<LogsRequest> {
+        let values = self.form.values();
+
+        let addresses = split_arguments(&values[ADDRESSES])
+            .iter()
+            .map(|address| {
+                address
+                    .parse::<Address>()
+                    .with_context(|| format!("Invalid address: {address}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let parse_topics = |i: usize| -> Result<Vec<H256>> {
+            split_arguments(&values[i])
+                .iter()
+                .map(|value| encode_topic(value))
+                .collect()
+        };
+
+        let parse_block = |i: usize| -> Result<BlockNumber> {
+            match parse_block_id(&values[i])? {
+                BlockId::Number(number) => Ok(number),
+                BlockId::Hash(_) => bail!("{} must be a block number", self.form.fields[i].label),
+            }
+        };
+
+        Ok(LogsRequest {
+            addresses,
+            event: Some(values[EVENT].to_owned()).filter(|event| !event.is_empty()),
+            topics: [
+                parse_topics(TOPICS[0])?,
+                parse_topics(TOPICS[1])?,
+                parse_topics(TOPICS[2])?,
+            ],
+            from_block: parse_block(FROM_BLOCK)?,
+            to_block: parse_block(TO_BLOCK)?,
+        })
+    }
+
+    /// Sets topic0 to the next known event signature.
+    pub fn next_event(&mut self) {
+        if self.events.is_empty() {
+            return;
+        }
+        let field = &mut self.form.fields[EVENT];
+        let next = self
+            .events
+            .iter()
+            .position(|event| *event == field.value)
+            .map_or(0, |i| (i + 1) % self.events.len());
+        field.value = self.events[next].to_owned();
+        field.cursor_position = field.value.chars().count();
+    }
+
+    /// The logs shown, after the filter.
+    pub fn visible_logs(&self) -> Vec<&LogEntry> {
+        match (self.result.as_ref(), self.visible.as_ref()) {
+            (Some(Ok(result)), Some(visible)) => visible.iter().map(|i| &result.logs[*i]).collect(),
+            (Some(Ok(result)), None) => result.logs.iter().collect(),
+            _ => vec![],
+        }
+    }
+
+    pub fn logs_len(&self) -> usize {
+        match (self.result.as_ref(), self.visible.as_ref()) {
+            (Some(Ok(_)), Some(visible)) => visible.len(),
+            (Some(Ok(result)), None) => result.logs.len(),
+            _ => 0,
+        }
+    }
+
+    /// Number of logs on the current page.
+    pub fn page_len(&self) -> usize {
+        self.logs_len()
+            .saturating_sub(self.page * PAGE_SIZE)
+            .min(PAGE_SIZE)
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.logs_len().div_ceil(PAGE_SIZE).max(1)
+    }
+
+    /// Index of the selected log in the whole result.
+    pub fn selected_index(&self) -> Option<usize> {
+        let i = self.page * PAGE_SIZE + self.table_state.selected()?;
+        match self.visible.as_ref() {
+            Some(visible) => visible.get(i).copied(),
+            None => Some(i),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    ethers::{
+        contract_call::{parse_block_id, split_arguments},
+        simulation::{Calldata, SimulationRequest, SimulationResult},
+    },
+    widget::{Form, TextField},
+};
+use anyhow::{Context, Result};
+use ethers::core::{
+    types::{Address, Bytes, U256},
+    utils::parse_ether,
+};
+
+const FROM: usize = 0;
+const TO: usize = 1;
+const VALUE: usize = 2;
+const CALLDATA: usize = 3;
+const ARGUMENTS: usize = 4;
+const BLOCK: usize = 5;
+
+/// State of the "Simulate" screen.
+pub struct SimulationState {
+    pub form: Form,
+    pub result: Option<Result<SimulationResult, String>>,
+    pub result_scroll: u16,
+}
+
+impl Default for SimulationState {
+    fn default() -> Self {
+        Self {
+            form: Form::with_fields(vec![
+                TextField::new("from", ""),
+                TextField::new("to", ""),
+                TextField::new("value (ETH)", "0"),
+                TextField::new("calldata / function", ""),
+                TextField::new("arguments", ""),
+                TextField::new("block", "latest"),
+            ]),
+            result: None,
+            result_scroll: 0,
+        }
+    }
+}
+
+impl SimulationState {
+    pub fn request(&self) -> Result<SimulationRequest> {
+        let values = self.form.values();
+
+        let parse_address = |i: usize| -> Result<Option<Address>> {
+            if values[i].is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(values[i].parse::<Address>().with_context(|| {
+                    format!("Invalid address for {}", self.form.fields[i].label)
+                })?))
+            }
+        };
+
+        let calldata = if values[CALLDATA].is_empty() || values[CALLDATA].starts_with("0x") {
+            Calldata::Raw(
+                values[CALLDATA]
+                    .parse::<Bytes>()
+                    .context("Invalid calldata")?,
+            )
+        } else {
+            Calldata::Function {
+                signature: values[CALLDATA].to_owned(),
+                arguments: split_arguments(&values[ARGUMENTS]),
+            }
+        };
+
+        Ok(SimulationRequest {
+            from: parse_address(FROM)?,
+            to: parse_address(TO)?,
+            value: if values[VALUE].is_empty() {
+                U256::zero()
+            } else {
+                parse_ether(&values[VALUE]).context("Invalid value")?
+            },
+            calldata,
+            block: parse_block_id(&values[BLOCK])?,
+        })
+    }
+}
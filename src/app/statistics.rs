@@ -1,31 +1,121 @@
-use ethers::core::types::{Block, Transaction, U256};
+use crate::beacon::Finality;
+use clap::ValueEnum;
+use ethers::core::types::{Address, Block, Transaction, U64};
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::fmt;
 
 #[derive(Clone, Debug)]
 pub struct Statistics {
-    pub ethusd: Option<f64>,
-    pub node_count: Option<usize>,
-    pub suggested_base_fee: Option<U256>,
-    pub med_gas_price: Option<U256>,
     pub last_safe_block: Option<Block<Transaction>>,
     pub last_finalized_block: Option<Block<Transaction>>,
+    pub latest_block_number: Option<U64>,
+    pub finality: Option<Finality>,
 }
 
 impl Statistics {
     pub fn new() -> Self {
         Self {
-            ethusd: None,
-            node_count: None,
-            suggested_base_fee: None,
-            med_gas_price: None,
             last_safe_block: None,
             last_finalized_block: None,
+            latest_block_number: None,
+            finality: None,
         }
     }
 
-    pub const ETHUSD_INDEX: usize = 0;
-    pub const SUGGESTED_BASE_FEE_INDEX: usize = 1;
-    pub const LAST_SAFE_BLOCK_INDEX: usize = 2;
-    pub const NODE_COUNT_INDEX: usize = 3;
-    pub const MED_GAS_PRICE_INDEX: usize = 4;
-    pub const LAST_FINALIZED_BLOCK_INDEX: usize = 5;
+    /// The status of the block `number` and its confirmation count, once the statistics are loaded.
+    pub fn block_status(&self, number: U64) -> Option<(BlockStatus, u64)> {
+        let latest_block_number = self.latest_block_number?;
+        let confirmations = (latest_block_number + 1).saturating_sub(number).as_u64();
+        let is_within = |block: &Option<Block<Transaction>>| {
+            block
+                .as_ref()
+                .and_then(|block| block.number)
+                .is_some_and(|last_number| number <= last_number)
+        };
+
+        Some((
+            if is_within(&self.last_finalized_block) {
+                BlockStatus::Finalized
+            } else if is_within(&self.last_safe_block) {
+                BlockStatus::Safe
+            } else {
+                BlockStatus::Unsafe
+            },
+            confirmations,
+        ))
+    }
+}
+
+/// The state of the connected node, read for the `node_count` tile instead of the Etherscan node
+/// count.
+#[derive(Clone, Debug)]
+pub struct NodeInfo {
+    pub client_version: String,
+    pub peer_count: u64,
+    /// Progress of the sync in percent, or `None` when the node is synced.
+    pub sync_progress: Option<f64>,
+}
+
+/// The node statistic read from a source.
+#[derive(Clone, Debug)]
+pub enum NodeStatistic {
+    /// Number of nodes of the network counted by Etherscan.
+    NodeCount(usize),
+    Node(NodeInfo),
+}
+
+/// Where a statistic is read from. `Auto` uses Etherscan when `ETHERSCAN_API_KEY` is set.
+#[derive(ValueEnum, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum StatisticsSource {
+    Auto,
+    Etherscan,
+    Node,
+}
+
+impl StatisticsSource {
+    pub fn uses_etherscan(&self, has_api_key: bool) -> bool {
+        match self {
+            Self::Auto => has_api_key,
+            Self::Etherscan => true,
+            Self::Node => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct StatisticsConfig {
+    pub ether_price: StatisticsSource,
+    pub gas: StatisticsSource,
+    pub node: StatisticsSource,
+    /// Chainlink aggregator read for the ether price when it comes from the node.
+    pub price_feed: Address,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BlockStatus {
+    Unsafe,
+    Safe,
+    Finalized,
+}
+
+impl BlockStatus {
+    pub fn color(&self) -> Color {
+        match self {
+            Self::Unsafe => Color::Yellow,
+            Self::Safe => Color::Cyan,
+            Self::Finalized => Color::Green,
+        }
+    }
+}
+
+impl fmt::Display for BlockStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unsafe => write!(f, "unsafe"),
+            Self::Safe => write!(f, "safe"),
+            Self::Finalized => write!(f, "finalized"),
+        }
+    }
 }
@@ -1,9 +1,13 @@
-use crate::ethers::types::TransactionWithReceipt;
+use crate::ethers::{transaction::blob_versioned_hashes, types::TransactionWithReceipt};
 
 pub enum SelectableTransactionDetailItem {
-    From,      //0
-    To,        //1
-    InputData, //2
+    From,         //0
+    To,           //1
+    InputData,    //2
+    CallTrace,    //3
+    StateChanges, //4
+    AccessList,   //5
+    Blobs,        //6
 }
 
 impl SelectableTransactionDetailItem {
@@ -12,30 +16,78 @@ impl SelectableTransactionDetailItem {
             Self::From => {
                 if transaction.transaction.to.is_some() {
                     Self::To
+                } else if has_access_list(transaction) {
+                    Self::AccessList
+                } else {
+                    Self::InputData
+                }
+            }
+            Self::To => {
+                if has_access_list(transaction) {
+                    Self::AccessList
+                } else {
+                    Self::InputData
+                }
+            }
+            Self::AccessList => Self::InputData,
+            Self::InputData => Self::CallTrace,
+            Self::CallTrace => Self::StateChanges,
+            Self::StateChanges => {
+                if has_blobs(transaction) {
+                    Self::Blobs
                 } else {
                     Self::From
                 }
             }
-            Self::To => Self::InputData,
-            Self::InputData => Self::From,
+            Self::Blobs => Self::From,
         }
     }
 
     pub fn previous(&self, transaction: &TransactionWithReceipt) -> Self {
         match self {
-            Self::From => Self::InputData,
+            Self::From => {
+                if has_blobs(transaction) {
+                    Self::Blobs
+                } else {
+                    Self::StateChanges
+                }
+            }
             Self::To => Self::From,
-            Self::InputData => {
+            Self::AccessList => {
                 if transaction.transaction.to.is_some() {
                     Self::To
                 } else {
                     Self::From
                 }
             }
+            Self::InputData => {
+                if has_access_list(transaction) {
+                    Self::AccessList
+                } else if transaction.transaction.to.is_some() {
+                    Self::To
+                } else {
+                    Self::From
+                }
+            }
+            Self::CallTrace => Self::InputData,
+            Self::StateChanges => Self::CallTrace,
+            Self::Blobs => Self::StateChanges,
         }
     }
 }
 
+fn has_blobs(transaction: &TransactionWithReceipt) -> bool {
+    !blob_versioned_hashes(&transaction.transaction).is_empty()
+}
+
+fn has_access_list(transaction: &TransactionWithReceipt) -> bool {
+    transaction
+        .transaction
+        .access_list
+        .as_ref()
+        .is_some_and(|access_list| !access_list.0.is_empty())
+}
+
 impl From<usize> for SelectableTransactionDetailItem {
     fn from(i: usize) -> Self {
         if i == 0 {
@@ -44,6 +96,14 @@ impl From<usize> for SelectableTransactionDetailItem {
             Self::To
         } else if i == 2 {
             Self::InputData
+        } else if i == 3 {
+            Self::CallTrace
+        } else if i == 4 {
+            Self::StateChanges
+        } else if i == 5 {
+            Self::AccessList
+        } else if i == 6 {
+            Self::Blobs
         } else {
             unreachable!()
         }
@@ -56,6 +116,10 @@ impl From<SelectableTransactionDetailItem> for usize {
             SelectableTransactionDetailItem::From => 0,
             SelectableTransactionDetailItem::To => 1,
             SelectableTransactionDetailItem::InputData => 2,
+            SelectableTransactionDetailItem::CallTrace => 3,
+            SelectableTransactionDetailItem::StateChanges => 4,
+            SelectableTransactionDetailItem::AccessList => 5,
+            SelectableTransactionDetailItem::Blobs => 6,
         }
     }
 }
@@ -101,3 +165,88 @@ impl From<SelectableInputDataDetailItem> for usize {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::core::types::{
+        transaction::eip2930::{AccessList, AccessListItem},
+        Address, Transaction, H256,
+    };
+
+    fn transaction(
+        to: Option<Address>,
+        has_access_list: bool,
+        has_blobs: bool,
+    ) -> TransactionWithReceipt {
+        let mut transaction = Transaction {
+            to,
+            access_list: Some(AccessList(if has_access_list {
+                vec![AccessListItem {
+                    address: Address::from_low_u64_be(1),
+                    storage_keys: vec![],
+                }]
+            } else {
+                vec![]
+            })),
+            ..Default::default()
+        };
+        if has_blobs {
+            transaction.other.insert(
+                "blobVersionedHashes".to_owned(),
+                vec![format!("{:#x}", H256::from_low_u64_be(1))].into(),
+            );
+        }
+        TransactionWithReceipt {
+            transaction,
+            transaction_receipt: Default::default(),
+            decoded_input_data: None,
+            state_changes: None,
+            revert_reason: None,
+            base_fee_per_gas: None,
+            blob_sidecars: None,
+        }
+    }
+
+    /// The items visited from `From` until coming back to it.
+    fn cycle(transaction: &TransactionWithReceipt, is_next: bool) -> Vec<usize> {
+        let mut items = vec![0];
+        let mut item = SelectableTransactionDetailItem::From;
+        loop {
+            item = if is_next {
+                item.next(transaction)
+            } else {
+                item.previous(transaction)
+            };
+            let i = usize::from(item);
+            if i == 0 || items.len() > 7 {
+                return items;
+            }
+            items.push(i);
+            item = SelectableTransactionDetailItem::from(i);
+        }
+    }
+
+    fn assert_cycle(transaction: &TransactionWithReceipt, items: &[usize]) {
+        assert_eq!(cycle(transaction, true), items);
+        let mut reversed = items[1..].to_vec();
+        reversed.reverse();
+        reversed.insert(0, 0);
+        assert_eq!(cycle(transaction, false), reversed);
+    }
+
+    #[test]
+    fn transfer() {
+        let to = Some(Address::from_low_u64_be(2));
+
+        assert_cycle(&transaction(to, false, false), &[0, 1, 2, 3, 4]);
+        assert_cycle(&transaction(to, true, false), &[0, 1, 5, 2, 3, 4]);
+        assert_cycle(&transaction(to, false, true), &[0, 1, 2, 3, 4, 6]);
+    }
+
+    #[test]
+    fn contract_creation() {
+        assert_cycle(&transaction(None, false, false), &[0, 2, 3, 4]);
+        assert_cycle(&transaction(None, true, false), &[0, 5, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,601 @@
+use crate::ethers::withdrawals::WithdrawalScan;
+use anyhow::{Context, Result};
+use beacon_api_client::mainnet::Client;
+use ethers::core::types::{Address, Bytes, H256, U256, U64};
+use futures::future::join_all;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use url::Url;
+
+pub const SECONDS_PER_SLOT: u64 = 12;
+pub const SLOTS_PER_EPOCH: u64 = 32;
+pub const FAR_FUTURE_EPOCH: u64 = u64::MAX;
+/// Finalized checkpoints normally trail the current epoch by two epochs.
+const FINALITY_STALL_EPOCHS: u64 = 4;
+/// Epochs of attestation rewards shown on the validator page.
+const ATTESTATION_EPOCHS: u64 = 4;
+/// Epochs scanned for the proposals of a validator, about a day.
+const PROPOSAL_EPOCHS: u64 = 225;
+/// Epochs of proposer duties requested at once.
+const EPOCHS_PER_REQUEST: usize = 16;
+/// Validator ids queried at once, to keep the URL short.
+const VALIDATORS_PER_REQUEST: usize = 64;
+
+/// Creates a client for the beacon node given with `--beacon-endpoint`.
+pub fn client(endpoint: Option<&str>) -> Result<Client> {
+    let endpoint = endpoint.context("Set --beacon-endpoint to use the beacon API")?;
+    Ok(Client::new(Url::parse(endpoint)?))
+}
+
+pub async fn get_genesis_time(client: &Client) -> Result<u64> {
+    let genesis = client.get::<Value>("eth/v1/beacon/genesis").await?;
+    parse_u64(&genesis["genesis_time"])
+}
+
+pub fn timestamp_to_slot(timestamp: u64, genesis_time: u64) -> u64 {
+    timestamp.saturating_sub(genesis_time) / SECONDS_PER_SLOT
+}
+
+/// The beacon API encodes integers as decimal strings.
+pub fn parse_u64(value: &Value) -> Result<u64> {
+    value
+        .as_str()
+        .and_then(|value| value.parse::<u64>().ok())
+        .or_else(|| value.as_u64())
+        .with_context(|| format!("Invalid integer: {value}"))
+}
+
+pub fn parse_h256(value: &Value) -> Result<H256> {
+    value
+        .as_str()
+        .and_then(|value| value.parse::<H256>().ok())
+        .with_context(|| format!("Invalid root: {value}"))
+}
+
+/// Identifies a beacon block by its slot or by the timestamp of its execution payload.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BeaconBlockId {
+    Slot(u64),
+    Timestamp(u64),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BeaconBlock {
+    pub root: H256,
+    pub slot: u64,
+    pub proposer_index: u64,
+    pub parent_root: H256,
+    pub state_root: H256,
+    pub graffiti: String,
+    pub attestation_count: usize,
+    /// Participants of the sync aggregate and the size of the sync committee.
+    pub sync_participation: Option<(usize, usize)>,
+    pub execution_block_hash: Option<H256>,
+    pub execution_block_number: Option<U64>,
+}
+
+impl BeaconBlock {
+    pub fn epoch(&self) -> u64 {
+        self.slot / SLOTS_PER_EPOCH
+    }
+}
+
+pub async fn get_beacon_block(client: &Client, id: BeaconBlockId) -> Result<BeaconBlock> {
+    let slot = match id {
+        BeaconBlockId::Slot(slot) => slot,
+        BeaconBlockId::Timestamp(timestamp) => {
+            timestamp_to_slot(timestamp, get_genesis_time(client).await?)
+        }
+    };
+
+    let block = client
+        .get::<Value>(&format!("eth/v2/beacon/blocks/{slot}"))
+        .await?;
+    let root = client
+        .get::<Value>(&format!("eth/v1/beacon/blocks/{slot}/root"))
+        .await?;
+
+    let message = &block["message"];
+    let body = &message["body"];
+
+    let graffiti = parse_h256(&body["graffiti"]).unwrap_or_default();
+    let graffiti = String::from_utf8_lossy(graffiti.as_bytes())
+        .trim_end_matches('\0')
+        .to_owned();
+
+    // The bits are a SSZ bitvector, so every set bit is a participant
+    let sync_participation = body["sync_aggregate"]["sync_committee_bits"]
+        .as_str()
+        .and_then(|bits| bits.parse::<Bytes>().ok())
+        .map(|bits| {
+            (
+                bits.iter().map(|byte| byte.count_ones() as usize).sum(),
+                bits.len() * 8,
+            )
+        });
+
+    let execution_payload = &body["execution_payload"];
+
+    Ok(BeaconBlock {
+        root: parse_h256(&root["root"])?,
+        slot: parse_u64(&message["slot"])?,
+        proposer_index: parse_u64(&message["proposer_index"])?,
+        parent_root: parse_h256(&message["parent_root"])?,
+        state_root: parse_h256(&message["state_root"])?,
+        graffiti,
+        attestation_count: body["attestations"].as_array().map_or(0, |a| a.len()),
+        sync_participation,
+        execution_block_hash: parse_h256(&execution_payload["block_hash"]).ok(),
+        execution_block_number: parse_u64(&execution_payload["block_number"])
+            .ok()
+            .map(U64::from),
+    })
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Checkpoint {
+    pub epoch: u64,
+    pub root: H256,
+}
+
+impl Checkpoint {
+    fn parse(value: &Value) -> Result<Self> {
+        Ok(Self {
+            epoch: parse_u64(&value["epoch"])?,
+            root: parse_h256(&value["root"])?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Finality {
+    pub head_slot: u64,
+    pub justified: Checkpoint,
+    pub finalized: Checkpoint,
+    /// Participation of the sync committee in the head block.
+    pub participation: Option<f64>,
+    /// Unix time of the first slot of the finalized epoch.
+    pub finalized_at: u64,
+}
+
+impl Finality {
+    pub fn current_epoch(&self) -> u64 {
+        self.head_slot / SLOTS_PER_EPOCH
+    }
+
+    pub fn is_stalled(&self) -> bool {
+        self.current_epoch().saturating_sub(self.finalized.epoch) > FINALITY_STALL_EPOCHS
+    }
+}
+
+pub async fn get_finality(client: &Client) -> Result<Finality> {
+    let checkpoints = client
+        .get::<Value>("eth/v1/beacon/states/head/finality_checkpoints")
+        .await?;
+    let head = client.get::<Value>("eth/v1/beacon/headers/head").await?;
+    let head_slot = parse_u64(&head["header"]["message"]["slot"])?;
+    let finalized = Checkpoint::parse(&checkpoints["finalized"])?;
+    let genesis_time = get_genesis_time(client).await?;
+
+    let participation = get_beacon_block(client, BeaconBlockId::Slot(head_slot))
+        .await
+        .ok()
+        .and_then(|block| block.sync_participation)
+        .map(|(participants, size)| participants as f64 / size as f64);
+
+    Ok(Finality {
+        head_slot,
+        justified: Checkpoint::parse(&checkpoints["current_justified"])?,
+        finalized_at: genesis_time + finalized.epoch * SLOTS_PER_EPOCH * SECONDS_PER_SLOT,
+        finalized,
+        participation,
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SlotStatus {
+    Proposed,
+    Missed,
+    /// A block was proposed but is not part of the canonical chain.
+    Orphaned,
+    Scheduled,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EpochSlot {
+    pub slot: u64,
+    pub proposer_index: Option<u64>,
+    pub status: SlotStatus,
+    pub execution_block_number: Option<U64>,
+    pub transaction_count: Option<usize>,
+    pub gas_used: Option<U256>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Epoch {
+    pub epoch: u64,
+    pub slots: Vec<EpochSlot>,
+}
+
+/// All slots of `epoch`, or of the current epoch when it is `None`.
+pub async fn get_epoch(client: &Client, epoch: Option<u64>) -> Result<Epoch> {
+    let head = client.get::<Value>("eth/v1/beacon/headers/head").await?;
+    let head_slot = parse_u64(&head["header"]["message"]["slot"])?;
+    let epoch = epoch.unwrap_or(head_slot / SLOTS_PER_EPOCH);
+
+    // Duties of old epochs are not available on every node, so the proposers of the headers are
+    // used as a fallback
+    let duties = client
+        .get::<Vec<Value>>(&format!("eth/v1/validator/duties/proposer/{epoch}"))
+        .await
+        .unwrap_or_default();
+
+    let first_slot = epoch * SLOTS_PER_EPOCH;
+    let slots = join_all((first_slot..first_slot + SLOTS_PER_EPOCH).map(|slot| {
+        let duties = &duties;
+        async move {
+            let proposer_index = duties
+                .iter()
+                .find(|duty| parse_u64(&duty["slot"]).ok() == Some(slot))
+                .and_then(|duty| parse_u64(&duty["validator_index"]).ok());
+            get_epoch_slot(client, slot, head_slot, proposer_index).await
+        }
+    }))
+    .await
+    .into_iter()
+    .collect::<Result<Vec<_>>>()?;
+
+    Ok(Epoch { epoch, slots })
+}
+
+async fn get_epoch_slot(
+    client: &Client,
+    slot: u64,
+    head_slot: u64,
+    proposer_index: Option<u64>,
+) -> Result<EpochSlot> {
+    let mut epoch_slot = EpochSlot {
+        slot,
+        proposer_index,
+        status: SlotStatus::Scheduled,
+        execution_block_number: None,
+        transaction_count: None,
+        gas_used: None,
+    };
+    if slot > head_slot {
+        return Ok(epoch_slot);
+    }
+
+    let headers = client
+        .get::<Vec<Value>>(&format!("eth/v1/beacon/headers?slot={slot}"))
+        .await
+        .unwrap_or_default();
+    let Some(header) = headers
+        .iter()
+        .find(|header| header["canonical"].as_bool() == Some(true))
+    else {
+        epoch_slot.status = if headers.is_empty() {
+            SlotStatus::Missed
+        } else {
+            SlotStatus::Orphaned
+        };
+        return Ok(epoch_slot);
+    };
+
+    let block = client
+        .get::<Value>(&format!("eth/v2/beacon/blocks/{slot}"))
+        .await?;
+    let execution_payload = &block["message"]["body"]["execution_payload"];
+
+    Ok(EpochSlot {
+        proposer_index: proposer_index
+            .or_else(|| parse_u64(&header["header"]["message"]["proposer_index"]).ok()),
+        status: SlotStatus::Proposed,
+        execution_block_number: parse_u64(&execution_payload["block_number"])
+            .ok()
+            .map(U64::from),
+        transaction_count: execution_payload["transactions"]
+            .as_array()
+            .map(|transactions| transactions.len()),
+        gas_used: parse_u64(&execution_payload["gas_used"])
+            .ok()
+            .map(U256::from),
+        ..epoch_slot
+    })
+}
+
+/// A validator identified by its index or its BLS public key.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidatorId {
+    Index(u64),
+    Pubkey(Bytes),
+}
+
+impl ValidatorId {
+    /// Parses a 48 byte BLS public key, which is longer than any hash or address.
+    pub fn parse_pubkey(s: &str) -> Option<Self> {
+        s.strip_prefix("0x")
+            .filter(|hex| hex.len() == 96)
+            .and_then(|_| s.parse::<Bytes>().ok())
+            .map(Self::Pubkey)
+    }
+}
+
+impl std::fmt::Display for ValidatorId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Index(index) => write!(f, "{index}"),
+            Self::Pubkey(pubkey) => write!(f, "{pubkey}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Proposal {
+    pub slot: u64,
+    /// `None` for slots that have not come yet.
+    pub is_proposed: Option<bool>,
+}
+
+/// Attestation rewards of an epoch in Gwei. Negative values are penalties for missed duties.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttestationReward {
+    pub epoch: u64,
+    pub head: i64,
+    pub target: i64,
+    pub source: i64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Validator {
+    pub index: u64,
+    pub pubkey: Bytes,
+    pub status: String,
+    /// Balances in Gwei.
+    pub balance: u64,
+    pub effective_balance: u64,
+    pub slashed: bool,
+    pub withdrawal_credentials: H256,
+    pub activation_eligibility_epoch: Option<u64>,
+    pub activation_epoch: Option<u64>,
+    pub exit_epoch: Option<u64>,
+    pub withdrawable_epoch: Option<u64>,
+    pub current_epoch: u64,
+    /// First epoch scanned for proposals, up to the current epoch.
+    pub proposals_from_epoch: u64,
+    /// Proposer duties of the scanned epochs, the latest first.
+    pub proposals: Vec<Proposal>,
+    pub attestation_rewards: Vec<AttestationReward>,
+    /// Withdrawals of the validator in the recent blocks, read from the execution node. `None`
+    /// without a withdrawal address.
+    pub withdrawals: Option<Result<WithdrawalScan, String>>,
+}
+
+impl Validator {
+    /// The execution address of `0x01` and `0x02` withdrawal credentials.
+    pub fn withdrawal_address(&self) -> Option<Address> {
+        match self.withdrawal_credentials[0] {
+            0x01 | 0x02 => Some(Address::from_slice(&self.withdrawal_credentials[12..])),
+            _ => None,
+        }
+    }
+}
+
+pub async fn get_validator(client: &Client, id: &ValidatorId) -> Result<Validator> {
+    let state = client
+        .get::<Value>(&format!("eth/v1/beacon/states/head/validators/{id}"))
+        .await?;
+    let validator = &state["validator"];
+    let parse_epoch = |key: &str| {
+        parse_u64(&validator[key])
+            .ok()
+            .filter(|epoch| *epoch != FAR_FUTURE_EPOCH)
+    };
+    let index = parse_u64(&state["index"])?;
+
+    let head = client.get::<Value>("eth/v1/beacon/headers/head").await?;
+    let head_slot = parse_u64(&head["header"]["message"]["slot"])?;
+    let current_epoch = head_slot / SLOTS_PER_EPOCH;
+
+    let (proposals_from_epoch, proposals) = get_proposals(client, index, head_slot).await?;
+
+    // Rewards of an epoch are available once the next epoch is finished
+    let mut attestation_rewards = vec![];
+    let last_epoch = current_epoch.saturating_sub(1);
+    for epoch in (last_epoch.saturating_sub(ATTESTATION_EPOCHS)..last_epoch).rev() {
+        if let Ok(reward) = get_attestation_reward(client, index, epoch).await {
+            attestation_rewards.push(reward);
+        }
+    }
+
+    Ok(Validator {
+        index,
+        pubkey: validator["pubkey"]
+            .as_str()
+            .and_then(|pubkey| pubkey.parse::<Bytes>().ok())
+            .context("Invalid pubkey")?,
+        status: state["status"].as_str().unwrap_or_default().to_owned(),
+        balance: parse_u64(&state["balance"])?,
+        effective_balance: parse_u64(&validator["effective_balance"])?,
+        slashed: validator["slashed"].as_bool().unwrap_or_default(),
+        withdrawal_credentials: parse_h256(&validator["withdrawal_credentials"])?,
+        activation_eligibility_epoch: parse_epoch("activation_eligibility_epoch"),
+        activation_epoch: parse_epoch("activation_epoch"),
+        exit_epoch: parse_epoch("exit_epoch"),
+        withdrawable_epoch: parse_epoch("withdrawable_epoch"),
+        current_epoch,
+        proposals_from_epoch,
+        proposals,
+        attestation_rewards,
+        withdrawals: None,
+    })
+}
+
+/// Proposals of the validator `index` in the last `PROPOSAL_EPOCHS` epochs, the latest first,
+/// and the first epoch scanned. The scan stops at the first epoch whose duties the node does not
+/// serve, as some nodes only keep the duties of recent epochs.
+async fn get_proposals(
+    client: &Client,
+    index: u64,
+    head_slot: u64,
+) -> Result<(u64, Vec<Proposal>)> {
+    let current_epoch = head_slot / SLOTS_PER_EPOCH;
+    let epochs = (current_epoch.saturating_sub(PROPOSAL_EPOCHS - 1)..=current_epoch)
+        .rev()
+        .collect::<Vec<_>>();
+
+    let mut from_epoch = current_epoch;
+    let mut slots = vec![];
+    'scan: for epochs in epochs.chunks(EPOCHS_PER_REQUEST) {
+        let duties = join_all(epochs.iter().map(|epoch| async move {
+            client
+                .get::<Vec<Value>>(&format!("eth/v1/validator/duties/proposer/{epoch}"))
+                .await
+        }))
+        .await;
+        for (&epoch, duties) in epochs.iter().zip(duties) {
+            let duties = match duties {
+                Ok(duties) => duties,
+                Err(err) if epoch == current_epoch => return Err(err.into()),
+                Err(_) => break 'scan,
+            };
+            from_epoch = epoch;
+            for duty in duties {
+                if parse_u64(&duty["validator_index"])? == index {
+                    slots.push(parse_u64(&duty["slot"])?);
+                }
+            }
+        }
+    }
+    slots.sort_unstable_by(|a, b| b.cmp(a));
+
+    let proposals = join_all(slots.into_iter().map(|slot| async move {
+        // Missed slots have no header
+        let is_proposed = if slot <= head_slot {
+            Some(
+                client
+                    .get::<Value>(&format!("eth/v1/beacon/headers/{slot}"))
+                    .await
+                    .is_ok(),
+            )
+        } else {
+            None
+        };
+        Proposal { slot, is_proposed }
+    }))
+    .await;
+
+    Ok((from_epoch, proposals))
+}
+
+/// Withdrawable epochs of the validators that have one, read from the head state.
+pub async fn get_withdrawable_epochs(
+    client: &Client,
+    indices: &[u64],
+) -> Result<HashMap<u64, u64>> {
+    let mut withdrawable_epochs = HashMap::new();
+    for indices in indices.chunks(VALIDATORS_PER_REQUEST) {
+        let ids = indices
+            .iter()
+            .map(|index| index.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let validators = client
+            .get::<Vec<Value>>(&format!("eth/v1/beacon/states/head/validators?id={ids}"))
+            .await?;
+        for validator in validators {
+            let withdrawable_epoch = parse_u64(&validator["validator"]["withdrawable_epoch"])?;
+            if withdrawable_epoch != FAR_FUTURE_EPOCH {
+                withdrawable_epochs.insert(parse_u64(&validator["index"])?, withdrawable_epoch);
+            }
+        }
+    }
+    Ok(withdrawable_epochs)
+}
+
+async fn get_attestation_reward(
+    client: &Client,
+    index: u64,
+    epoch: u64,
+) -> Result<AttestationReward> {
+    let response = client
+        .http_post(
+            &format!("eth/v1/beacon/rewards/attestations/{epoch}"),
+            &[index.to_string()],
+        )
+        .await?
+        .json::<Value>()
+        .await?;
+    let reward = response["data"]["total_rewards"]
+        .as_array()
+        .and_then(|rewards| rewards.first())
+        .context("No attestation rewards")?;
+    // Penalties are negative decimal strings
+    let parse_i64 = |key: &str| -> Result<i64> {
+        reward[key]
+            .as_str()
+            .and_then(|value| value.parse::<i64>().ok())
+            .with_context(|| format!("Invalid {key}"))
+    };
+
+    Ok(AttestationReward {
+        epoch,
+        head: parse_i64("head")?,
+        target: parse_i64("target")?,
+        source: parse_i64("source")?,
+    })
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlobSidecar {
+    pub index: u64,
+    pub versioned_hash: H256,
+    pub kzg_commitment: Bytes,
+    pub kzg_proof: Bytes,
+    pub blob: Bytes,
+}
+
+impl BlobSidecar {
+    /// Length of the blob without its trailing zero bytes.
+    pub fn size(&self) -> usize {
+        self.blob
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map_or(0, |i| i + 1)
+    }
+}
+
+/// Blob sidecars of the beacon block at `slot`.
+pub async fn get_blob_sidecars(client: &Client, slot: u64) -> Result<Vec<BlobSidecar>> {
+    let sidecars = client
+        .get::<Vec<Value>>(&format!("eth/v1/beacon/blob_sidecars/{slot}"))
+        .await?;
+
+    sidecars
+        .iter()
+        .map(|sidecar| {
+            let parse_bytes = |key: &str| -> Result<Bytes> {
+                sidecar[key]
+                    .as_str()
+                    .and_then(|value| value.parse::<Bytes>().ok())
+                    .with_context(|| format!("Invalid {key}"))
+            };
+            let kzg_commitment = parse_bytes("kzg_commitment")?;
+            Ok(BlobSidecar {
+                index: parse_u64(&sidecar["index"])?,
+                versioned_hash: kzg_to_versioned_hash(&kzg_commitment),
+                kzg_commitment,
+                kzg_proof: parse_bytes("kzg_proof")?,
+                blob: parse_bytes("blob")?,
+            })
+        })
+        .collect()
+}
+
+/// `0x01 || sha256(commitment)[1:]`, as defined in EIP-4844.
+pub fn kzg_to_versioned_hash(kzg_commitment: &[u8]) -> H256 {
+    let mut hash: [u8; 32] = Sha256::digest(kzg_commitment).into();
+    hash[0] = 0x01;
+    H256(hash)
+}
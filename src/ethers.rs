@@ -1,10 +1,28 @@
+pub mod bytecode;
+pub mod contract_call;
+pub mod event_log;
+pub mod fee_history;
+pub mod logs;
+pub mod price_feed;
+pub mod proxy;
+pub mod revert;
+pub mod simulation;
+pub mod source_files;
+pub mod source_provider;
+pub mod state_diff;
+pub mod storage;
+pub mod trace;
+pub mod withdrawals;
+
 pub mod types {
-    use ethers::{
-        core::{
-            abi::Abi,
-            types::{Address, Block, Transaction, TransactionReceipt, U256},
-        },
-        etherscan::contract::ContractMetadata,
+    use super::{
+        bytecode::ContractBytecode, proxy::ProxyInfo, source_files::SourceFile,
+        state_diff::StateChanges, storage::StorageLayout,
+    };
+    use crate::beacon::BlobSidecar;
+    use ethers::core::{
+        abi::Abi,
+        types::{Address, Block, Transaction, TransactionReceipt, U256},
     };
     use serde::{Deserialize, Deserializer};
     use std::cmp::PartialEq;
@@ -16,8 +34,11 @@ pub mod types {
         pub ens_id: Option<String>,
         pub avatar_url: Option<Url>,
         pub contract_abi: Option<Abi>,
-        pub contract_source_code: Option<ContractMetadata>,
+        pub source_files: Vec<SourceFile>,
         pub balance: U256,
+        pub proxy: Option<ProxyInfo>,
+        pub bytecode: Option<ContractBytecode>,
+        pub storage_layout: Option<StorageLayout>,
     }
 
     #[derive(Clone, Debug, PartialEq)]
@@ -25,6 +46,11 @@ pub mod types {
         pub transaction: Transaction,
         pub transaction_receipt: TransactionReceipt,
         pub decoded_input_data: Option<String>,
+        pub state_changes: Option<Result<StateChanges, String>>,
+        pub revert_reason: Option<String>,
+        /// Base fee of the block including the transaction, used to split its fee.
+        pub base_fee_per_gas: Option<U256>,
+        pub blob_sidecars: Option<Result<Vec<BlobSidecar>, String>>,
     }
 
     #[derive(Clone, Debug, PartialEq)]
@@ -39,6 +65,8 @@ pub mod types {
         pub ticker: String,
         #[serde(deserialize_with = "deserialize_address_from_string")]
         pub contract_address: Address,
+        #[serde(default)]
+        pub decimals: Option<u8>,
     }
 
     fn deserialize_address_from_string<'de, D>(deserializer: D) -> Result<Address, D::Error>
@@ -68,35 +96,294 @@ pub mod types {
 } /* types */
 
 pub mod transaction {
-    use anyhow::{bail, Context, Result};
-    use ethers::{
-        core::types::{Block, Transaction, TransactionReceipt},
-        utils::format_ether,
-    };
+    use anyhow::{Context, Result};
+    use ethers::core::types::{Block, Transaction, TransactionReceipt, H256, U256};
+
+    const MIN_BLOB_BASE_FEE: u64 = 1;
+    /// EIP-4844
+    const CANCUN_BLOB_BASE_FEE_UPDATE_FRACTION: u64 = 3338477;
+    /// EIP-7691
+    const PRAGUE_BLOB_BASE_FEE_UPDATE_FRACTION: u64 = 5007716;
+
+    /// The fee paid by a transaction, split by where it goes.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct TransactionFee {
+        pub gas_used: U256,
+        pub effective_gas_price: U256,
+        /// `base_fee_per_gas * gas_used`, burnt since London. `None` when the base fee is unknown.
+        pub burnt: Option<U256>,
+        /// The part of the execution fee paid to the fee recipient. `None` when the base fee is
+        /// unknown.
+        pub tip: Option<U256>,
+        /// `blob_gas_price * blob_gas_used` of EIP-4844 transactions, also burnt.
+        pub blob: Option<U256>,
+        /// The L1 data fee charged by OP Stack rollups.
+        pub l1: Option<U256>,
+    }
+
+    impl TransactionFee {
+        pub fn new(
+            transaction: &Transaction,
+            transaction_receipt: &TransactionReceipt,
+            base_fee_per_gas: Option<U256>,
+        ) -> Result<Self> {
+            let gas_used = transaction_receipt
+                .gas_used
+                .context("The client is running in light client mode.")?;
+            let effective_gas_price =
+                effective_gas_price(transaction, transaction_receipt, base_fee_per_gas)
+                    .context("Gas price is None")?;
+
+            let burnt = base_fee_per_gas.map(|base_fee_per_gas| base_fee_per_gas * gas_used);
+            let tip = base_fee_per_gas.map(|base_fee_per_gas| {
+                effective_gas_price.saturating_sub(base_fee_per_gas) * gas_used
+            });
 
-    pub fn calculate_transaction_fee(
+            let get = |key: &str| {
+                transaction_receipt
+                    .other
+                    .get_deserialized::<U256>(key)
+                    .and_then(Result::ok)
+            };
+            let blob = get("blobGasUsed")
+                .zip(get("blobGasPrice"))
+                .map(|(blob_gas_used, blob_gas_price)| blob_gas_used * blob_gas_price);
+
+            Ok(Self {
+                gas_used,
+                effective_gas_price,
+                burnt,
+                tip,
+                blob,
+                l1: get("l1Fee"),
+            })
+        }
+
+        pub fn total(&self) -> U256 {
+            self.gas_used * self.effective_gas_price
+                + self.blob.unwrap_or_default()
+                + self.l1.unwrap_or_default()
+        }
+    }
+
+    /// The gas price actually paid. Receipts of nodes before London lack `effectiveGasPrice`, so
+    /// it is derived from the fee caps and the base fee for EIP-1559 transactions.
+    pub fn effective_gas_price(
         transaction: &Transaction,
         transaction_receipt: &TransactionReceipt,
-        _block: Option<Block<Transaction>>,
-    ) -> Result<String> {
-        if let Some(gas_used) = transaction_receipt.gas_used {
-            // Legacy
-            if let Some(gas_price) = transaction.gas_price {
-                Ok(format_ether(gas_price * gas_used))
-            } else {
-                //EIP-1559
-                Ok(format_ether(
-                    std::cmp::min(
-                        transaction.max_fee_per_gas.context("Fee per gas is None")?,
-                        //block.base_fee_per_gas.unwrap() +
-                        transaction
-                            .max_priority_fee_per_gas
-                            .context("Max Priority Fee Per Gas is NOne")?,
-                    ) * gas_used,
-                ))
-            }
+        base_fee_per_gas: Option<U256>,
+    ) -> Option<U256> {
+        transaction_receipt.effective_gas_price.or(
+            match (
+                transaction.max_fee_per_gas,
+                transaction.max_priority_fee_per_gas,
+                base_fee_per_gas,
+            ) {
+                (Some(max_fee_per_gas), Some(max_priority_fee_per_gas), Some(base_fee_per_gas)) => {
+                    Some(
+                        base_fee_per_gas
+                            + max_priority_fee_per_gas
+                                .min(max_fee_per_gas.saturating_sub(base_fee_per_gas)),
+                    )
+                }
+                _ => transaction.gas_price,
+            },
+        )
+    }
+
+    /// Blob versioned hashes of EIP-4844 transactions, which the ethers types do not include yet.
+    pub fn blob_versioned_hashes(transaction: &Transaction) -> Vec<H256> {
+        transaction
+            .other
+            .get_deserialized::<Vec<H256>>("blobVersionedHashes")
+            .and_then(Result::ok)
+            .unwrap_or_default()
+    }
+
+    /// The blob base fee of a block, `fake_exponential` of its excess blob gas as in EIP-4844.
+    /// Blocks since Prague are told apart by their `requestsHash` (EIP-7685).
+    pub fn blob_base_fee<T>(block: &Block<T>) -> Option<U256> {
+        let excess_blob_gas = block.excess_blob_gas?;
+        let update_fraction = if block.other.contains_key("requestsHash") {
+            PRAGUE_BLOB_BASE_FEE_UPDATE_FRACTION
         } else {
-            bail!("The client is running in light client mode.")
+            CANCUN_BLOB_BASE_FEE_UPDATE_FRACTION
+        };
+
+        let factor = U256::from(MIN_BLOB_BASE_FEE);
+        let denominator = U256::from(update_fraction);
+        let mut i = U256::one();
+        let mut output = U256::zero();
+        let mut accumulator = factor * denominator;
+        while !accumulator.is_zero() {
+            output += accumulator;
+            accumulator = accumulator * excess_blob_gas / (denominator * i);
+            i += U256::one();
+        }
+        Some(output / denominator)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        const GWEI: u64 = 1_000_000_000;
+
+        fn gwei(value: u64) -> U256 {
+            U256::from(value * GWEI)
+        }
+
+        fn eip1559_transaction(max_fee_per_gas: u64, max_priority_fee_per_gas: u64) -> Transaction {
+            Transaction {
+                max_fee_per_gas: Some(gwei(max_fee_per_gas)),
+                max_priority_fee_per_gas: Some(gwei(max_priority_fee_per_gas)),
+                ..Default::default()
+            }
+        }
+
+        fn transaction_receipt(gas_used: u64) -> TransactionReceipt {
+            TransactionReceipt {
+                gas_used: Some(U256::from(gas_used)),
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn eip1559_fee() {
+            let fee = TransactionFee::new(
+                &eip1559_transaction(30, 2),
+                &transaction_receipt(21000),
+                Some(gwei(10)),
+            )
+            .unwrap();
+
+            assert_eq!(fee.effective_gas_price, gwei(12));
+            assert_eq!(fee.burnt, Some(gwei(10) * 21000));
+            assert_eq!(fee.tip, Some(gwei(2) * 21000));
+            assert_eq!(fee.total(), gwei(12) * 21000);
+        }
+
+        #[test]
+        fn tip_capped_by_max_fee() {
+            assert_eq!(
+                effective_gas_price(
+                    &eip1559_transaction(11, 2),
+                    &transaction_receipt(21000),
+                    Some(gwei(10))
+                ),
+                Some(gwei(11))
+            );
+        }
+
+        #[test]
+        fn receipt_effective_gas_price_first() {
+            let transaction_receipt = TransactionReceipt {
+                effective_gas_price: Some(gwei(15)),
+                ..transaction_receipt(21000)
+            };
+
+            assert_eq!(
+                effective_gas_price(
+                    &eip1559_transaction(30, 2),
+                    &transaction_receipt,
+                    Some(gwei(10))
+                ),
+                Some(gwei(15))
+            );
+        }
+
+        #[test]
+        fn legacy_fee_without_base_fee() {
+            let transaction = Transaction {
+                gas_price: Some(gwei(20)),
+                ..Default::default()
+            };
+
+            let fee = TransactionFee::new(&transaction, &transaction_receipt(21000), None).unwrap();
+
+            assert_eq!(fee.effective_gas_price, gwei(20));
+            assert_eq!(fee.burnt, None);
+            assert_eq!(fee.tip, None);
+            assert_eq!(fee.total(), gwei(20) * 21000);
+        }
+
+        #[test]
+        fn blob_and_l1_fees() {
+            let mut transaction_receipt = transaction_receipt(21000);
+            transaction_receipt
+                .other
+                .insert("blobGasUsed".to_owned(), "0x20000".into());
+            transaction_receipt
+                .other
+                .insert("blobGasPrice".to_owned(), "0x3".into());
+            transaction_receipt
+                .other
+                .insert("l1Fee".to_owned(), "0x64".into());
+
+            let fee = TransactionFee::new(
+                &eip1559_transaction(30, 2),
+                &transaction_receipt,
+                Some(gwei(10)),
+            )
+            .unwrap();
+
+            assert_eq!(fee.blob, Some(U256::from(0x20000 * 3)));
+            assert_eq!(fee.l1, Some(U256::from(100)));
+            assert_eq!(
+                fee.total(),
+                gwei(12) * 21000 + U256::from(0x20000 * 3) + U256::from(100)
+            );
+        }
+
+        #[test]
+        fn light_client_receipt() {
+            let transaction_receipt = TransactionReceipt::default();
+
+            assert!(
+                TransactionFee::new(&eip1559_transaction(30, 2), &transaction_receipt, None)
+                    .is_err()
+            );
+        }
+
+        fn block(excess_blob_gas: Option<u64>, is_prague: bool) -> Block<H256> {
+            let mut block = Block {
+                excess_blob_gas: excess_blob_gas.map(U256::from),
+                ..Default::default()
+            };
+            if is_prague {
+                block.other.insert(
+                    "requestsHash".to_owned(),
+                    format!("{:#x}", H256::zero()).into(),
+                );
+            }
+            block
+        }
+
+        #[test]
+        fn blob_base_fee_since_cancun() {
+            assert_eq!(blob_base_fee(&block(None, false)), None);
+            assert_eq!(blob_base_fee(&block(Some(0), false)), Some(U256::one()));
+            assert_eq!(
+                blob_base_fee(&block(Some(CANCUN_BLOB_BASE_FEE_UPDATE_FRACTION), false)),
+                Some(U256::from(2))
+            );
+            assert_eq!(
+                blob_base_fee(&block(Some(PRAGUE_BLOB_BASE_FEE_UPDATE_FRACTION), false)),
+                Some(U256::from(4))
+            );
+        }
+
+        #[test]
+        fn blob_base_fee_since_prague() {
+            assert_eq!(blob_base_fee(&block(Some(0), true)), Some(U256::one()));
+            assert_eq!(
+                blob_base_fee(&block(Some(PRAGUE_BLOB_BASE_FEE_UPDATE_FRACTION), true)),
+                Some(U256::from(2))
+            );
+            assert_eq!(
+                blob_base_fee(&block(Some(100_000_000), true)),
+                Some(U256::from(470_442_149))
+            );
         }
     }
 } /* transaction */
@@ -0,0 +1,465 @@
+use anyhow::Result;
+use ethers::core::{abi::Abi, types::Bytes, utils::hex};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// https://openchain.xyz/signatures
+const SIGNATURE_DATABASE_URL: &str = "https://api.openchain.xyz/signature-database/v1/lookup";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Instruction {
+    pub offset: usize,
+    pub opcode: u8,
+    pub push_data: Vec<u8>,
+}
+
+impl Instruction {
+    pub fn name(&self) -> String {
+        opcode_name(self.opcode)
+            .map(|name| name.to_owned())
+            .unwrap_or_else(|| format!("INVALID(0x{:02x})", self.opcode))
+    }
+}
+
+/// Compiler information stored in the CBOR encoded metadata at the end of the runtime bytecode.
+/// See https://docs.soliditylang.org/en/latest/metadata.html#encoding-of-the-metadata-hash-in-the-bytecode
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CompilerMetadata {
+    pub compiler: Option<String>,
+    pub ipfs: Option<String>,
+    pub bzzr: Option<String>,
+    pub is_experimental: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractBytecode {
+    pub code: Bytes,
+    pub instructions: Vec<Instruction>,
+    pub metadata: Option<CompilerMetadata>,
+    /// Selectors found in the function dispatcher and their candidate signatures.
+    pub selectors: Vec<([u8; 4], Vec<String>)>,
+}
+
+impl ContractBytecode {
+    pub fn new(code: Bytes) -> Self {
+        let (body, metadata) = split_metadata(&code);
+        Self {
+            instructions: disassemble(body),
+            metadata: metadata.and_then(parse_metadata),
+            selectors: vec![],
+            code,
+        }
+    }
+
+    /// PUSH4 values compared with `EQ` right after being pushed, as in the function dispatcher
+    /// emitted by solc and vyper.
+    pub fn dispatcher_selectors(&self) -> Vec<[u8; 4]> {
+        let mut selectors = vec![];
+        for (i, instruction) in self.instructions.iter().enumerate() {
+            if instruction.opcode == 0x63
+                && self.instructions[i + 1..]
+                    .iter()
+                    .take(2)
+                    .any(|next| next.opcode == 0x14)
+            {
+                if let Ok(selector) = <[u8; 4]>::try_from(instruction.push_data.as_slice()) {
+                    if !selectors.contains(&selector) {
+                        selectors.push(selector);
+                    }
+                }
+            }
+        }
+        selectors
+    }
+
+    /// Resolves selectors with the functions of `abi` first, then with `signatures` looked up in
+    /// a selector database.
+    pub fn resolve_selectors(
+        &mut self,
+        abi: Option<&Abi>,
+        signatures: &HashMap<[u8; 4], Vec<String>>,
+    ) {
+        self.selectors = self
+            .dispatcher_selectors()
+            .into_iter()
+            .map(|selector| {
+                let from_abi = abi
+                    .map(|abi| {
+                        abi.functions()
+                            .filter(|function| function.short_signature() == selector)
+                            .map(|function| function.signature())
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+                (
+                    selector,
+                    if from_abi.is_empty() {
+                        signatures.get(&selector).cloned().unwrap_or_default()
+                    } else {
+                        from_abi
+                    },
+                )
+            })
+            .collect();
+    }
+}
+
+/// Looks up the text signatures of `selectors` in the openchain signature database.
+pub async fn lookup_signatures(selectors: &[[u8; 4]]) -> Result<HashMap<[u8; 4], Vec<String>>> {
+    if selectors.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let response = reqwest::Client::new()
+        .get(SIGNATURE_DATABASE_URL)
+        .query(&[
+            (
+                "function",
+                selectors
+                    .iter()
+                    .map(|selector| format!("0x{}", hex::encode(selector)))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            ("filter", "true".to_owned()),
+        ])
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<Value>()
+        .await?;
+
+    Ok(selectors
+        .iter()
+        .filter_map(|selector| {
+            let signatures = response
+                .pointer(&format!("/result/function/0x{}", hex::encode(selector)))?
+                .as_array()?
+                .iter()
+                .filter_map(|signature| signature.get("name")?.as_str().map(|s| s.to_owned()))
+                .collect::<Vec<_>>();
+            Some((*selector, signatures))
+        })
+        .collect())
+}
+
+pub fn disassemble(code: &[u8]) -> Vec<Instruction> {
+    let mut instructions = vec![];
+    let mut offset = 0;
+    while offset < code.len() {
+        let opcode = code[offset];
+        let push_size = if (0x60..=0x7f).contains(&opcode) {
+            (opcode - 0x5f) as usize
+        } else {
+            0
+        };
+        let end = (offset + 1 + push_size).min(code.len());
+        instructions.push(Instruction {
+            offset,
+            opcode,
+            push_data: code[offset + 1..end].to_vec(),
+        });
+        offset = end;
+    }
+    instructions
+}
+
+/// Splits the runtime bytecode into the code and the CBOR metadata whose length is stored in the
+/// last 2 bytes. The code is left whole unless that tail decodes as a CBOR map, or an array
+/// holding one for vyper >= 0.3.10.
+pub fn split_metadata(code: &[u8]) -> (&[u8], Option<&[u8]>) {
+    if code.len() >= 2 {
+        let length = u16::from_be_bytes([code[code.len() - 2], code[code.len() - 1]]) as usize;
+        // vyper counts the 2 bytes of the length itself
+        for length in [length, length.saturating_sub(2)] {
+            if length > 0 && length + 2 <= code.len() {
+                let start = code.len() - 2 - length;
+                let metadata = &code[start..code.len() - 2];
+                let mut position = 0;
+                let is_metadata = match decode_cbor(metadata, &mut position) {
+                    Some(Cbor::Map(_)) => true,
+                    Some(Cbor::Array(items)) => {
+                        items.iter().any(|item| matches!(item, Cbor::Map(_)))
+                    }
+                    _ => false,
+                };
+                if is_metadata && position == metadata.len() {
+                    return (&code[..start], Some(metadata));
+                }
+            }
+        }
+    }
+    (code, None)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Cbor {
+    Unsigned(u64),
+    Bytes(Vec<u8>),
+    Text(String),
+    Array(Vec<Cbor>),
+    Map(Vec<(Cbor, Cbor)>),
+    Bool(bool),
+}
+
+fn parse_metadata(data: &[u8]) -> Option<CompilerMetadata> {
+    let mut position = 0;
+    let value = decode_cbor(data, &mut position)?;
+    // vyper appends the sizes of its sections before the map
+    let entries = match value {
+        Cbor::Map(entries) => entries,
+        Cbor::Array(items) => items.into_iter().find_map(|item| match item {
+            Cbor::Map(entries) => Some(entries),
+            _ => None,
+        })?,
+        _ => return None,
+    };
+
+    let mut metadata = CompilerMetadata::default();
+    for (key, value) in entries {
+        let Cbor::Text(key) = key else {
+            continue;
+        };
+        match (key.as_str(), value) {
+            ("solc" | "vyper", Cbor::Bytes(version)) => {
+                metadata.compiler = Some(format!(
+                    "{key} {}",
+                    version
+                        .iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<_>>()
+                        .join(".")
+                ));
+            }
+            ("solc" | "vyper", Cbor::Array(version)) => {
+                metadata.compiler = Some(format!(
+                    "{key} {}",
+                    version
+                        .iter()
+                        .filter_map(|v| match v {
+                            Cbor::Unsigned(v) => Some(v.to_string()),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>()
+                        .join(".")
+                ));
+            }
+            ("solc" | "vyper", Cbor::Text(version)) => {
+                metadata.compiler = Some(format!("{key} {version}"));
+            }
+            ("ipfs", Cbor::Bytes(hash)) => {
+                metadata.ipfs = Some(format!("0x{}", hex::encode(hash)));
+            }
+            ("bzzr0" | "bzzr1", Cbor::Bytes(hash)) => {
+                metadata.bzzr = Some(format!("0x{}", hex::encode(hash)));
+            }
+            ("experimental", Cbor::Bool(is_experimental)) => {
+                metadata.is_experimental = is_experimental;
+            }
+            _ => {}
+        }
+    }
+    Some(metadata)
+}
+
+/// Decodes the subset of CBOR used by compiler metadata.
+fn decode_cbor(data: &[u8], position: &mut usize) -> Option<Cbor> {
+    let head = *data.get(*position)?;
+    *position += 1;
+    let major = head >> 5;
+    let info = head & 0x1f;
+
+    let argument = match info {
+        0..=23 => info as u64,
+        24..=27 => {
+            let size = 1 << (info - 24);
+            let bytes = data.get(*position..*position + size)?;
+            *position += size;
+            bytes.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64)
+        }
+        _ => return None,
+    };
+
+    match major {
+        0 => Some(Cbor::Unsigned(argument)),
+        2 | 3 => {
+            let end = position.checked_add(usize::try_from(argument).ok()?)?;
+            let bytes = data.get(*position..end)?.to_vec();
+            *position = end;
+            if major == 2 {
+                Some(Cbor::Bytes(bytes))
+            } else {
+                String::from_utf8(bytes).ok().map(Cbor::Text)
+            }
+        }
+        4 => (0..argument)
+            .map(|_| decode_cbor(data, position))
+            .collect::<Option<Vec<_>>>()
+            .map(Cbor::Array),
+        5 => (0..argument)
+            .map(|_| Some((decode_cbor(data, position)?, decode_cbor(data, position)?)))
+            .collect::<Option<Vec<_>>>()
+            .map(Cbor::Map),
+        7 => match info {
+            20 => Some(Cbor::Bool(false)),
+            21 => Some(Cbor::Bool(true)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+pub fn opcode_name(opcode: u8) -> Option<&'static str> {
+    Some(match opcode {
+        0x00 => "STOP",
+        0x01 => "ADD",
+        0x02 => "MUL",
+        0x03 => "SUB",
+        0x04 => "DIV",
+        0x05 => "SDIV",
+        0x06 => "MOD",
+        0x07 => "SMOD",
+        0x08 => "ADDMOD",
+        0x09 => "MULMOD",
+        0x0a => "EXP",
+        0x0b => "SIGNEXTEND",
+        0x10 => "LT",
+        0x11 => "GT",
+        0x12 => "SLT",
+        0x13 => "SGT",
+        0x14 => "EQ",
+        0x15 => "ISZERO",
+        0x16 => "AND",
+        0x17 => "OR",
+        0x18 => "XOR",
+        0x19 => "NOT",
+        0x1a => "BYTE",
+        0x1b => "SHL",
+        0x1c => "SHR",
+        0x1d => "SAR",
+        0x20 => "KECCAK256",
+        0x30 => "ADDRESS",
+        0x31 => "BALANCE",
+        0x32 => "ORIGIN",
+        0x33 => "CALLER",
+        0x34 => "CALLVALUE",
+        0x35 => "CALLDATALOAD",
+        0x36 => "CALLDATASIZE",
+        0x37 => "CALLDATACOPY",
+        0x38 => "CODESIZE",
+        0x39 => "CODECOPY",
+        0x3a => "GASPRICE",
+        0x3b => "EXTCODESIZE",
+        0x3c => "EXTCODECOPY",
+        0x3d => "RETURNDATASIZE",
+        0x3e => "RETURNDATACOPY",
+        0x3f => "EXTCODEHASH",
+        0x40 => "BLOCKHASH",
+        0x41 => "COINBASE",
+        0x42 => "TIMESTAMP",
+        0x43 => "NUMBER",
+        0x44 => "PREVRANDAO",
+        0x45 => "GASLIMIT",
+        0x46 => "CHAINID",
+        0x47 => "SELFBALANCE",
+        0x48 => "BASEFEE",
+        0x49 => "BLOBHASH",
+        0x4a => "BLOBBASEFEE",
+        0x50 => "POP",
+        0x51 => "MLOAD",
+        0x52 => "MSTORE",
+        0x53 => "MSTORE8",
+        0x54 => "SLOAD",
+        0x55 => "SSTORE",
+        0x56 => "JUMP",
+        0x57 => "JUMPI",
+        0x58 => "PC",
+        0x59 => "MSIZE",
+        0x5a => "GAS",
+        0x5b => "JUMPDEST",
+        0x5c => "TLOAD",
+        0x5d => "TSTORE",
+        0x5e => "MCOPY",
+        0x5f => "PUSH0",
+        0x60 => "PUSH1",
+        0x61 => "PUSH2",
+        0x62 => "PUSH3",
+        0x63 => "PUSH4",
+        0x64 => "PUSH5",
+        0x65 => "PUSH6",
+        0x66 => "PUSH7",
+        0x67 => "PUSH8",
+        0x68 => "PUSH9",
+        0x69 => "PUSH10",
+        0x6a => "PUSH11",
+        0x6b => "PUSH12",
+        0x6c => "PUSH13",
+        0x6d => "PUSH14",
+        0x6e => "PUSH15",
+        0x6f => "PUSH16",
+        0x70 => "PUSH17",
+        0x71 => "PUSH18",
+        0x72 => "PUSH19",
+        0x73 => "PUSH20",
+        0x74 => "PUSH21",
+        0x75 => "PUSH22",
+        0x76 => "PUSH23",
+        0x77 => "PUSH24",
+        0x78 => "PUSH25",
+        0x79 => "PUSH26",
+        0x7a => "PUSH27",
+        0x7b => "PUSH28",
+        0x7c => "PUSH29",
+        0x7d => "PUSH30",
+        0x7e => "PUSH31",
+        0x7f => "PUSH32",
+        0x80 => "DUP1",
+        0x81 => "DUP2",
+        0x82 => "DUP3",
+        0x83 => "DUP4",
+        0x84 => "DUP5",
+        0x85 => "DUP6",
+        0x86 => "DUP7",
+        0x87 => "DUP8",
+        0x88 => "DUP9",
+        0x89 => "DUP10",
+        0x8a => "DUP11",
+        0x8b => "DUP12",
+        0x8c => "DUP13",
+        0x8d => "DUP14",
+        0x8e => "DUP15",
+        0x8f => "DUP16",
+        0x90 => "SWAP1",
+        0x91 => "SWAP2",
+        0x92 => "SWAP3",
+        0x93 => "SWAP4",
+        0x94 => "SWAP5",
+        0x95 => "SWAP6",
+        0x96 => "SWAP7",
+        0x97 => "SWAP8",
+        0x98 => "SWAP9",
+        0x99 => "SWAP10",
+        0x9a => "SWAP11",
+        0x9b => "SWAP12",
+        0x9c => "SWAP13",
+        0x9d => "SWAP14",
+        0x9e => "SWAP15",
+        0x9f => "SWAP16",
+        0xa0 => "LOG0",
+        0xa1 => "LOG1",
+        0xa2 => "LOG2",
+        0xa3 => "LOG3",
+        0xa4 => "LOG4",
+        0xf0 => "CREATE",
+        0xf1 => "CALL",
+        0xf2 => "CALLCODE",
+        0xf3 => "RETURN",
+        0xf4 => "DELEGATECALL",
+        0xf5 => "CREATE2",
+        0xfa => "STATICCALL",
+        0xfd => "REVERT",
+        0xfe => "INVALID",
+        0xff => "SELFDESTRUCT",
+        _ => return None,
+    })
+}
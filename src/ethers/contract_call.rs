@@ -0,0 +1,224 @@
+use anyhow::{bail, Result};
+use ethers::core::{
+    abi::{
+        token::{LenientTokenizer, Tokenizer},
+        Abi, Function, Param, StateMutability, Token,
+    },
+    types::{BlockId, BlockNumber, H256, I256},
+    utils::hex,
+};
+
+/// Functions that can be called without sending a transaction.
+pub fn read_functions(abi: &Abi) -> Vec<Function> {
+    abi.functions()
+        .filter(|function| {
+            matches!(
+                function.state_mutability,
+                StateMutability::View | StateMutability::Pure
+            )
+        })
+        .map(|function| function.to_owned())
+        .collect()
+}
+
+pub fn param_label(i: usize, param: &Param) -> String {
+    format!(
+        "{} ({})",
+        if param.name.is_empty() {
+            format!("arg{i}")
+        } else {
+            param.name.to_owned()
+        },
+        param.kind
+    )
+}
+
+/// Parses user input into ABI tokens, e.g. `0x...` for `address`, `1000` for `uint256`,
+/// `[1,2,3]` for arrays and `(0x...,true)` for tuples.
+pub fn parse_arguments(params: &[Param], values: &[String]) -> Result<Vec<Token>> {
+    if params.len() != values.len() {
+        bail!("Expected {} arguments, got {}.", params.len(), values.len());
+    }
+
+    params
+        .iter()
+        .zip(values)
+        .enumerate()
+        .map(|(i, (param, value))| {
+            LenientTokenizer::tokenize(&param.kind, value).map_err(|err| {
+                anyhow::anyhow!("Invalid value for {}: {}", param_label(i, param), err)
+            })
+        })
+        .collect()
+}
+
+/// Splits comma separated arguments, keeping the commas inside `[...]`, `(...)` and quotes.
+pub fn split_arguments(value: &str) -> Vec<String> {
+    let mut arguments = vec![];
+    let mut argument = String::new();
+    let mut depth = 0usize;
+    let mut is_quoted = false;
+
+    for c in value.chars() {
+        match c {
+            '"' => is_quoted = !is_quoted,
+            '[' | '(' if !is_quoted => depth += 1,
+            ']' | ')' if !is_quoted => depth = depth.saturating_sub(1),
+            ',' if !is_quoted && depth == 0 => {
+                arguments.push(argument.trim().to_owned());
+                argument.clear();
+                continue;
+            }
+            _ => {}
+        }
+        argument.push(c);
+    }
+
+    if !argument.trim().is_empty() || !arguments.is_empty() {
+        arguments.push(argument.trim().to_owned());
+    }
+    arguments
+}
+
+/// Parses a block tag (`latest`, `safe`, `finalized`, ...), a block number or a block hash.
+pub fn parse_block_id(value: &str) -> Result<BlockId> {
+    let value = value.trim();
+    Ok(match value {
+        "" | "latest" => BlockId::Number(BlockNumber::Latest),
+        "pending" => BlockId::Number(BlockNumber::Pending),
+        "earliest" => BlockId::Number(BlockNumber::Earliest),
+        "safe" => BlockId::Number(BlockNumber::Safe),
+        "finalized" => BlockId::Number(BlockNumber::Finalized),
+        _ => {
+            if let Ok(number) = value.parse::<u64>() {
+                BlockId::Number(BlockNumber::Number(number.into()))
+            } else if let Ok(hash) = value.parse::<H256>() {
+                BlockId::Hash(hash)
+            } else {
+                bail!("Invalid block: {value}")
+            }
+        }
+    })
+}
+
+pub fn format_token(token: &Token) -> String {
+    match token {
+        Token::Address(address) => format!("{:#x}", address),
+        Token::Uint(value) => value.to_string(),
+        Token::Int(value) => I256::from_raw(*value).to_string(),
+        Token::Bool(value) => value.to_string(),
+        Token::String(value) => format!("{value:?}"),
+        Token::Bytes(bytes) | Token::FixedBytes(bytes) => format!("0x{}", hex::encode(bytes)),
+        Token::Array(tokens) | Token::FixedArray(tokens) => format!(
+            "[{}]",
+            tokens
+                .iter()
+                .map(format_token)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Token::Tuple(tokens) => format!(
+            "({})",
+            tokens
+                .iter()
+                .map(format_token)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::core::{
+        abi::ParamType,
+        types::{Address, U256},
+    };
+
+    fn param(name: &str, kind: ParamType) -> Param {
+        Param {
+            name: name.to_owned(),
+            kind,
+            internal_type: None,
+        }
+    }
+
+    fn arguments(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn value_types() {
+        let params = [
+            param("owner", ParamType::Address),
+            param("amount", ParamType::Uint(256)),
+            param("", ParamType::Bool),
+        ];
+        let owner = "0x000000000000000000000000000000000000dEaD";
+
+        assert_eq!(
+            parse_arguments(&params, &arguments(&[owner, "1000", "true"])).unwrap(),
+            vec![
+                Token::Address(owner.parse::<Address>().unwrap()),
+                Token::Uint(U256::from(1000)),
+                Token::Bool(true),
+            ]
+        );
+    }
+
+    #[test]
+    fn arrays_and_tuples() {
+        let params = [
+            param("ids", ParamType::Array(Box::new(ParamType::Uint(8)))),
+            param(
+                "pair",
+                ParamType::Tuple(vec![ParamType::Uint(256), ParamType::String]),
+            ),
+        ];
+
+        assert_eq!(
+            parse_arguments(&params, &arguments(&["[1,2,3]", "(7,hello)"])).unwrap(),
+            vec![
+                Token::Array(vec![
+                    Token::Uint(U256::from(1)),
+                    Token::Uint(U256::from(2)),
+                    Token::Uint(U256::from(3)),
+                ]),
+                Token::Tuple(vec![
+                    Token::Uint(U256::from(7)),
+                    Token::String("hello".to_owned()),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        let params = [param("", ParamType::Address)];
+
+        assert_eq!(
+            parse_arguments(&params, &[]).unwrap_err().to_string(),
+            "Expected 1 arguments, got 0."
+        );
+        assert!(parse_arguments(&params, &arguments(&["1000"]))
+            .unwrap_err()
+            .to_string()
+            .starts_with("Invalid value for arg0 (address)"));
+    }
+
+    #[test]
+    fn split_nested_and_quoted_arguments() {
+        assert_eq!(
+            split_arguments(r#" 0x01, [1, 2], (3, "a, b"), "c,d" "#),
+            arguments(&["0x01", "[1, 2]", r#"(3, "a, b")"#, r#""c,d""#])
+        );
+    }
+
+    #[test]
+    fn split_empty_arguments() {
+        assert!(split_arguments("").is_empty());
+        assert!(split_arguments("  ").is_empty());
+        assert_eq!(split_arguments("1,"), arguments(&["1", ""]));
+    }
+}
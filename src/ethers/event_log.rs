@@ -0,0 +1,67 @@
+use super::contract_call::format_token;
+use ethers::core::{
+    abi::{parse_abi, Abi, RawLog},
+    types::{Address, Bytes, H256},
+};
+
+/// Well-known events used when the emitting contract is not verified.
+pub const KNOWN_EVENTS: [&str; 6] = [
+    "event Transfer(address indexed from, address indexed to, uint256 value)",
+    "event Transfer(address indexed from, address indexed to, uint256 indexed tokenId)",
+    "event Approval(address indexed owner, address indexed spender, uint256 value)",
+    "event ApprovalForAll(address indexed owner, address indexed operator, bool approved)",
+    "event Deposit(address indexed dst, uint256 wad)",
+    "event Withdrawal(address indexed src, uint256 wad)",
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedLog {
+    pub address: Address,
+    pub topics: Vec<H256>,
+    pub data: Bytes,
+    /// Name of the event and its decoded parameters, if the event is known.
+    pub event: Option<(String, Vec<(String, String)>)>,
+}
+
+impl DecodedLog {
+    /// Decodes a log with the ABI of the emitting contract, falling back to well-known events.
+    pub fn new(address: Address, topics: Vec<H256>, data: Bytes, abi: Option<&Abi>) -> Self {
+        let event = abi
+            .and_then(|abi| Self::decode(abi, &topics, &data))
+            .or_else(|| {
+                parse_abi(&KNOWN_EVENTS)
+                    .ok()
+                    .and_then(|abi| Self::decode(&abi, &topics, &data))
+            });
+
+        Self {
+            address,
+            topics,
+            data,
+            event,
+        }
+    }
+
+    fn decode(abi: &Abi, topics: &[H256], data: &Bytes) -> Option<(String, Vec<(String, String)>)> {
+        let topic0 = topics.first()?;
+        abi.events()
+            .filter(|event| !event.anonymous && event.signature() == *topic0)
+            .find_map(|event| {
+                event
+                    .parse_log(RawLog {
+                        topics: topics.to_vec(),
+                        data: data.to_vec(),
+                    })
+                    .ok()
+                    .map(|log| {
+                        (
+                            event.name.to_owned(),
+                            log.params
+                                .iter()
+                                .map(|param| (param.name.to_owned(), format_token(&param.value)))
+                                .collect(),
+                        )
+                    })
+            })
+    }
+}
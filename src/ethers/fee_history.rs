@@ -0,0 +1,98 @@
+use ethers::core::types::U256;
+use serde::{Deserialize, Serialize};
+
+/// Percentiles of the priority fees requested from `eth_feeHistory`, used as low, medium and high
+/// tip suggestions.
+pub const REWARD_PERCENTILES: [f64; 3] = [10.0, 50.0, 90.0];
+pub const DEFAULT_BLOCK_COUNT: u64 = 64;
+/// Most nodes refuse to return the history of more blocks at once.
+pub const MAX_BLOCK_COUNT: u64 = 1024;
+
+const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+
+/// The response of `eth_feeHistory`. Blob fields are optional because they were only added with
+/// Cancun, and the ethers type does not include them yet.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeHistory {
+    pub oldest_block: U256,
+    /// Base fees of the blocks, followed by the base fee of the next block.
+    pub base_fee_per_gas: Vec<U256>,
+    pub gas_used_ratio: Vec<f64>,
+    #[serde(default)]
+    pub base_fee_per_blob_gas: Option<Vec<U256>>,
+    #[serde(default)]
+    pub blob_gas_used_ratio: Option<Vec<f64>>,
+    /// Priority fees of each block at `REWARD_PERCENTILES`.
+    #[serde(default)]
+    pub reward: Option<Vec<Vec<U256>>>,
+}
+
+impl FeeHistory {
+    pub fn block_count(&self) -> usize {
+        self.gas_used_ratio.len()
+    }
+
+    pub fn newest_block(&self) -> U256 {
+        self.oldest_block + self.block_count().saturating_sub(1)
+    }
+
+    /// The base fee of the next block. Nodes include it in the response, otherwise it is
+    /// predicted from the last block as in EIP-1559.
+    pub fn next_base_fee(&self) -> Option<U256> {
+        self.base_fee_per_gas
+            .get(self.block_count())
+            .copied()
+            .or_else(|| {
+                let base_fee = *self.base_fee_per_gas.last()?;
+                let gas_used_ratio = *self.gas_used_ratio.last()?;
+                Some(predict_base_fee(base_fee, gas_used_ratio))
+            })
+    }
+
+    pub fn next_blob_base_fee(&self) -> Option<U256> {
+        self.base_fee_per_blob_gas
+            .as_ref()
+            .and_then(|base_fees| base_fees.last())
+            .copied()
+    }
+
+    /// Low, medium and high tips: the median over the non-empty blocks of each reward percentile.
+    pub fn tip_suggestions(&self) -> Option<[U256; 3]> {
+        let reward = self.reward.as_ref()?;
+        let mut suggestions = [U256::zero(); 3];
+        for (i, suggestion) in suggestions.iter_mut().enumerate() {
+            let mut tips = reward
+                .iter()
+                .zip(self.gas_used_ratio.iter())
+                .filter(|(_, gas_used_ratio)| **gas_used_ratio > 0.0)
+                .filter_map(|(rewards, _)| rewards.get(i).copied())
+                .collect::<Vec<_>>();
+            if tips.is_empty() {
+                return None;
+            }
+            tips.sort();
+            *suggestion = tips[tips.len() / 2];
+        }
+        Some(suggestions)
+    }
+}
+
+/// The base fee of the block after a block with `base_fee` and `gas_used_ratio`, which moves by
+/// up to 1/8 towards keeping blocks half full.
+pub fn predict_base_fee(base_fee: U256, gas_used_ratio: f64) -> U256 {
+    // The ratio is scaled to parts per million to stay in integer arithmetic
+    let used = U256::from((gas_used_ratio.clamp(0.0, 1.0) * 2_000_000.0).round() as u64);
+    let target = U256::from(1_000_000);
+    let denominator = target * BASE_FEE_MAX_CHANGE_DENOMINATOR;
+    if used > target {
+        base_fee + (base_fee * (used - target) / denominator).max(U256::one())
+    } else {
+        base_fee - base_fee * (target - used) / denominator
+    }
+}
+
+/// Converts wei to Gwei for charts, where the precision of `f64` is enough.
+pub fn to_gwei(wei: U256) -> f64 {
+    wei.low_u128() as f64 / 1e9
+}
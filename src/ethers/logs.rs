@@ -0,0 +1,147 @@
+use super::{event_log::DecodedLog, storage::encode_key};
+use anyhow::{bail, Context, Result};
+use ethers::core::{
+    abi::{Abi, AbiParser, Event},
+    types::{Address, BlockNumber, H256, U256, U64},
+    utils::keccak256,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogsRequest {
+    pub addresses: Vec<Address>,
+    /// A topic0 hash, an event signature, or the name of an event in the ABI of the addresses.
+    pub event: Option<String>,
+    /// Accepted values of the indexed topics 1 to 3. An empty list matches any value.
+    pub topics: [Vec<H256>; 3],
+    pub from_block: BlockNumber,
+    pub to_block: BlockNumber,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogEntry {
+    pub block_number: Option<U64>,
+    pub transaction_hash: Option<H256>,
+    pub log_index: Option<U256>,
+    pub log: DecodedLog,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogsResult {
+    pub from_block: U64,
+    pub to_block: U64,
+    pub logs: Vec<LogEntry>,
+    /// Number of `eth_getLogs` calls after the range was split.
+    pub requests: usize,
+    /// Signatures of the events of the queried ABI, selectable as topic0.
+    pub events: Vec<String>,
+}
+
+/// Encodes a topic filter value. Strings and bytes are hashed as Solidity does for indexed
+/// dynamic values, so they have to be given as `string:value` or `bytes:0x...`.
+pub fn encode_topic(value: &str) -> Result<H256> {
+    let is_dynamic = value
+        .split_once(':')
+        .is_some_and(|(kind, _)| matches!(kind.trim(), "string" | "bytes"));
+    let encoded = encode_key(value)?;
+    if is_dynamic {
+        Ok(H256(keccak256(encoded)))
+    } else if encoded.len() == 32 {
+        Ok(H256::from_slice(&encoded))
+    } else {
+        bail!("Invalid topic: {value}. Prefix strings with `string:`")
+    }
+}
+
+/// Resolves the event of topic0 from a signature or an event name in `abi`.
+pub fn resolve_event(value: &str, abi: Option<&Abi>) -> Result<Event> {
+    if value.contains('(') {
+        let signature = if value.starts_with("event ") {
+            value.to_owned()
+        } else {
+            format!("event {value}")
+        };
+        return AbiParser::default()
+            .parse_event(&signature)
+            .with_context(|| format!("Invalid event signature: {value}"));
+    }
+
+    abi.and_then(|abi| abi.events_by_name(value).ok())
+        .and_then(|events| events.first())
+        .cloned()
+        .with_context(|| format!("Event {value} was not found in the ABI."))
+}
+
+/// Full signature of an event with its parameter names, e.g. `Transfer(address indexed from, ...)`.
+pub fn event_signature(event: &Event) -> String {
+    format!(
+        "{}({})",
+        event.name,
+        event
+            .inputs
+            .iter()
+            .map(|input| format!(
+                "{}{} {}",
+                input.kind,
+                if input.indexed { " indexed" } else { "" },
+                input.name
+            ))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+/// Formats the logs as CSV with one column per log field and the decoded parameters joined.
+pub fn to_csv(logs: &[LogEntry]) -> String {
+    let escape = |value: String| {
+        if value.contains([',', '"', '\n']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value
+        }
+    };
+
+    let mut csv =
+        "block_number,transaction_hash,log_index,address,event,params,topics,data\n".to_string();
+    for entry in logs {
+        let (event, params) =
+            entry
+                .log
+                .event
+                .as_ref()
+                .map_or((String::new(), String::new()), |(name, params)| {
+                    (
+                        name.to_owned(),
+                        params
+                            .iter()
+                            .map(|(name, value)| format!("{name}={value}"))
+                            .collect::<Vec<_>>()
+                            .join("; "),
+                    )
+                });
+        let row = [
+            entry
+                .block_number
+                .map_or(String::new(), |number| number.to_string()),
+            entry
+                .transaction_hash
+                .map_or(String::new(), |hash| format!("{:#x}", hash)),
+            entry
+                .log_index
+                .map_or(String::new(), |index| index.to_string()),
+            format!("{:#x}", entry.log.address),
+            event,
+            params,
+            entry
+                .log
+                .topics
+                .iter()
+                .map(|topic| format!("{:#x}", topic))
+                .collect::<Vec<_>>()
+                .join(" "),
+            entry.log.data.to_string(),
+        ];
+        csv.push_str(&row.map(escape).join(","));
+        csv.push('\n');
+    }
+    csv
+}
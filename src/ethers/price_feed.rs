@@ -0,0 +1,42 @@
+use anyhow::{bail, Result};
+use ethers::{
+    core::{
+        abi::{AbiParser, Token},
+        types::{transaction::eip2718::TypedTransaction, Address, TransactionRequest, I256},
+    },
+    providers::{Http, Middleware, Provider},
+};
+use futures::future::try_join;
+
+/// The Chainlink ETH / USD aggregator on Ethereum mainnet.
+pub const CHAINLINK_ETH_USD: &str = "0x5f4eC3Df9cbd43714FE2740f5E3616155c5b8419";
+
+/// Reads the latest answer of a Chainlink aggregator, scaled by its decimals.
+pub async fn get_chainlink_price(provider: &Provider<Http>, feed: Address) -> Result<f64> {
+    let mut parser = AbiParser::default();
+    let latest_round_data = parser.parse_function(
+        "function latestRoundData() view returns (uint80, int256, uint256, uint256, uint80)",
+    )?;
+    let decimals = parser.parse_function("function decimals() view returns (uint8)")?;
+
+    let request =
+        |data| -> TypedTransaction { TransactionRequest::new().to(feed).data(data).into() };
+    let round_data_request = request(latest_round_data.encode_input(&[])?);
+    let decimals_request = request(decimals.encode_input(&[])?);
+    let (round_data, decimals_output) = try_join(
+        provider.call(&round_data_request, None),
+        provider.call(&decimals_request, None),
+    )
+    .await?;
+
+    let answer = match latest_round_data.decode_output(&round_data)?.get(1) {
+        Some(Token::Int(answer)) => I256::from_raw(*answer),
+        _ => bail!("The price feed returned no answer."),
+    };
+    let decimals = match decimals.decode_output(&decimals_output)?.first() {
+        Some(Token::Uint(decimals)) => decimals.low_u32() as i32,
+        _ => bail!("The price feed returned no decimals."),
+    };
+
+    Ok(answer.as_i128() as f64 / 10f64.powi(decimals))
+}
@@ -0,0 +1,216 @@
+use ethers::core::{
+    abi::Abi,
+    types::{Address, H256},
+};
+use std::fmt;
+
+/// `bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)`
+pub const EIP1967_IMPLEMENTATION_SLOT: H256 = H256([
+    0x36, 0x08, 0x94, 0xa1, 0x3b, 0xa1, 0xa3, 0x21, 0x06, 0x67, 0xc8, 0x28, 0x49, 0x2d, 0xb9, 0x8d,
+    0xca, 0x3e, 0x20, 0x76, 0xcc, 0x37, 0x35, 0xa9, 0x20, 0xa3, 0xca, 0x50, 0x5d, 0x38, 0x2b, 0xbc,
+]);
+
+/// `bytes32(uint256(keccak256("eip1967.proxy.admin")) - 1)`
+pub const EIP1967_ADMIN_SLOT: H256 = H256([
+    0xb5, 0x31, 0x27, 0x68, 0x4a, 0x56, 0x8b, 0x31, 0x73, 0xae, 0x13, 0xb9, 0xf8, 0xa6, 0x01, 0x6e,
+    0x24, 0x3e, 0x63, 0xb6, 0xe8, 0xee, 0x11, 0x78, 0xd6, 0xa7, 0x17, 0x85, 0x0b, 0x5d, 0x61, 0x03,
+]);
+
+/// `bytes32(uint256(keccak256("eip1967.proxy.beacon")) - 1)`
+pub const EIP1967_BEACON_SLOT: H256 = H256([
+    0xa3, 0xf0, 0xad, 0x74, 0xe5, 0x42, 0x3a, 0xeb, 0xfd, 0x80, 0xd3, 0xef, 0x43, 0x46, 0x57, 0x83,
+    0x35, 0xa9, 0xa7, 0x2a, 0xea, 0xee, 0x59, 0xff, 0x6c, 0xb3, 0x58, 0x2b, 0x35, 0x13, 0x3d, 0x50,
+]);
+
+/// `keccak256("PROXIABLE")`
+pub const EIP1822_PROXIABLE_SLOT: H256 = H256([
+    0xc5, 0xf1, 0x6f, 0x0f, 0xcc, 0x63, 0x9f, 0xa4, 0x8a, 0x69, 0x47, 0x83, 0x6d, 0x98, 0x50, 0xf5,
+    0x04, 0x79, 0x85, 0x23, 0xbf, 0x8c, 0x9a, 0x3a, 0x87, 0xd5, 0x87, 0x6c, 0xf6, 0x22, 0xbc, 0xf7,
+]);
+
+/// `keccak256("org.zeppelinos.proxy.implementation")`
+pub const OPEN_ZEPPELIN_IMPLEMENTATION_SLOT: H256 = H256([
+    0x70, 0x50, 0xc9, 0xe0, 0xf4, 0xca, 0x76, 0x9c, 0x69, 0xbd, 0x3a, 0x8e, 0xf7, 0x40, 0xbc, 0x37,
+    0x93, 0x4f, 0x8e, 0x2c, 0x03, 0x6e, 0x5a, 0x72, 0x3f, 0xd8, 0xee, 0x04, 0x8e, 0xd3, 0xf8, 0xc3,
+]);
+
+const EIP1167_PREFIX: [u8; 10] = [0x36, 0x3d, 0x3d, 0x37, 0x3d, 0x3d, 0x3d, 0x36, 0x3d, 0x73];
+const EIP1167_SUFFIX: [u8; 15] = [
+    0x5a, 0xf4, 0x3d, 0x82, 0x80, 0x3e, 0x90, 0x3d, 0x91, 0x60, 0x2b, 0x57, 0xfd, 0x5b, 0xf3,
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProxyKind {
+    Eip1967,
+    Eip1967Beacon,
+    Eip1822,
+    OpenZeppelinLegacy,
+    GnosisSafe,
+    Eip1167,
+}
+
+impl fmt::Display for ProxyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Eip1967 => "EIP-1967",
+                Self::Eip1967Beacon => "EIP-1967 Beacon",
+                Self::Eip1822 => "EIP-1822",
+                Self::OpenZeppelinLegacy => "OpenZeppelin (legacy)",
+                Self::GnosisSafe => "Gnosis Safe",
+                Self::Eip1167 => "EIP-1167 Minimal Proxy",
+            }
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProxyInfo {
+    pub kind: ProxyKind,
+    pub implementation: Address,
+    pub admin: Option<Address>,
+    pub beacon: Option<Address>,
+}
+
+/// Returns the address stored in the lower 20 bytes of a storage slot, if any.
+pub fn slot_to_address(value: H256) -> Option<Address> {
+    let address = Address::from(value);
+    if address.is_zero() {
+        None
+    } else {
+        Some(address)
+    }
+}
+
+/// Returns the implementation of an EIP-1167 minimal proxy from its runtime bytecode.
+pub fn minimal_proxy_implementation(code: &[u8]) -> Option<Address> {
+    if code.len() == EIP1167_PREFIX.len() + 20 + EIP1167_SUFFIX.len()
+        && code.starts_with(&EIP1167_PREFIX)
+        && code.ends_with(&EIP1167_SUFFIX)
+    {
+        Some(Address::from_slice(
+            &code[EIP1167_PREFIX.len()..EIP1167_PREFIX.len() + 20],
+        ))
+    } else {
+        None
+    }
+}
+
+/// Merges the ABI of an implementation into the ABI of its proxy.
+/// Entries of the proxy take precedence over the ones of the implementation with the same signature.
+pub fn merge_abis(proxy_abi: Option<Abi>, implementation_abi: Option<Abi>) -> Option<Abi> {
+    match (proxy_abi, implementation_abi) {
+        (Some(mut abi), Some(implementation_abi)) => {
+            for (name, functions) in implementation_abi.functions {
+                let entry = abi.functions.entry(name).or_default();
+                for function in functions {
+                    if !entry
+                        .iter()
+                        .any(|f| f.short_signature() == function.short_signature())
+                    {
+                        entry.push(function);
+                    }
+                }
+            }
+            for (name, events) in implementation_abi.events {
+                let entry = abi.events.entry(name).or_default();
+                for event in events {
+                    if !entry.iter().any(|e| e.signature() == event.signature()) {
+                        entry.push(event);
+                    }
+                }
+            }
+            for (name, errors) in implementation_abi.errors {
+                let entry = abi.errors.entry(name).or_default();
+                for error in errors {
+                    if !entry.iter().any(|e| e.signature() == error.signature()) {
+                        entry.push(error);
+                    }
+                }
+            }
+            abi.receive |= implementation_abi.receive;
+            abi.fallback |= implementation_abi.fallback;
+            Some(abi)
+        }
+        (abi, None) | (None, abi) => abi,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::core::{abi::parse_abi, types::U256, utils::keccak256};
+
+    fn eip1967_slot(name: &str) -> H256 {
+        let mut bytes = [0u8; 32];
+        (U256::from_big_endian(&keccak256(name)) - 1).to_big_endian(&mut bytes);
+        H256(bytes)
+    }
+
+    #[test]
+    fn slots() {
+        assert_eq!(
+            EIP1967_IMPLEMENTATION_SLOT,
+            eip1967_slot("eip1967.proxy.implementation")
+        );
+        assert_eq!(EIP1967_ADMIN_SLOT, eip1967_slot("eip1967.proxy.admin"));
+        assert_eq!(EIP1967_BEACON_SLOT, eip1967_slot("eip1967.proxy.beacon"));
+        assert_eq!(EIP1822_PROXIABLE_SLOT, H256(keccak256("PROXIABLE")));
+        assert_eq!(
+            OPEN_ZEPPELIN_IMPLEMENTATION_SLOT,
+            H256(keccak256("org.zeppelinos.proxy.implementation"))
+        );
+    }
+
+    #[test]
+    fn slot_values() {
+        let address = Address::from_low_u64_be(0xdead);
+
+        assert_eq!(slot_to_address(H256::from(address)), Some(address));
+        assert_eq!(slot_to_address(H256::zero()), None);
+    }
+
+    #[test]
+    fn minimal_proxy() {
+        let implementation = Address::from_low_u64_be(0xbeef);
+        let code = [
+            &EIP1167_PREFIX[..],
+            implementation.as_bytes(),
+            &EIP1167_SUFFIX[..],
+        ]
+        .concat();
+
+        assert_eq!(minimal_proxy_implementation(&code), Some(implementation));
+        assert_eq!(minimal_proxy_implementation(&code[1..]), None);
+        assert_eq!(minimal_proxy_implementation(&[]), None);
+    }
+
+    #[test]
+    fn merged_abis() {
+        let proxy_abi = parse_abi(&[
+            "function upgradeTo(address implementation)",
+            "event Upgraded(address indexed implementation)",
+        ])
+        .unwrap();
+        let implementation_abi = parse_abi(&[
+            "function upgradeTo(address implementation)",
+            "function balanceOf(address owner) view returns (uint256)",
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+        ])
+        .unwrap();
+
+        let abi = merge_abis(Some(proxy_abi.to_owned()), Some(implementation_abi)).unwrap();
+
+        assert_eq!(abi.functions["upgradeTo"].len(), 1);
+        assert!(abi.functions.contains_key("balanceOf"));
+        assert!(abi.events.contains_key("Upgraded"));
+        assert!(abi.events.contains_key("Transfer"));
+        assert_eq!(
+            merge_abis(Some(proxy_abi.to_owned()), None),
+            Some(proxy_abi)
+        );
+        assert_eq!(merge_abis(None, None), None);
+    }
+}
@@ -0,0 +1,125 @@
+use ethers::core::{
+    abi::{decode, Abi, ParamType, Token},
+    types::U256,
+    utils::{hex, id},
+};
+
+/// Decodes the return data of a reverted call into a human readable reason.
+/// Custom errors are looked up in `abi` when it is given.
+pub fn decode_revert_data(data: &[u8], abi: Option<&Abi>) -> String {
+    if data.len() < 4 {
+        return if data.is_empty() {
+            "execution reverted".to_owned()
+        } else {
+            format!("0x{}", hex::encode(data))
+        };
+    }
+
+    let (selector, args) = data.split_at(4);
+
+    if selector == id("Error(string)") {
+        if let Ok(tokens) = decode(&[ParamType::String], args) {
+            if let Some(Token::String(reason)) = tokens.first() {
+                return reason.to_owned();
+            }
+        }
+    } else if selector == id("Panic(uint256)") {
+        if let Ok(tokens) = decode(&[ParamType::Uint(256)], args) {
+            if let Some(Token::Uint(code)) = tokens.first() {
+                return format!("Panic({:#04x}): {}", code.low_u64(), panic_reason(*code));
+            }
+        }
+    } else if let Some(error) = abi.and_then(|abi| {
+        abi.errors()
+            .find(|error| &error.signature().as_bytes()[..4] == selector)
+    }) {
+        if let Ok(tokens) = error.decode(args) {
+            return format!(
+                "{}({})",
+                error.name,
+                tokens
+                    .iter()
+                    .map(|token| token.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+    }
+
+    format!("0x{}", hex::encode(data))
+}
+
+/// See https://docs.soliditylang.org/en/latest/control-structures.html#panic-via-assert-and-error-via-require
+fn panic_reason(code: U256) -> &'static str {
+    match code.low_u64() {
+        0x00 => "generic compiler inserted panic",
+        0x01 => "assertion failed",
+        0x11 => "arithmetic overflow or underflow",
+        0x12 => "division or modulo by zero",
+        0x21 => "invalid enum value",
+        0x22 => "incorrectly encoded storage byte array",
+        0x31 => "pop() on an empty array",
+        0x32 => "array index out of bounds",
+        0x41 => "too much memory allocated",
+        0x51 => "call to a zero-initialized internal function",
+        _ => "unknown panic code",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::core::abi::{encode, parse_abi};
+
+    fn revert_data(signature: &str, tokens: &[Token]) -> Vec<u8> {
+        [&id(signature)[..], &encode(tokens)].concat()
+    }
+
+    #[test]
+    fn error_string() {
+        let data = revert_data(
+            "Error(string)",
+            &[Token::String("Ownable: caller is not the owner".to_owned())],
+        );
+
+        assert_eq!(
+            decode_revert_data(&data, None),
+            "Ownable: caller is not the owner"
+        );
+    }
+
+    #[test]
+    fn panic_code() {
+        let data = revert_data("Panic(uint256)", &[Token::Uint(U256::from(0x11))]);
+
+        assert_eq!(
+            decode_revert_data(&data, None),
+            "Panic(0x11): arithmetic overflow or underflow"
+        );
+    }
+
+    #[test]
+    fn custom_error() {
+        let abi =
+            parse_abi(&["error InsufficientBalance(uint256 available, uint256 required)"]).unwrap();
+        let data = revert_data(
+            "InsufficientBalance(uint256,uint256)",
+            &[Token::Uint(U256::from(1)), Token::Uint(U256::from(2))],
+        );
+
+        assert_eq!(
+            decode_revert_data(&data, Some(&abi)),
+            "InsufficientBalance(1, 2)"
+        );
+        assert_eq!(
+            decode_revert_data(&data, None),
+            format!("0x{}", hex::encode(&data))
+        );
+    }
+
+    #[test]
+    fn empty_and_short_data() {
+        assert_eq!(decode_revert_data(&[], None), "execution reverted");
+        assert_eq!(decode_revert_data(&[0xde, 0xad], None), "0xdead");
+    }
+}
@@ -0,0 +1,59 @@
+use super::event_log::DecodedLog;
+use ethers::core::types::{Address, BlockId, Bytes, CallFrame, H256, U256};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Calldata {
+    Raw(Bytes),
+    /// A function signature such as `balanceOf(address)` or the name of a function in the
+    /// verified ABI of the callee, with its arguments separated by commas.
+    Function {
+        signature: String,
+        arguments: Vec<String>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulationRequest {
+    pub from: Option<Address>,
+    pub to: Option<Address>,
+    pub value: U256,
+    pub calldata: Calldata,
+    pub block: BlockId,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulationResult {
+    pub is_success: bool,
+    pub revert_reason: Option<String>,
+    pub gas_estimate: Option<U256>,
+    /// Only available when the node supports `debug_traceCall`.
+    pub gas_used: Option<U256>,
+    pub return_data: Bytes,
+    pub decoded_output: Vec<String>,
+    /// Only available when the node supports `debug_traceCall`.
+    pub logs: Option<Vec<DecodedLog>>,
+}
+
+/// Collects the logs emitted by a call frame and its subcalls, skipping reverted frames.
+pub fn collect_logs(frame: &CallFrame) -> Vec<(Address, Vec<H256>, Bytes)> {
+    let mut logs = vec![];
+    if frame.error.is_some() {
+        return logs;
+    }
+
+    for log in frame.logs.iter().flatten() {
+        if let Some(address) = log.address {
+            logs.push((
+                address,
+                log.topics.to_owned().unwrap_or_default(),
+                log.data.to_owned().unwrap_or_default(),
+            ));
+        }
+    }
+
+    for call in frame.calls.iter().flatten() {
+        logs.append(&mut collect_logs(call));
+    }
+
+    logs
+}
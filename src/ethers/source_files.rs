@@ -0,0 +1,138 @@
+use ethers::etherscan::contract::ContractMetadata;
+use serde_json::Value;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SourceLanguage {
+    Solidity,
+    Vyper,
+}
+
+impl SourceLanguage {
+    fn detect(path: &str, compiler_version: &str) -> Self {
+        if path.ends_with(".vy") || compiler_version.starts_with("vyper") {
+            Self::Vyper
+        } else {
+            Self::Solidity
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceFile {
+    pub path: String,
+    pub content: String,
+    pub language: SourceLanguage,
+}
+
+impl SourceFile {
+    pub fn new(path: &str, content: &str, compiler_version: &str) -> Self {
+        Self {
+            path: path.to_owned(),
+            content: content.to_owned(),
+            language: SourceLanguage::detect(path, compiler_version),
+        }
+    }
+
+    /// Indices of the lines containing `query`, ignoring case.
+    pub fn matches(&self, query: &str) -> Vec<usize> {
+        let query = query.to_lowercase();
+        self.content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| line.to_lowercase().contains(&query))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// Extracts every file of a verified contract.
+/// Etherscan returns either a single flattened file, a JSON object of files, or a standard JSON
+/// input wrapped in an extra pair of braces.
+pub fn source_files(contract_source_code: &ContractMetadata) -> Vec<SourceFile> {
+    let mut files = vec![];
+
+    for item in contract_source_code.items.iter() {
+        let sources = match serde_json::to_value(&item.source_code) {
+            Ok(Value::String(source_code)) => {
+                let trimmed = source_code.trim();
+                let json = if trimmed.starts_with("{{") && trimmed.ends_with("}}") {
+                    &trimmed[1..trimmed.len() - 1]
+                } else {
+                    trimmed
+                };
+                match serde_json::from_str::<Value>(json) {
+                    Ok(value @ Value::Object(_)) => sources_from_json(&value),
+                    _ => vec![(format!("{}.sol", item.contract_name), source_code)],
+                }
+            }
+            Ok(value) => sources_from_json(&value),
+            Err(_) => vec![],
+        };
+
+        for (path, content) in sources {
+            files.push(SourceFile::new(&path, &content, &item.compiler_version));
+        }
+    }
+
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    files
+}
+
+fn sources_from_json(value: &Value) -> Vec<(String, String)> {
+    let sources = value.get("sources").unwrap_or(value);
+    sources
+        .as_object()
+        .map(|sources| {
+            sources
+                .iter()
+                .filter_map(|(path, source)| {
+                    source
+                        .get("content")
+                        .and_then(Value::as_str)
+                        .map(|content| (path.to_owned(), content.to_owned()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// A row of the file tree, either a directory or a file of `files`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileTreeRow {
+    pub depth: usize,
+    pub name: String,
+    pub file_index: Option<usize>,
+}
+
+/// Lays out sorted files as a tree, emitting each directory once before its first file.
+pub fn file_tree(files: &[SourceFile]) -> Vec<FileTreeRow> {
+    let mut rows = vec![];
+    let mut current_dirs: Vec<&str> = vec![];
+
+    for (i, file) in files.iter().enumerate() {
+        let mut components = file.path.split('/').collect::<Vec<_>>();
+        let name = components.pop().unwrap_or_default();
+
+        let common = current_dirs
+            .iter()
+            .zip(components.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        for (depth, dir) in components.iter().enumerate().skip(common) {
+            rows.push(FileTreeRow {
+                depth,
+                name: format!("{dir}/"),
+                file_index: None,
+            });
+        }
+        current_dirs = components;
+
+        rows.push(FileTreeRow {
+            depth: current_dirs.len(),
+            name: name.to_owned(),
+            file_index: Some(i),
+        });
+    }
+
+    rows
+}
@@ -0,0 +1,319 @@
+use super::{
+    bytecode::split_metadata,
+    source_files::{source_files, SourceFile},
+    storage::StorageLayout,
+};
+use anyhow::{bail, Result};
+use ethers::{
+    core::{
+        abi::Abi,
+        types::{Address, Bytes, Chain, H256},
+        utils::{keccak256, to_checksum},
+    },
+    etherscan::Client,
+    providers::{Http, Middleware, Provider},
+};
+use futures::future::{try_join, BoxFuture};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The ABI and source files of a contract, as published by a source provider.
+/// `storage_layout` is only known for local artifacts compiled with the `storageLayout` output.
+#[derive(Clone, Debug, Default)]
+pub struct VerifiedContract {
+    pub abi: Option<Abi>,
+    pub source_files: Vec<SourceFile>,
+    pub storage_layout: Option<StorageLayout>,
+}
+
+/// A backend serving verified contracts. Providers are tried in the order given on the command
+/// line until one of them knows the contract.
+pub trait SourceProvider: Send + Sync {
+    fn get_verified_contract(&self, address: Address) -> BoxFuture<'_, Result<VerifiedContract>>;
+}
+
+pub struct EtherscanSourceProvider;
+
+impl SourceProvider for EtherscanSourceProvider {
+    fn get_verified_contract(&self, address: Address) -> BoxFuture<'_, Result<VerifiedContract>> {
+        Box::pin(async move {
+            let client = Client::new_from_env(Chain::Mainnet)?;
+            let (contract_source_code, abi) = try_join(
+                client.contract_source_code(address),
+                client.contract_abi(address),
+            )
+            .await?;
+
+            Ok(VerifiedContract {
+                abi: Some(abi),
+                source_files: source_files(&contract_source_code),
+                storage_layout: None,
+            })
+        })
+    }
+}
+
+/// https://docs.sourcify.dev/docs/api/
+pub struct SourcifySourceProvider {
+    endpoint: String,
+    client: reqwest::Client,
+}
+
+impl SourcifySourceProvider {
+    const SERVER_URL: &'static str = "https://sourcify.dev/server";
+
+    pub fn new(endpoint: &str) -> Self {
+        Self {
+            endpoint: endpoint.to_owned(),
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+impl SourceProvider for SourcifySourceProvider {
+    fn get_verified_contract(&self, address: Address) -> BoxFuture<'_, Result<VerifiedContract>> {
+        Box::pin(async move {
+            let provider = Provider::<Http>::try_from(self.endpoint.as_str())?;
+            let chain_id = provider.get_chainid().await?;
+
+            let response = self
+                .client
+                .get(format!(
+                    "{}/files/any/{}/{}",
+                    Self::SERVER_URL,
+                    chain_id,
+                    to_checksum(&address, None)
+                ))
+                .send()
+                .await?
+                .error_for_status()?
+                .json::<Value>()
+                .await?;
+
+            let files = response
+                .get("files")
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default();
+
+            let metadata = files
+                .iter()
+                .find(|file| file.get("name").and_then(Value::as_str) == Some("metadata.json"))
+                .and_then(|file| file.get("content").and_then(Value::as_str))
+                .and_then(|content| serde_json::from_str::<Value>(content).ok());
+
+            let compiler_version = metadata
+                .as_ref()
+                .and_then(|metadata| metadata.pointer("/compiler/version"))
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_owned();
+
+            let abi = metadata
+                .as_ref()
+                .and_then(|metadata| metadata.pointer("/output/abi"))
+                .and_then(|abi| serde_json::from_value::<Abi>(abi.to_owned()).ok());
+
+            let mut source_files = files
+                .iter()
+                .filter_map(|file| {
+                    let path = file.get("path").and_then(Value::as_str)?;
+                    let content = file.get("content").and_then(Value::as_str)?;
+                    // Paths look like `.../full_match/1/0x.../sources/contracts/Token.sol`
+                    let (_, path) = path.split_once("/sources/")?;
+                    Some(SourceFile::new(path, content, &compiler_version))
+                })
+                .collect::<Vec<_>>();
+            source_files.sort_by(|a, b| a.path.cmp(&b.path));
+
+            if abi.is_none() && source_files.is_empty() {
+                bail!("The contract is not verified on Sourcify.");
+            }
+
+            Ok(VerifiedContract {
+                abi,
+                source_files,
+                storage_layout: None,
+            })
+        })
+    }
+}
+
+/// Matches the deployed bytecode against the artifacts of a Foundry (`out/`) or Hardhat
+/// (`artifacts/`) project.
+/// The artifacts are indexed once by the hash of their deployed bytecode, without the metadata
+/// and with the immutables zeroed.
+pub struct LocalArtifactsSourceProvider {
+    endpoint: String,
+    artifacts: HashMap<H256, VerifiedContract>,
+    /// Distinct `(start, length)` ranges of the immutables of the indexed artifacts.
+    immutables: Vec<Vec<(usize, usize)>>,
+}
+
+impl LocalArtifactsSourceProvider {
+    pub fn new(endpoint: &str, directories: &[PathBuf]) -> Self {
+        let mut provider = Self {
+            endpoint: endpoint.to_owned(),
+            artifacts: HashMap::new(),
+            immutables: vec![],
+        };
+        for directory in directories {
+            // `out/` and `artifacts/` live in the project root
+            let root = directory.parent().unwrap_or(directory);
+            provider.index(root, directory);
+        }
+        provider
+    }
+
+    fn index(&mut self, root: &Path, directory: &Path) {
+        let Ok(entries) = fs::read_dir(directory) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                self.index(root, &path);
+            } else if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                let Some(artifact) = fs::read_to_string(&path)
+                    .ok()
+                    .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+                else {
+                    continue;
+                };
+                let Some((code, immutables)) = Self::deployed_bytecode(&artifact) else {
+                    continue;
+                };
+
+                let key = Self::key(&code, &immutables);
+                if !self.immutables.contains(&immutables) {
+                    self.immutables.push(immutables);
+                }
+                self.artifacts
+                    .entry(key)
+                    .or_insert_with(|| VerifiedContract {
+                        abi: artifact
+                            .get("abi")
+                            .and_then(|abi| serde_json::from_value::<Abi>(abi.to_owned()).ok()),
+                        source_files: Self::source_files(root, &artifact),
+                        storage_layout: artifact.get("storageLayout").and_then(|layout| {
+                            serde_json::from_value::<StorageLayout>(layout.to_owned()).ok()
+                        }),
+                    });
+            }
+        }
+    }
+
+    /// The deployed bytecode of an artifact and the ranges of its immutables, which are only
+    /// known after deployment.
+    fn deployed_bytecode(artifact: &Value) -> Option<(Bytes, Vec<(usize, usize)>)> {
+        // Foundry nests the bytecode in an object, Hardhat stores it as a string
+        let deployed_bytecode = artifact.get("deployedBytecode")?;
+        let code = deployed_bytecode
+            .get("object")
+            .unwrap_or(deployed_bytecode)
+            .as_str()?
+            .parse::<Bytes>()
+            .ok()
+            .filter(|code| !code.is_empty())?;
+
+        let mut immutables = deployed_bytecode
+            .get("immutableReferences")
+            .and_then(Value::as_object)
+            .map(|references| {
+                references
+                    .values()
+                    .filter_map(Value::as_array)
+                    .flatten()
+                    .map(|range| {
+                        let start = range.get("start").and_then(Value::as_u64).unwrap_or(0);
+                        let length = range.get("length").and_then(Value::as_u64).unwrap_or(0);
+                        (start as usize, length as usize)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        immutables.sort();
+        Some((code, immutables))
+    }
+
+    fn key(code: &[u8], immutables: &[(usize, usize)]) -> H256 {
+        let mut code = code.to_vec();
+        for &(start, length) in immutables {
+            if let Some(slice) = code.get_mut(start..start + length) {
+                slice.fill(0);
+            }
+        }
+        let (code, _) = split_metadata(&code);
+        H256::from(keccak256(code))
+    }
+
+    fn source_files(root: &Path, artifact: &Value) -> Vec<SourceFile> {
+        let compiler_version = artifact
+            .pointer("/metadata/compiler/version")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+
+        let source_name = artifact
+            .get("sourceName")
+            .and_then(Value::as_str)
+            .map(|source_name| source_name.to_owned())
+            .or_else(|| {
+                artifact
+                    .pointer("/metadata/settings/compilationTarget")
+                    .and_then(Value::as_object)
+                    .and_then(|targets| targets.keys().next().cloned())
+            });
+
+        source_name
+            .and_then(|source_name| {
+                fs::read_to_string(root.join(&source_name))
+                    .ok()
+                    .map(|content| vec![SourceFile::new(&source_name, &content, compiler_version)])
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl SourceProvider for LocalArtifactsSourceProvider {
+    fn get_verified_contract(&self, address: Address) -> BoxFuture<'_, Result<VerifiedContract>> {
+        Box::pin(async move {
+            let provider = Provider::<Http>::try_from(self.endpoint.as_str())?;
+            let code = provider.get_code(address, None).await?;
+            if code.is_empty() {
+                bail!("{:#x} is not a contract.", address);
+            }
+
+            if let Some(contract) = self
+                .immutables
+                .iter()
+                .find_map(|immutables| self.artifacts.get(&Self::key(&code, immutables)))
+            {
+                return Ok(contract.to_owned());
+            }
+
+            bail!("No artifact matches the bytecode of {:#x}.", address)
+        })
+    }
+}
+
+/// Asks each provider in turn and returns the first contract that has an ABI or source files.
+pub async fn get_verified_contract(
+    source_providers: &[Box<dyn SourceProvider>],
+    address: Address,
+) -> Option<VerifiedContract> {
+    for source_provider in source_providers {
+        if let Ok(contract) = source_provider.get_verified_contract(address).await {
+            if contract.abi.is_some() || !contract.source_files.is_empty() {
+                return Some(contract);
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,258 @@
+use ethers::core::{
+    types::{AccountState, Address, DiffMode, TransactionReceipt, H256, I256, U256},
+    utils::keccak256,
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateChanges {
+    pub accounts: Vec<AccountStateChange>,
+    pub token_balance_changes: Vec<TokenBalanceChange>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountStateChange {
+    pub address: Address,
+    pub balance: Option<(U256, U256)>,
+    pub nonce: Option<(U256, U256)>,
+    pub code: Option<(Option<String>, Option<String>)>,
+    pub storage: Vec<StorageChange>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StorageChange {
+    pub slot: H256,
+    pub before: H256,
+    pub after: H256,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenBalanceChange {
+    pub token: Address,
+    pub holder: Address,
+    pub delta: I256,
+}
+
+impl StateChanges {
+    /// Builds the per-account changes from a `prestateTracer` trace in diff mode
+    /// and the token balance changes from the `Transfer` events of the receipt.
+    pub fn new(diff: DiffMode, transaction_receipt: &TransactionReceipt) -> Self {
+        let DiffMode { pre, post } = diff;
+        let addresses = pre.keys().chain(post.keys()).collect::<BTreeSet<_>>();
+
+        let accounts = addresses
+            .into_iter()
+            .map(|address| {
+                AccountStateChange::new(address.to_owned(), pre.get(address), post.get(address))
+            })
+            .collect();
+
+        Self {
+            accounts,
+            token_balance_changes: TokenBalanceChange::from_receipt(transaction_receipt),
+        }
+    }
+}
+
+impl AccountStateChange {
+    // In diff mode `post` only contains the fields that were modified.
+    // A storage slot present in `pre` but missing in `post` has been cleared.
+    fn new(address: Address, pre: Option<&AccountState>, post: Option<&AccountState>) -> Self {
+        let balance = post
+            .and_then(|post| post.balance)
+            .map(|after| (pre.and_then(|pre| pre.balance).unwrap_or_default(), after));
+        let nonce = post
+            .and_then(|post| post.nonce)
+            .map(|after| (pre.and_then(|pre| pre.nonce).unwrap_or_default(), after));
+        let code = post
+            .and_then(|post| post.code.to_owned())
+            .map(|after| (pre.and_then(|pre| pre.code.to_owned()), Some(after)));
+
+        let empty = BTreeMap::new();
+        let pre_storage = pre.and_then(|pre| pre.storage.as_ref()).unwrap_or(&empty);
+        let post_storage = post
+            .and_then(|post| post.storage.as_ref())
+            .unwrap_or(&empty);
+        let storage = pre_storage
+            .keys()
+            .chain(post_storage.keys())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|slot| StorageChange {
+                slot: slot.to_owned(),
+                before: pre_storage.get(slot).copied().unwrap_or_default(),
+                after: post_storage.get(slot).copied().unwrap_or_default(),
+            })
+            .filter(|change| change.before != change.after)
+            .collect();
+
+        Self {
+            address,
+            balance,
+            nonce,
+            code,
+            storage,
+        }
+    }
+}
+
+impl TokenBalanceChange {
+    /// Sums up `Transfer(address,address,uint256)` events per token and holder.
+    pub fn from_receipt(transaction_receipt: &TransactionReceipt) -> Vec<Self> {
+        let transfer_topic = H256::from(keccak256("Transfer(address,address,uint256)"));
+        let mut deltas: BTreeMap<(Address, Address), I256> = BTreeMap::new();
+
+        for log in &transaction_receipt.logs {
+            // ERC-721 also emits `Transfer` but with the token id as the third indexed topic
+            if log.topics.len() != 3 || log.topics[0] != transfer_topic || log.data.len() != 32 {
+                continue;
+            }
+            let from = Address::from(log.topics[1]);
+            let to = Address::from(log.topics[2]);
+            let value = I256::from_raw(U256::from_big_endian(&log.data));
+
+            *deltas.entry((log.address, from)).or_default() -= value;
+            *deltas.entry((log.address, to)).or_default() += value;
+        }
+
+        deltas
+            .into_iter()
+            .filter(|(_, delta)| !delta.is_zero())
+            .map(|((token, holder), delta)| Self {
+                token,
+                holder,
+                delta,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::core::types::Log;
+
+    fn address(n: u64) -> Address {
+        Address::from_low_u64_be(n)
+    }
+
+    fn account(balance: Option<u64>, nonce: Option<u64>, storage: &[(u64, u64)]) -> AccountState {
+        AccountState {
+            balance: balance.map(U256::from),
+            nonce: nonce.map(U256::from),
+            code: None,
+            storage: Some(
+                storage
+                    .iter()
+                    .map(|(slot, value)| {
+                        (H256::from_low_u64_be(*slot), H256::from_low_u64_be(*value))
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    fn transfer(token: u64, from: u64, to: u64, value: u64) -> Log {
+        let mut data = [0u8; 32];
+        U256::from(value).to_big_endian(&mut data);
+        Log {
+            address: address(token),
+            topics: vec![
+                H256::from(keccak256("Transfer(address,address,uint256)")),
+                H256::from(address(from)),
+                H256::from(address(to)),
+            ],
+            data: data.to_vec().into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn account_changes() {
+        let diff = DiffMode {
+            pre: BTreeMap::from([
+                (address(1), account(Some(100), Some(1), &[])),
+                (
+                    address(2),
+                    account(Some(0), None, &[(1, 1), (2, 2), (3, 3)]),
+                ),
+            ]),
+            post: BTreeMap::from([
+                (address(1), account(Some(90), Some(2), &[])),
+                (address(2), account(None, None, &[(1, 10), (4, 4)])),
+            ]),
+        };
+
+        let state_changes = StateChanges::new(diff, &TransactionReceipt::default());
+
+        assert_eq!(
+            state_changes.accounts[0],
+            AccountStateChange {
+                address: address(1),
+                balance: Some((U256::from(100), U256::from(90))),
+                nonce: Some((U256::from(1), U256::from(2))),
+                code: None,
+                storage: vec![],
+            }
+        );
+        assert_eq!(state_changes.accounts[1].balance, None);
+        assert_eq!(
+            state_changes.accounts[1].storage,
+            [(1, 1, 10), (2, 2, 0), (3, 3, 0), (4, 0, 4)]
+                .into_iter()
+                .map(|(slot, before, after)| StorageChange {
+                    slot: H256::from_low_u64_be(slot),
+                    before: H256::from_low_u64_be(before),
+                    after: H256::from_low_u64_be(after),
+                })
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn created_account() {
+        let diff = DiffMode {
+            pre: BTreeMap::new(),
+            post: BTreeMap::from([(address(3), account(Some(5), Some(1), &[(1, 1)]))]),
+        };
+
+        let state_changes = StateChanges::new(diff, &TransactionReceipt::default());
+
+        assert_eq!(
+            state_changes.accounts[0].balance,
+            Some((U256::zero(), U256::from(5)))
+        );
+        assert_eq!(state_changes.accounts[0].storage.len(), 1);
+    }
+
+    #[test]
+    fn token_balance_changes() {
+        let mut erc721_transfer = transfer(11, 1, 2, 1);
+        erc721_transfer.topics.push(H256::from_low_u64_be(1));
+        let transaction_receipt = TransactionReceipt {
+            logs: vec![
+                transfer(10, 1, 2, 100),
+                transfer(10, 2, 3, 40),
+                transfer(10, 3, 1, 40),
+                erc721_transfer,
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            TokenBalanceChange::from_receipt(&transaction_receipt),
+            vec![
+                TokenBalanceChange {
+                    token: address(10),
+                    holder: address(1),
+                    delta: I256::from(-60),
+                },
+                TokenBalanceChange {
+                    token: address(10),
+                    holder: address(2),
+                    delta: I256::from(60),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,366 @@
+use anyhow::{bail, Context, Result};
+use ethers::core::{
+    abi::{encode, token::LenientTokenizer, token::Tokenizer, HumanReadableParser, ParamType},
+    types::{Address, BlockId, H256, I256, U256, U64},
+    utils::{hex, keccak256},
+};
+use serde::Deserialize;
+use std::{collections::HashMap, fmt};
+
+/// The `storageLayout` output of solc.
+/// See https://docs.soliditylang.org/en/latest/internals/layout_in_storage.html#json-output
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct StorageLayout {
+    pub storage: Vec<StorageVariable>,
+    #[serde(default)]
+    pub types: Option<HashMap<String, StorageType>>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct StorageVariable {
+    pub label: String,
+    pub slot: String,
+    pub offset: usize,
+    #[serde(rename = "type")]
+    pub type_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct StorageType {
+    pub encoding: String,
+    pub label: String,
+    #[serde(rename = "numberOfBytes")]
+    pub number_of_bytes: String,
+}
+
+impl StorageLayout {
+    pub fn type_of(&self, variable: &StorageVariable) -> Option<&StorageType> {
+        self.types.as_ref()?.get(&variable.type_id)
+    }
+
+    pub fn find(&self, label: &str) -> Option<&StorageVariable> {
+        self.storage.iter().find(|variable| variable.label == label)
+    }
+}
+
+impl StorageVariable {
+    pub fn slot(&self) -> H256 {
+        u256_to_h256(U256::from_dec_str(&self.slot).unwrap_or_default())
+    }
+}
+
+/// A slot given as a number, a hash or the name of a state variable, followed by the mapping keys
+/// and array indexes (`[i]`) to walk from it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StorageQuery {
+    pub slot: String,
+    pub keys: Vec<String>,
+    pub block: BlockId,
+}
+
+impl StorageQuery {
+    pub fn resolve(&self, layout: Option<&StorageLayout>) -> Result<H256> {
+        let mut slot = parse_slot(&self.slot, layout)?;
+        for key in self.keys.iter() {
+            slot = if let Some(index) = key.strip_prefix('[').and_then(|key| key.strip_suffix(']'))
+            {
+                array_slot(
+                    slot,
+                    U256::from_dec_str(index.trim())
+                        .with_context(|| format!("Invalid array index: {index}"))?,
+                )
+            } else {
+                mapping_slot(slot, &encode_key(key)?)
+            };
+        }
+        Ok(slot)
+    }
+}
+
+impl fmt::Display for StorageQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.slot.trim())?;
+        for key in self.keys.iter() {
+            if key.starts_with('[') {
+                write!(f, "{key}")?;
+            } else {
+                write!(f, "[{key}]")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The storage at a block: the queried slot, if any, and the decoded state variables.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StorageSnapshot {
+    pub block_number: U64,
+    pub slot: Option<(String, H256, H256)>,
+    pub variables: Vec<(String, String)>,
+}
+
+pub fn parse_slot(value: &str, layout: Option<&StorageLayout>) -> Result<H256> {
+    let value = value.trim();
+    if let Some(variable) = layout.and_then(|layout| layout.find(value)) {
+        return Ok(variable.slot());
+    }
+    if let Some(hex) = value.strip_prefix("0x") {
+        Ok(u256_to_h256(
+            U256::from_str_radix(hex, 16).with_context(|| format!("Invalid slot: {value}"))?,
+        ))
+    } else {
+        Ok(u256_to_h256(
+            U256::from_dec_str(value).with_context(|| format!("Invalid slot: {value}"))?,
+        ))
+    }
+}
+
+/// `keccak256(key . slot)`, where value types are padded to 32 bytes and strings and bytes are not.
+pub fn mapping_slot(slot: H256, key: &[u8]) -> H256 {
+    H256(keccak256([key, slot.as_bytes()].concat()))
+}
+
+/// `keccak256(slot) + index` for dynamic arrays whose elements take a whole slot.
+pub fn array_slot(slot: H256, index: U256) -> H256 {
+    u256_to_h256(
+        U256::from_big_endian(&keccak256(slot.as_bytes()))
+            .overflowing_add(index)
+            .0,
+    )
+}
+
+/// Encodes a mapping key given as `type:value`, or infers its type from its format.
+pub fn encode_key(value: &str) -> Result<Vec<u8>> {
+    let value = value.trim();
+    let (kind, value) = match value.split_once(':') {
+        Some((kind, value)) => (
+            HumanReadableParser::parse_type(kind.trim())
+                .with_context(|| format!("Invalid key type: {kind}"))?,
+            value.trim(),
+        ),
+        None => (
+            if value.len() == 42 && value.starts_with("0x") {
+                ParamType::Address
+            } else if value.len() == 66 && value.starts_with("0x") {
+                ParamType::FixedBytes(32)
+            } else if value == "true" || value == "false" {
+                ParamType::Bool
+            } else if value.parse::<U256>().is_ok() && !value.starts_with("0x") {
+                ParamType::Uint(256)
+            } else if value.starts_with('-') && I256::from_dec_str(value).is_ok() {
+                ParamType::Int(256)
+            } else {
+                ParamType::String
+            },
+            value,
+        ),
+    };
+
+    match kind {
+        ParamType::String => Ok(value.trim_matches('"').as_bytes().to_vec()),
+        ParamType::Bytes => Ok(value
+            .parse::<ethers::core::types::Bytes>()
+            .context("Invalid bytes key")?
+            .to_vec()),
+        ParamType::Array(_) | ParamType::FixedArray(_, _) | ParamType::Tuple(_) => {
+            bail!("Mapping keys must be value types, strings or bytes")
+        }
+        kind => Ok(encode(&[LenientTokenizer::tokenize(&kind, value)
+            .with_context(|| format!("Invalid {kind} key: {value}"))?])),
+    }
+}
+
+/// Decodes the value of a state variable stored in `word`.
+pub fn format_variable(layout: &StorageLayout, variable: &StorageVariable, word: H256) -> String {
+    let Some(kind) = layout.type_of(variable) else {
+        return format!("{:#x}", word);
+    };
+    let bytes = word.as_bytes();
+
+    match kind.encoding.as_str() {
+        "mapping" => kind.label.to_owned(),
+        "dynamic_array" => format!("length {}", U256::from_big_endian(bytes)),
+        "bytes" => {
+            // Short values are stored in place with `length * 2` in the lowest byte
+            let last = bytes[31];
+            if last & 1 == 0 {
+                let content = &bytes[..(last / 2) as usize];
+                if kind.label == "string" {
+                    format!("{:?}", String::from_utf8_lossy(content))
+                } else {
+                    format!("0x{}", hex::encode(content))
+                }
+            } else {
+                format!("length {}", (U256::from_big_endian(bytes) - 1) / 2)
+            }
+        }
+        _ => {
+            let size = kind.number_of_bytes.parse::<usize>().unwrap_or(32);
+            if size > 32 || variable.offset + size > 32 {
+                return format!("{} ({} bytes from this slot)", kind.label, size);
+            }
+            let end = 32 - variable.offset;
+            let value = &bytes[end - size..end];
+
+            if kind.label == "bool" {
+                (value[size - 1] != 0).to_string()
+            } else if kind.label.starts_with("address") || kind.label.starts_with("contract ") {
+                format!(
+                    "{:#x}",
+                    Address::from_slice(&value[size.saturating_sub(20)..])
+                )
+            } else if kind.label.starts_with("uint") || kind.label.starts_with("enum ") {
+                U256::from_big_endian(value).to_string()
+            } else if kind.label.starts_with("int") {
+                let mut extended = [if value[0] & 0x80 == 0 { 0 } else { 0xff }; 32];
+                extended[32 - size..].copy_from_slice(value);
+                I256::from_raw(U256::from_big_endian(&extended)).to_string()
+            } else {
+                format!("0x{}", hex::encode(value))
+            }
+        }
+    }
+}
+
+fn u256_to_h256(value: U256) -> H256 {
+    let mut bytes = [0u8; 32];
+    value.to_big_endian(&mut bytes);
+    H256(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::core::types::{BlockNumber, H160};
+
+    fn layout() -> StorageLayout {
+        serde_json::from_value(serde_json::json!({
+            "storage": [
+                { "label": "owner", "slot": "0", "offset": 0, "type": "t_address" },
+                { "label": "paused", "slot": "0", "offset": 20, "type": "t_bool" },
+                { "label": "decimals", "slot": "0", "offset": 21, "type": "t_int8" },
+                { "label": "name", "slot": "1", "offset": 0, "type": "t_string_storage" },
+                { "label": "balances", "slot": "2", "offset": 0, "type": "t_mapping" },
+                { "label": "holders", "slot": "3", "offset": 0, "type": "t_array" },
+            ],
+            "types": {
+                "t_address": { "encoding": "inplace", "label": "address", "numberOfBytes": "20" },
+                "t_bool": { "encoding": "inplace", "label": "bool", "numberOfBytes": "1" },
+                "t_int8": { "encoding": "inplace", "label": "int8", "numberOfBytes": "1" },
+                "t_string_storage": {
+                    "encoding": "bytes",
+                    "label": "string",
+                    "numberOfBytes": "32",
+                },
+                "t_mapping": {
+                    "encoding": "mapping",
+                    "label": "mapping(address => uint256)",
+                    "numberOfBytes": "32",
+                },
+                "t_array": {
+                    "encoding": "dynamic_array",
+                    "label": "address[]",
+                    "numberOfBytes": "32",
+                },
+            },
+        }))
+        .unwrap()
+    }
+
+    fn query(slot: &str, keys: &[&str]) -> StorageQuery {
+        StorageQuery {
+            slot: slot.to_owned(),
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+            block: BlockId::Number(BlockNumber::Latest),
+        }
+    }
+
+    #[test]
+    fn slots() {
+        let layout = layout();
+
+        assert_eq!(parse_slot("0x10", None).unwrap(), H256::from_low_u64_be(16));
+        assert_eq!(parse_slot("16", None).unwrap(), H256::from_low_u64_be(16));
+        assert_eq!(
+            parse_slot("balances", Some(&layout)).unwrap(),
+            H256::from_low_u64_be(2)
+        );
+        assert!(parse_slot("balances", None).is_err());
+    }
+
+    #[test]
+    fn mapping_and_array_slots() {
+        let layout = layout();
+        let holder = "0x000000000000000000000000000000000000dEaD";
+        let mut key = [0u8; 32];
+        key[12..].copy_from_slice(holder.parse::<H160>().unwrap().as_bytes());
+
+        assert_eq!(
+            query("balances", &[holder]).resolve(Some(&layout)).unwrap(),
+            H256(keccak256(
+                [&key[..], H256::from_low_u64_be(2).as_bytes()].concat()
+            ))
+        );
+        assert_eq!(
+            query("holders", &["[1]"]).resolve(Some(&layout)).unwrap(),
+            u256_to_h256(U256::from_big_endian(&keccak256(H256::from_low_u64_be(3))) + 1)
+        );
+        assert_eq!(
+            query("2", &[holder, "[1]"]).to_string(),
+            format!("2[{holder}][1]")
+        );
+    }
+
+    #[test]
+    fn keys() {
+        let mut one = [0u8; 32];
+        one[31] = 1;
+
+        assert_eq!(encode_key("1").unwrap(), one);
+        assert_eq!(encode_key("uint8:1").unwrap(), one);
+        assert_eq!(encode_key("true").unwrap(), one);
+        assert_eq!(encode_key("-1").unwrap(), [0xff; 32]);
+        assert_eq!(encode_key("\"name\"").unwrap(), b"name");
+        assert_eq!(encode_key("bytes:0x0102").unwrap(), [1, 2]);
+        assert!(encode_key("uint256[]:[1]").is_err());
+    }
+
+    #[test]
+    fn variables() {
+        let layout = layout();
+        let variable = |label| layout.find(label).unwrap();
+        let mut word = [0u8; 32];
+        word[12..].copy_from_slice(&[0xaa; 20]);
+        word[11] = 1;
+        word[10] = 0xfe;
+
+        assert_eq!(
+            format_variable(&layout, variable("owner"), H256(word)),
+            format!("{:#x}", Address::repeat_byte(0xaa))
+        );
+        assert_eq!(
+            format_variable(&layout, variable("paused"), H256(word)),
+            "true"
+        );
+        assert_eq!(
+            format_variable(&layout, variable("decimals"), H256(word)),
+            "-2"
+        );
+
+        let mut name = [0u8; 32];
+        name[..4].copy_from_slice(b"Test");
+        name[31] = 8;
+        assert_eq!(
+            format_variable(&layout, variable("name"), H256(name)),
+            "\"Test\""
+        );
+        assert_eq!(
+            format_variable(&layout, variable("name"), H256::from_low_u64_be(65)),
+            "length 32"
+        );
+        assert_eq!(
+            format_variable(&layout, variable("holders"), H256::from_low_u64_be(3)),
+            "length 3"
+        );
+    }
+}
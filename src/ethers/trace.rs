@@ -0,0 +1,302 @@
+use ethers::core::{
+    abi::Abi,
+    types::{Action, Address, Bytes, CallFrame, CallType, NameOrAddress, Res, Trace, TxHash, U256},
+};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallTrace {
+    pub transaction_hash: TxHash,
+    pub root: CallTraceNode,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallTraceNode {
+    pub call_type: String,
+    pub from: Address,
+    pub to: Option<Address>,
+    pub value: Option<U256>,
+    pub gas_used: U256,
+    pub input: Bytes,
+    pub output: Option<Bytes>,
+    pub error: Option<String>,
+    pub method: Option<String>,
+    pub calls: Vec<CallTraceNode>,
+}
+
+impl From<CallFrame> for CallTraceNode {
+    fn from(frame: CallFrame) -> Self {
+        Self {
+            call_type: frame.typ,
+            from: frame.from,
+            to: frame.to.and_then(|to| match to {
+                NameOrAddress::Address(address) => Some(address),
+                NameOrAddress::Name(_) => None,
+            }),
+            value: frame.value,
+            gas_used: frame.gas_used,
+            input: frame.input,
+            output: frame.output,
+            error: frame.error,
+            method: None,
+            calls: frame
+                .calls
+                .unwrap_or_default()
+                .into_iter()
+                .map(Self::from)
+                .collect(),
+        }
+    }
+}
+
+impl CallTraceNode {
+    /// Builds a call tree from the flat list returned by `trace_transaction` (Erigon, Reth).
+    pub fn from_parity_traces(traces: &[Trace]) -> Option<Self> {
+        let root = traces.iter().find(|trace| trace.trace_address.is_empty())?;
+        Some(Self::from_parity_trace(root, traces))
+    }
+
+    fn from_parity_trace(trace: &Trace, traces: &[Trace]) -> Self {
+        let (call_type, from, to, value, input) = match &trace.action {
+            Action::Call(call) => (
+                match call.call_type {
+                    CallType::CallCode => "CALLCODE",
+                    CallType::DelegateCall => "DELEGATECALL",
+                    CallType::StaticCall => "STATICCALL",
+                    _ => "CALL",
+                },
+                call.from,
+                Some(call.to),
+                Some(call.value),
+                call.input.to_owned(),
+            ),
+            Action::Create(create) => (
+                "CREATE",
+                create.from,
+                None,
+                Some(create.value),
+                create.init.to_owned(),
+            ),
+            Action::Suicide(suicide) => (
+                "SELFDESTRUCT",
+                suicide.address,
+                Some(suicide.refund_address),
+                Some(suicide.balance),
+                Bytes::default(),
+            ),
+            Action::Reward(reward) => (
+                "REWARD",
+                reward.author,
+                None,
+                Some(reward.value),
+                Bytes::default(),
+            ),
+        };
+
+        let (to, gas_used, output) = match &trace.result {
+            Some(Res::Call(result)) => (to, result.gas_used, Some(result.output.to_owned())),
+            Some(Res::Create(result)) => (
+                Some(result.address),
+                result.gas_used,
+                Some(result.code.to_owned()),
+            ),
+            _ => (to, U256::zero(), None),
+        };
+
+        let depth = trace.trace_address.len();
+        let calls = traces
+            .iter()
+            .filter(|child| {
+                child.trace_address.len() == depth + 1
+                    && child.trace_address.starts_with(&trace.trace_address)
+            })
+            .map(|child| Self::from_parity_trace(child, traces))
+            .collect();
+
+        Self {
+            call_type: call_type.to_owned(),
+            from,
+            to,
+            value,
+            gas_used,
+            input,
+            output,
+            error: trace.error.to_owned(),
+            method: None,
+            calls,
+        }
+    }
+
+    pub fn is_reverted(&self) -> bool {
+        self.error.is_some()
+    }
+
+    pub fn addresses(&self) -> HashSet<Address> {
+        let mut addresses = HashSet::new();
+        if let Some(to) = self.to {
+            addresses.insert(to);
+        }
+        for call in &self.calls {
+            addresses.extend(call.addresses());
+        }
+        addresses
+    }
+
+    /// Resolves method names from the 4-byte selector of each call.
+    pub fn decode_methods(&mut self, abis: &HashMap<Address, Abi>) {
+        if self.input.len() >= 4 {
+            if let Some(abi) = self.to.and_then(|to| abis.get(&to)) {
+                self.method = abi
+                    .functions()
+                    .find(|function| function.short_signature() == self.input[..4])
+                    .map(|function| function.name.to_owned());
+            }
+        }
+        for call in self.calls.iter_mut() {
+            call.decode_methods(abis);
+        }
+    }
+
+    /// Flattens the tree in depth-first order, skipping the children of collapsed nodes.
+    /// Each node is paired with its path from the root.
+    pub fn flatten(&self, collapsed: &HashSet<Vec<usize>>) -> Vec<(Vec<usize>, &Self)> {
+        let mut nodes = vec![];
+        self.flatten_into(vec![], collapsed, &mut nodes);
+        nodes
+    }
+
+    fn flatten_into<'a>(
+        &'a self,
+        path: Vec<usize>,
+        collapsed: &HashSet<Vec<usize>>,
+        nodes: &mut Vec<(Vec<usize>, &'a Self)>,
+    ) {
+        let is_collapsed = collapsed.contains(&path);
+        nodes.push((path.to_owned(), self));
+        if !is_collapsed {
+            for (i, call) in self.calls.iter().enumerate() {
+                let mut child_path = path.to_owned();
+                child_path.push(i);
+                call.flatten_into(child_path, collapsed, nodes);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::core::types::H256;
+    use serde_json::json;
+
+    fn address(n: u64) -> Address {
+        Address::from_low_u64_be(n)
+    }
+
+    fn call(trace_address: &[usize], call_type: &str, from: u64, to: u64) -> Trace {
+        serde_json::from_value(json!({
+            "action": {
+                "callType": call_type,
+                "from": address(from),
+                "to": address(to),
+                "gas": "0x0",
+                "input": "0x",
+                "value": "0x0",
+            },
+            "result": { "gasUsed": "0x5208", "output": "0x" },
+            "traceAddress": trace_address,
+            "subtraces": 0,
+            "blockNumber": 1,
+            "blockHash": H256::zero(),
+            "type": "call",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn parity_traces_to_tree() {
+        let traces = vec![
+            call(&[], "call", 1, 2),
+            call(&[0], "delegatecall", 2, 3),
+            call(&[0, 0], "staticcall", 2, 4),
+            call(&[1], "call", 2, 5),
+        ];
+
+        let root = CallTraceNode::from_parity_traces(&traces).unwrap();
+
+        assert_eq!(root.call_type, "CALL");
+        assert_eq!(root.gas_used, U256::from(21000));
+        assert_eq!(root.calls.len(), 2);
+        assert_eq!(root.calls[0].call_type, "DELEGATECALL");
+        assert_eq!(root.calls[0].calls[0].call_type, "STATICCALL");
+        assert_eq!(root.calls[0].calls[0].to, Some(address(4)));
+        assert_eq!(root.calls[1].to, Some(address(5)));
+        assert_eq!(
+            root.addresses(),
+            HashSet::from([address(2), address(3), address(4), address(5)])
+        );
+    }
+
+    #[test]
+    fn missing_root() {
+        assert_eq!(
+            CallTraceNode::from_parity_traces(&[call(&[0], "call", 1, 2)]),
+            None
+        );
+    }
+
+    #[test]
+    fn call_frame_to_tree() {
+        let frame: CallFrame = serde_json::from_value(json!({
+            "type": "CALL",
+            "from": address(1),
+            "to": address(2),
+            "gas": "0x0",
+            "gasUsed": "0x0",
+            "input": "0x",
+            "error": "execution reverted",
+            "calls": [{
+                "type": "STATICCALL",
+                "from": address(2),
+                "to": address(3),
+                "gas": "0x0",
+                "gasUsed": "0x0",
+                "input": "0x",
+            }],
+        }))
+        .unwrap();
+
+        let root = CallTraceNode::from(frame);
+
+        assert!(root.is_reverted());
+        assert_eq!(root.calls[0].call_type, "STATICCALL");
+        assert!(!root.calls[0].is_reverted());
+    }
+
+    #[test]
+    fn flatten_skips_collapsed_children() {
+        let traces = vec![
+            call(&[], "call", 1, 2),
+            call(&[0], "call", 2, 3),
+            call(&[0, 0], "call", 3, 4),
+            call(&[1], "call", 2, 5),
+        ];
+        let root = CallTraceNode::from_parity_traces(&traces).unwrap();
+
+        let paths = |collapsed: HashSet<Vec<usize>>| {
+            root.flatten(&collapsed)
+                .into_iter()
+                .map(|(path, _)| path)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            paths(HashSet::new()),
+            vec![vec![], vec![0], vec![0, 0], vec![1]]
+        );
+        assert_eq!(
+            paths(HashSet::from([vec![0]])),
+            vec![vec![], vec![0], vec![1]]
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use ethers::core::types::{Address, Withdrawal, U256, U64};
+use std::collections::HashMap;
+
+/// Without a beacon node, withdrawals of at least this amount are estimated to be full exits.
+/// Since Pectra this is only a guess: partial withdrawals requested with EIP-7002 and skims of
+/// consolidated validators may exceed it.
+pub const FULL_EXIT_THRESHOLD_GWEI: u64 = 16_000_000_000;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScannedWithdrawal {
+    pub block_number: U64,
+    pub timestamp: u64,
+    pub withdrawal: Withdrawal,
+    /// Whether the validator was withdrawable at the time of the withdrawal, as read from the
+    /// beacon node. `None` without a beacon node.
+    pub is_withdrawable: Option<bool>,
+}
+
+impl ScannedWithdrawal {
+    pub fn is_full_exit(&self) -> bool {
+        self.is_withdrawable
+            .unwrap_or_else(|| self.withdrawal.amount >= U256::from(FULL_EXIT_THRESHOLD_GWEI))
+    }
+}
+
+/// Withdrawals received by a validator or an address. Amounts are in Gwei.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WithdrawalTotals {
+    pub partial_count: usize,
+    pub partial_amount: U256,
+    pub exit_count: usize,
+    pub exit_amount: U256,
+}
+
+impl WithdrawalTotals {
+    fn add(&mut self, withdrawal: &ScannedWithdrawal) {
+        if withdrawal.is_full_exit() {
+            self.exit_count += 1;
+            self.exit_amount += withdrawal.withdrawal.amount;
+        } else {
+            self.partial_count += 1;
+            self.partial_amount += withdrawal.withdrawal.amount;
+        }
+    }
+
+    pub fn total(&self) -> U256 {
+        self.partial_amount + self.exit_amount
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithdrawalScan {
+    pub from_block: U64,
+    pub to_block: U64,
+    /// Only the withdrawals to this address are kept when it is set.
+    pub address: Option<Address>,
+    pub withdrawals: Vec<ScannedWithdrawal>,
+    /// Blocks that could not be fetched, so their withdrawals are missing from the scan.
+    pub missing_blocks: Vec<U64>,
+    /// Whether the full exits are estimated from the amounts, without the beacon node.
+    pub is_estimate: bool,
+}
+
+impl WithdrawalScan {
+    pub fn by_validator(&self) -> Vec<(U64, WithdrawalTotals)> {
+        Self::aggregate(&self.withdrawals, |withdrawal| {
+            withdrawal.withdrawal.validator_index
+        })
+    }
+
+    pub fn by_address(&self) -> Vec<(Address, WithdrawalTotals)> {
+        Self::aggregate(&self.withdrawals, |withdrawal| {
+            withdrawal.withdrawal.address
+        })
+    }
+
+    /// Number of rows listed: the withdrawals to the address, or the validators otherwise.
+    pub fn row_count(&self) -> usize {
+        if self.address.is_some() {
+            self.withdrawals.len()
+        } else {
+            self.by_validator().len()
+        }
+    }
+
+    /// Validator index of the `i`th row.
+    pub fn validator_index(&self, i: usize) -> Option<U64> {
+        if self.address.is_some() {
+            self.withdrawals
+                .get(i)
+                .map(|withdrawal| withdrawal.withdrawal.validator_index)
+        } else {
+            self.by_validator()
+                .get(i)
+                .map(|(validator_index, _)| *validator_index)
+        }
+    }
+
+    pub fn totals(&self) -> WithdrawalTotals {
+        let mut totals = WithdrawalTotals::default();
+        for withdrawal in self.withdrawals.iter() {
+            totals.add(withdrawal);
+        }
+        totals
+    }
+
+    /// Groups the withdrawals by `key`, the largest total first. Ties are ordered by `key` so that
+    /// the rows keep their order across renders.
+    fn aggregate<K: Copy + Ord + std::hash::Hash>(
+        withdrawals: &[ScannedWithdrawal],
+        key: impl Fn(&ScannedWithdrawal) -> K,
+    ) -> Vec<(K, WithdrawalTotals)> {
+        let mut totals = HashMap::<K, WithdrawalTotals>::new();
+        for withdrawal in withdrawals {
+            totals.entry(key(withdrawal)).or_default().add(withdrawal);
+        }
+        let mut totals = totals.into_iter().collect::<Vec<_>>();
+        totals.sort_by(|(a_key, a), (b_key, b)| b.total().cmp(&a.total()).then(a_key.cmp(b_key)));
+        totals
+    }
+}
@@ -1,19 +1,33 @@
 mod app;
+mod beacon;
 mod ethers;
 mod network;
 mod route;
 mod ui;
 mod widget;
+use crate::ethers::{
+    price_feed::CHAINLINK_ETH_USD,
+    source_provider::{
+        EtherscanSourceProvider, LocalArtifactsSourceProvider, SourceProvider,
+        SourcifySourceProvider,
+    },
+};
+use ::ethers::core::types::Address;
 use anyhow::Result;
-use app::{event_handling::event_handling, App};
+use app::{
+    dashboard::Dashboard,
+    event_handling::event_handling,
+    statistics::{StatisticsConfig, StatisticsSource},
+    App,
+};
 use chrono::Utc;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use crossterm::{event, execute, terminal};
 use log::LevelFilter;
 use network::{IoEvent, Network};
 use ratatui::prelude::*;
 use simplelog::{ColorChoice, CombinedLogger, Config, TermLogger, TerminalMode, WriteLogger};
-use std::{io, sync::Arc, time::Duration};
+use std::{io, path::PathBuf, sync::Arc, time::Duration};
 use tokio::sync::Mutex;
 
 #[derive(Parser, Debug)]
@@ -22,10 +36,57 @@ struct Args {
     /// Json-RPC URL
     #[arg(short, long, default_value = "https://eth.llamarpc.com")]
     endpoint: String,
+    /// Backends to look up verified contracts in, in order of priority
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "etherscan,sourcify,local"
+    )]
+    sources: Vec<Source>,
+    /// Directories of Foundry (`out/`) or Hardhat (`artifacts/`) build artifacts
+    #[arg(long)]
+    artifacts: Vec<PathBuf>,
+    /// Beacon node REST API URL
+    #[arg(long)]
+    beacon_endpoint: Option<String>,
+    /// Number of recent blocks scanned by the withdrawals explorer
+    #[arg(long, default_value_t = 256)]
+    withdrawal_blocks: u64,
+    /// Source of the ether price. `node` reads the Chainlink price feed
+    #[arg(long, value_enum, default_value = "auto")]
+    ether_price_source: StatisticsSource,
+    /// Source of the suggested base fee and gas price. `node` reads `eth_feeHistory`
+    #[arg(long, value_enum, default_value = "auto")]
+    gas_source: StatisticsSource,
+    /// Source of the node statistics. `etherscan` counts the Ethereum nodes, `node` shows the
+    /// peers, client and sync status of the endpoint
+    #[arg(long, value_enum, default_value = "auto")]
+    node_source: StatisticsSource,
+    /// Chainlink aggregator read for the ether price
+    #[arg(long, default_value = CHAINLINK_ETH_USD)]
+    price_feed: Address,
+    /// JSON file defining the statistics tiles
+    #[arg(long)]
+    dashboard: Option<PathBuf>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Source {
+    Etherscan,
+    Sourcify,
+    Local,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    // Parsed before the terminal is set up, so that `--help` and errors are printed normally
+    let args = Args::parse();
+    let dashboard = match args.dashboard.as_deref() {
+        Some(path) => Dashboard::load(path)?,
+        None => Dashboard::default(),
+    };
+
     let _ = std::fs::create_dir("logs");
     CombinedLogger::init(vec![
         TermLogger::new(
@@ -54,14 +115,41 @@ async fn main() -> Result<()> {
 
     let (sync_io_tx, sync_io_rx) = std::sync::mpsc::channel::<IoEvent>();
 
-    let args = Args::parse();
-
     // create app and run it
-    let app = Arc::new(Mutex::new(App::new(sync_io_tx, &args.endpoint)));
+    let app = Arc::new(Mutex::new(App::new(sync_io_tx, &args.endpoint, dashboard)));
     let cloned_app = Arc::clone(&app);
 
+    let source_providers = args
+        .sources
+        .iter()
+        // Without `--artifacts` there is nothing to match the bytecode against
+        .filter(|source| !matches!(source, Source::Local) || !args.artifacts.is_empty())
+        .map(|source| -> Box<dyn SourceProvider> {
+            match source {
+                Source::Etherscan => Box::new(EtherscanSourceProvider),
+                Source::Sourcify => Box::new(SourcifySourceProvider::new(&args.endpoint)),
+                Source::Local => Box::new(LocalArtifactsSourceProvider::new(
+                    &args.endpoint,
+                    &args.artifacts,
+                )),
+            }
+        })
+        .collect::<Vec<_>>();
+
     std::thread::spawn(move || {
-        let mut network = Network::new(&app, &args.endpoint);
+        let mut network = Network::new(
+            &app,
+            &args.endpoint,
+            &source_providers,
+            args.beacon_endpoint.as_deref(),
+            args.withdrawal_blocks,
+            StatisticsConfig {
+                ether_price: args.ether_price_source,
+                gas: args.gas_source,
+                node: args.node_source,
+                price_feed: args.price_feed,
+            },
+        );
         start_tokio(sync_io_rx, &mut network);
     });
 
@@ -106,6 +194,10 @@ async fn start_ui<B: Backend>(terminal: &mut Terminal<B>, app: &Arc<Mutex<App>>)
 
             is_first_render = false;
         }
+
+        for (index, tile) in app.dashboard.due_tiles() {
+            app.dispatch(IoEvent::GetMetric { index, tile });
+        }
     }
 }
 
@@ -1,20 +1,56 @@
 use crate::{
-    app::{statistics::Statistics, App},
-    ethers::types::{AddressInfo, BlockWithTransactionReceipts, TransactionWithReceipt},
+    app::{
+        block_transactions::selector,
+        dashboard::{Metric, Reading, TileConfig},
+        statistics::{NodeInfo, NodeStatistic, Statistics, StatisticsConfig, StatisticsSource},
+        App,
+    },
+    beacon::{self, BeaconBlock, BeaconBlockId, BlobSidecar, Epoch, Validator, ValidatorId},
+    ethers::{
+        bytecode::{lookup_signatures, ContractBytecode},
+        contract_call::{format_token, param_label, parse_arguments},
+        event_log::DecodedLog,
+        fee_history::{to_gwei, FeeHistory, REWARD_PERCENTILES},
+        logs::{event_signature, resolve_event, LogEntry, LogsRequest, LogsResult},
+        price_feed::get_chainlink_price,
+        proxy::{
+            merge_abis, minimal_proxy_implementation, slot_to_address, ProxyInfo, ProxyKind,
+            EIP1822_PROXIABLE_SLOT, EIP1967_ADMIN_SLOT, EIP1967_BEACON_SLOT,
+            EIP1967_IMPLEMENTATION_SLOT, OPEN_ZEPPELIN_IMPLEMENTATION_SLOT,
+        },
+        revert::decode_revert_data,
+        simulation::{collect_logs, Calldata, SimulationRequest, SimulationResult},
+        source_provider::{get_verified_contract, SourceProvider},
+        state_diff::{StateChanges, TokenBalanceChange},
+        storage::{format_variable, StorageLayout, StorageQuery, StorageSnapshot},
+        trace::{CallTrace, CallTraceNode},
+        transaction::blob_versioned_hashes,
+        types::{AddressInfo, BlockWithTransactionReceipts, ERC20Token, TransactionWithReceipt},
+        withdrawals::{ScannedWithdrawal, WithdrawalScan},
+    },
     route::{ActiveBlock, Route, RouteId},
     widget::StatefulList,
 };
-use anyhow::Result;
+use anyhow::{anyhow, bail, Context, Result};
 use ethers::{
-    core::types::{
-        Address, BlockId, BlockNumber, Chain, NameOrAddress, Transaction, TransactionReceipt,
-        TxHash, H256, U64,
+    core::{
+        abi::{Abi, AbiParser, Function, Token},
+        types::{
+            transaction::eip2718::TypedTransaction, Address, Block, BlockId, BlockNumber, Bytes,
+            CallConfig, CallFrame, Chain, Filter, GethDebugBuiltInTracerConfig,
+            GethDebugBuiltInTracerType, GethDebugTracerConfig, GethDebugTracerType,
+            GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace, GethTraceFrame, Log,
+            NameOrAddress, PreStateConfig, PreStateFrame, SyncingStatus, Transaction,
+            TransactionReceipt, TransactionRequest, TxHash, H256, U256, U64,
+        },
+        utils::id,
     },
     etherscan::Client,
-    providers::{Http, Middleware, Provider},
+    providers::{Http, Middleware, Provider, ProviderError, RpcError},
 };
-use futures::future::{join_all, try_join, try_join3};
+use futures::future::{join_all, try_join3, try_join5};
 use std::{
+    collections::{HashMap, HashSet},
     fs::File,
     io::Write,
     process::Command,
@@ -24,18 +60,30 @@ use tempfile::tempdir;
 use tokio::sync::Mutex;
 
 const RATE_LIMIT: usize = 60;
+/// Upper bound of `eth_getLogs` calls made by splitting the block range of a logs query.
+const MAX_LOGS_REQUESTS: usize = 256;
+
+/// Proxies detected by the addresses checked for one, `None` for the addresses that are not
+/// proxies.
+type ProxyImplementations = Mutex<HashMap<Address, Option<ProxyInfo>>>;
 
 pub enum IoEvent {
     GetStatistics,
+    GetMetric {
+        index: usize,
+        tile: TileConfig,
+    },
     GetNameOrAddressInfo {
         name_or_address: NameOrAddress,
         is_searching: bool,
     },
     GetBlock {
         number: U64,
+        is_searching: bool,
     },
     GetBlockByHash {
         hash: H256,
+        is_searching: bool,
     },
     GetTransactionWithReceipt {
         transaction_hash: TxHash,
@@ -46,6 +94,56 @@ pub enum IoEvent {
     GetDecodedInputData {
         transaction: Transaction,
     },
+    GetCallTrace {
+        transaction_hash: TxHash,
+    },
+    GetStateChanges {
+        transaction: TransactionWithReceipt,
+    },
+    GetRevertReason {
+        transaction: Transaction,
+    },
+    GetBlobSidecars {
+        transaction: TransactionWithReceipt,
+    },
+    GetBeaconBlock {
+        id: BeaconBlockId,
+    },
+    GetValidator {
+        id: ValidatorId,
+        is_searching: bool,
+    },
+    GetEpoch {
+        epoch: Option<u64>,
+    },
+    GetWithdrawals {
+        address: Option<Address>,
+    },
+    /// Opens the withdrawals of the block `number` with the withdrawal `index` selected.
+    GetWithdrawalsOfBlock {
+        number: U64,
+        index: U64,
+    },
+    CallContractFunction {
+        address: Address,
+        function: Function,
+        arguments: Vec<Token>,
+        block: BlockId,
+    },
+    Simulate {
+        request: SimulationRequest,
+    },
+    GetLogs {
+        request: LogsRequest,
+    },
+    GetFeeHistory {
+        block_count: u64,
+    },
+    GetStorage {
+        address: Address,
+        query: StorageQuery,
+        storage_layout: Option<StorageLayout>,
+    },
     GetLatestBlocks {
         n: usize,
     },
@@ -64,17 +162,37 @@ pub enum IoEvent {
 pub struct Network<'a> {
     pub app: &'a Arc<Mutex<App>>,
     endpoint: &'a str,
+    source_providers: &'a [Box<dyn SourceProvider>],
+    beacon_endpoint: Option<&'a str>,
+    withdrawal_blocks: u64,
+    statistics_config: StatisticsConfig,
+    proxies: Arc<ProxyImplementations>,
 }
 
 impl<'a> Network<'a> {
-    pub fn new(app: &'a Arc<Mutex<App>>, endpoint: &'a str) -> Self {
-        Self { app, endpoint }
+    pub fn new(
+        app: &'a Arc<Mutex<App>>,
+        endpoint: &'a str,
+        source_providers: &'a [Box<dyn SourceProvider>],
+        beacon_endpoint: Option<&'a str>,
+        withdrawal_blocks: u64,
+        statistics_config: StatisticsConfig,
+    ) -> Self {
+        Self {
+            app,
+            endpoint,
+            source_providers,
+            beacon_endpoint,
+            withdrawal_blocks,
+            statistics_config,
+            proxies: Arc::new(Mutex::new(HashMap::new())),
+        }
     }
 
     pub async fn handle_network_event(&mut self, io_event: IoEvent) -> Result<()> {
         match io_event {
             IoEvent::GetStatistics => {
-                let res = Self::get_statistics(self.endpoint).await;
+                let res = Self::get_statistics(self.endpoint, self.beacon_endpoint).await;
                 let mut app = self.app.lock().await;
                 if let Ok(statistics) = res {
                     app.statistics = statistics;
@@ -82,6 +200,13 @@ impl<'a> Network<'a> {
                 app.is_loading = false;
                 Ok(())
             }
+            IoEvent::GetMetric { index, tile } => {
+                let res = Self::get_metric(self.endpoint, self.statistics_config, &tile).await;
+                let mut app = self.app.lock().await;
+                app.dashboard.update(index, res);
+                app.is_loading = false;
+                Ok(())
+            }
             IoEvent::GetNameOrAddressInfo {
                 name_or_address,
                 is_searching,
@@ -89,13 +214,25 @@ impl<'a> Network<'a> {
                 let res = match name_or_address {
                     NameOrAddress::Name(name) => Self::get_name_info(self.endpoint, &name).await,
                     NameOrAddress::Address(address) => {
-                        Self::get_address_info(self.endpoint, address).await
+                        Self::get_address_info(
+                            self.endpoint,
+                            self.source_providers,
+                            &self.proxies,
+                            address,
+                        )
+                        .await
                     }
                 };
                 let mut app = self.app.lock().await;
                 if is_searching {
                     app.pop_current_route();
                 }
+                app.contract_call.reset();
+                app.source_code.reset();
+                app.bytecode.reset();
+                app.storage.reset();
+                app.source_code_scroll = 0;
+                app.source_code_scroll_state = app.source_code_scroll_state.position(0);
                 app.set_route(Route::new(
                     RouteId::AddressInfo(if let Ok(some) = res { some } else { None }),
                     ActiveBlock::Main,
@@ -104,64 +241,43 @@ impl<'a> Network<'a> {
                 app.is_loading = false;
                 Ok(())
             }
-            IoEvent::GetBlock { number } => {
+            IoEvent::GetBlock {
+                number,
+                is_searching,
+            } => {
                 let res = Self::get_block(self.endpoint, number).await;
                 if let Ok(block) = res {
-                    {
-                        let mut app = self.app.lock().await;
-                        app.pop_current_route();
-                        app.set_route(Route::new(
-                            RouteId::Block(block.to_owned()),
-                            ActiveBlock::Main,
-                        ));
-                    }
-
-                    if let Some(block) = block {
-                        let mut addresses = vec![];
-                        for transaction in block.block.transactions {
-                            addresses.push(transaction.from);
-                            if let Some(to) = transaction.to {
-                                addresses.push(to);
-                            }
-                        }
-
-                        let _ = self.update_app_with_ens_ids(&addresses).await;
-                    }
+                    self.update_app_with_block(block, is_searching).await;
                 }
                 let mut app = self.app.lock().await;
                 app.is_loading = false;
                 Ok(())
             }
-            IoEvent::GetBlockByHash { hash } => {
+            IoEvent::GetBlockByHash { hash, is_searching } => {
                 let res = Self::get_block(self.endpoint, hash).await;
                 if let Ok(block) = res {
-                    {
-                        let mut app = self.app.lock().await;
-                        app.pop_current_route();
-                        app.set_route(Route::new(
-                            RouteId::Block(block.to_owned()),
-                            ActiveBlock::Main,
-                        ));
-                    }
-
-                    if let Some(block) = block {
-                        let mut addresses = vec![];
-                        for transaction in block.block.transactions {
-                            addresses.push(transaction.from);
-                            if let Some(to) = transaction.to {
-                                addresses.push(to);
-                            }
+                    // Nodes may no longer serve blocks that were orphaned by a reorg
+                    let block = match block {
+                        Some(block) => Some(block),
+                        None => {
+                            let app = self.app.lock().await;
+                            app.chain_store.get_orphaned(hash).cloned()
                         }
-
-                        let _ = self.update_app_with_ens_ids(&addresses).await;
-                    }
+                    };
+                    self.update_app_with_block(block, is_searching).await;
                 }
                 let mut app = self.app.lock().await;
                 app.is_loading = false;
                 Ok(())
             }
             IoEvent::GetDecodedInputData { transaction } => {
-                let res = Self::get_decoded_input_data(transaction).await;
+                let res = Self::get_decoded_input_data(
+                    self.endpoint,
+                    self.source_providers,
+                    &self.proxies,
+                    transaction,
+                )
+                .await;
 
                 let mut app = self.app.lock().await;
                 if let Ok(decoded_input_data) = res {
@@ -172,9 +288,8 @@ impl<'a> Network<'a> {
                             app.pop_current_route();
                             let new_transaction =
                                 transaction.map(|transaction| TransactionWithReceipt {
-                                    transaction: transaction.transaction,
-                                    transaction_receipt: transaction.transaction_receipt,
                                     decoded_input_data,
+                                    ..transaction
                                 });
                             let new_route_id = match current_route.get_id() {
                                 RouteId::Transaction(_) => RouteId::Transaction(new_transaction),
@@ -193,10 +308,282 @@ impl<'a> Network<'a> {
                 }
                 Ok(())
             }
+            IoEvent::GetCallTrace { transaction_hash } => {
+                let res = Self::get_call_trace(
+                    self.endpoint,
+                    self.source_providers,
+                    &self.proxies,
+                    transaction_hash,
+                )
+                .await;
+                let mut app = self.app.lock().await;
+                app.set_route(Route::new(
+                    RouteId::CallTraceOfTransaction(res.map_err(|err| err.to_string())),
+                    ActiveBlock::Main,
+                ));
+                app.is_loading = false;
+                Ok(())
+            }
+            IoEvent::GetStateChanges { transaction } => {
+                let res = Self::get_state_changes(self.endpoint, &transaction).await;
+
+                if let Ok(state_changes) = res.as_ref() {
+                    let _ = self
+                        .update_app_with_token_decimals(&state_changes.token_balance_changes)
+                        .await;
+                }
+
+                let mut app = self.app.lock().await;
+                let current_route = app.get_current_route();
+                if let RouteId::StateChangesOfTransaction(Some(transaction_with_receipt)) =
+                    current_route.get_id()
+                {
+                    if transaction_with_receipt.transaction.hash == transaction.transaction.hash {
+                        app.pop_current_route();
+                        app.set_route(Route::new(
+                            RouteId::StateChangesOfTransaction(Some(TransactionWithReceipt {
+                                state_changes: Some(res.map_err(|err| err.to_string())),
+                                ..transaction_with_receipt
+                            })),
+                            current_route.get_active_block(),
+                        ));
+                    }
+                }
+                app.is_loading = false;
+                Ok(())
+            }
+            IoEvent::GetBlobSidecars { transaction } => {
+                let res =
+                    Self::get_blob_sidecars(self.endpoint, self.beacon_endpoint, &transaction)
+                        .await;
+
+                let mut app = self.app.lock().await;
+                let current_route = app.get_current_route();
+                if let RouteId::BlobsOfTransaction(Some(transaction_with_receipt)) =
+                    current_route.get_id()
+                {
+                    if transaction_with_receipt.transaction.hash == transaction.transaction.hash {
+                        app.pop_current_route();
+                        app.set_route(Route::new(
+                            RouteId::BlobsOfTransaction(Some(TransactionWithReceipt {
+                                blob_sidecars: Some(res.map_err(|err| err.to_string())),
+                                ..transaction_with_receipt
+                            })),
+                            current_route.get_active_block(),
+                        ));
+                    }
+                }
+                app.is_loading = false;
+                Ok(())
+            }
+            IoEvent::GetBeaconBlock { id } => {
+                let res = Self::get_beacon_block(self.beacon_endpoint, id).await;
+
+                let mut app = self.app.lock().await;
+                app.set_route(Route::new(
+                    RouteId::BeaconBlock(res.ok()),
+                    ActiveBlock::Main,
+                ));
+                app.is_loading = false;
+                Ok(())
+            }
+            IoEvent::GetValidator { id, is_searching } => {
+                let res = Self::get_validator(
+                    self.endpoint,
+                    self.beacon_endpoint,
+                    self.withdrawal_blocks,
+                    &id,
+                )
+                .await;
+
+                let mut app = self.app.lock().await;
+                if is_searching {
+                    app.pop_current_route();
+                }
+                app.validator_withdrawals_table_state.select(None);
+                app.set_route(Route::new(
+                    RouteId::Validator(res.map_err(|err| err.to_string())),
+                    ActiveBlock::Main,
+                ));
+                app.is_loading = false;
+                Ok(())
+            }
+            IoEvent::GetEpoch { epoch } => {
+                let res = Self::get_epoch(self.beacon_endpoint, epoch).await;
+
+                let mut app = self.app.lock().await;
+                app.pop_current_route();
+                app.epoch_table_state.select(None);
+                app.set_route(Route::new(RouteId::Epoch(res.ok()), ActiveBlock::Main));
+                app.is_loading = false;
+                Ok(())
+            }
+            IoEvent::GetWithdrawals { address } => {
+                let res = Self::get_withdrawals(
+                    self.endpoint,
+                    self.beacon_endpoint,
+                    self.withdrawal_blocks,
+                    address,
+                )
+                .await;
+
+                let mut app = self.app.lock().await;
+                app.pop_current_route();
+                app.withdrawal_scan_table_state.select(None);
+                app.set_route(Route::new(
+                    RouteId::Withdrawals(res.ok()),
+                    ActiveBlock::Main,
+                ));
+                app.is_loading = false;
+                Ok(())
+            }
+            IoEvent::GetWithdrawalsOfBlock { number, index } => {
+                let res = Self::get_block(self.endpoint, number).await;
+
+                let mut app = self.app.lock().await;
+                if let Ok(block) = res {
+                    let selected = block.as_ref().and_then(|block| {
+                        let withdrawals = block.block.withdrawals.as_ref()?;
+                        app.block_withdrawal_indices(&block.block)
+                            .iter()
+                            .position(|&i| withdrawals[i].index == index)
+                    });
+                    app.withdrawals_table_state.select(selected);
+                    app.set_route(Route::new(
+                        RouteId::WithdrawalsOfBlock(block),
+                        ActiveBlock::Main,
+                    ));
+                }
+                app.is_loading = false;
+                Ok(())
+            }
+            IoEvent::GetRevertReason { transaction } => {
+                let res = Self::get_revert_reason(
+                    self.endpoint,
+                    self.source_providers,
+                    &self.proxies,
+                    &transaction,
+                )
+                .await;
+
+                let mut app = self.app.lock().await;
+                if let Ok(revert_reason) = res {
+                    let current_route = app.get_current_route();
+                    let new_route_id = match current_route.get_id() {
+                        RouteId::Transaction(Some(transaction_with_receipt))
+                            if transaction_with_receipt.transaction.hash == transaction.hash =>
+                        {
+                            Some(RouteId::Transaction(Some(TransactionWithReceipt {
+                                revert_reason,
+                                ..transaction_with_receipt
+                            })))
+                        }
+                        _ => None,
+                    };
+                    if let Some(new_route_id) = new_route_id {
+                        app.pop_current_route();
+                        app.set_route(Route::new(new_route_id, current_route.get_active_block()));
+                    }
+                }
+                app.is_loading = false;
+                Ok(())
+            }
+            IoEvent::CallContractFunction {
+                address,
+                function,
+                arguments,
+                block,
+            } => {
+                let res = Self::call_contract_function(
+                    self.endpoint,
+                    self.source_providers,
+                    &self.proxies,
+                    address,
+                    &function,
+                    &arguments,
+                    block,
+                )
+                .await;
+                let mut app = self.app.lock().await;
+                app.contract_call.result = Some(res.map_err(|err| err.to_string()));
+                app.is_loading = false;
+                Ok(())
+            }
+            IoEvent::Simulate { request } => {
+                let res = Self::simulate(
+                    self.endpoint,
+                    self.source_providers,
+                    &self.proxies,
+                    &request,
+                )
+                .await;
+                let mut app = self.app.lock().await;
+                app.simulation.result = Some(res.map_err(|err| err.to_string()));
+                app.is_loading = false;
+                Ok(())
+            }
+            IoEvent::GetLogs { request } => {
+                let res = Self::get_logs(
+                    self.endpoint,
+                    self.source_providers,
+                    &self.proxies,
+                    &request,
+                )
+                .await;
+                let mut app = self.app.lock().await;
+                if let Ok(result) = res.as_ref() {
+                    for event in result.events.iter() {
+                        if !app.logs.events.contains(event) {
+                            app.logs.events.push(event.to_owned());
+                        }
+                    }
+                }
+                app.logs.result = Some(res.map_err(|err| err.to_string()));
+                app.logs.page = 0;
+                app.logs.table_state.select(None);
+                app.apply_filters();
+                app.is_loading = false;
+                Ok(())
+            }
+            IoEvent::GetFeeHistory { block_count } => {
+                let res = Self::get_fee_history(self.endpoint, block_count).await;
+
+                let mut app = self.app.lock().await;
+                app.pop_current_route();
+                app.set_route(Route::new(RouteId::GasTracker(res.ok()), ActiveBlock::Main));
+                app.is_loading = false;
+                Ok(())
+            }
+            IoEvent::GetStorage {
+                address,
+                query,
+                storage_layout,
+            } => {
+                let res =
+                    Self::get_storage(self.endpoint, address, &query, storage_layout.as_ref())
+                        .await;
+                let mut app = self.app.lock().await;
+                match res {
+                    Ok(snapshot) => {
+                        app.storage.error = None;
+                        app.storage.snapshots.push(snapshot);
+                    }
+                    Err(err) => app.storage.error = Some(err.to_string()),
+                }
+                app.is_loading = false;
+                Ok(())
+            }
             IoEvent::GetTransactionWithReceipt { transaction_hash } => {
-                let res = Self::get_transaction_with_receipt(self.endpoint, transaction_hash).await;
+                let res = Self::get_transaction_with_receipt(
+                    self.endpoint,
+                    self.source_providers,
+                    &self.proxies,
+                    transaction_hash,
+                )
+                .await;
                 let mut app = self.app.lock().await;
                 if let Ok(some) = res {
+                    app.is_access_list_expanded = false;
                     app.set_route(Route::new(RouteId::Transaction(some), ActiveBlock::Main));
                 }
                 app.is_loading = false;
@@ -218,7 +605,7 @@ impl<'a> Network<'a> {
             }
             IoEvent::InitialSetup { n } => {
                 let (statistics, blocks, transactions) = try_join3(
-                    Self::get_statistics(self.endpoint),
+                    Self::get_statistics(self.endpoint, self.beacon_endpoint),
                     Self::get_latest_blocks(self.endpoint, n),
                     Self::get_latest_transactions(self.endpoint, n),
                 )
@@ -230,16 +617,23 @@ impl<'a> Network<'a> {
                         addresses.push(to);
                     }
                 }
+                let selectors = transactions
+                    .iter()
+                    .filter_map(|transaction| selector(&transaction.transaction))
+                    .collect::<Vec<_>>();
 
                 {
                     let mut app = self.app.lock().await;
                     app.statistics = statistics;
 
+                    app.chain_store.insert_blocks(&blocks);
                     app.latest_blocks = Some(StatefulList::with_items(blocks));
                     app.latest_transactions = Some(StatefulList::with_items(transactions));
+                    app.apply_filters();
                 }
 
                 let _ = self.update_app_with_ens_ids(&addresses).await;
+                let _ = self.update_app_with_signatures(&selectors).await;
 
                 let mut app = self.app.lock().await;
                 app.is_loading = false;
@@ -248,6 +642,7 @@ impl<'a> Network<'a> {
             IoEvent::GetLatestBlocks { n } => {
                 let blocks = Self::get_latest_blocks(self.endpoint, n).await?;
                 let mut app = self.app.lock().await;
+                app.chain_store.insert_blocks(&blocks);
                 app.latest_blocks = Some(StatefulList::with_items(blocks));
                 app.is_loading = false;
                 Ok(())
@@ -262,13 +657,19 @@ impl<'a> Network<'a> {
                         addresses.push(to);
                     }
                 }
+                let selectors = transactions
+                    .iter()
+                    .filter_map(|transaction| selector(&transaction.transaction))
+                    .collect::<Vec<_>>();
 
                 {
                     let mut app = self.app.lock().await;
                     app.latest_transactions = Some(StatefulList::with_items(transactions));
+                    app.apply_filters();
                 }
 
                 let _ = self.update_app_with_ens_ids(&addresses).await;
+                let _ = self.update_app_with_signatures(&selectors).await;
 
                 let mut app = self.app.lock().await;
                 app.is_loading = false;
@@ -329,13 +730,18 @@ impl<'a> Network<'a> {
             balance,
             avatar_url,
             contract_abi: None,
-            contract_source_code: None,
+            source_files: vec![],
             ens_id: Some(ens_id.to_owned()),
+            proxy: None,
+            bytecode: None,
+            storage_layout: None,
         }))
     }
 
     async fn get_address_info(
         endpoint: &'a str,
+        source_providers: &'a [Box<dyn SourceProvider>],
+        proxies: &ProxyImplementations,
         address: Address,
     ) -> Result<Option<AddressInfo>, Box<dyn Error>> {
         let provider = Provider::<Http>::try_from(endpoint)?;
@@ -347,58 +753,83 @@ impl<'a> Network<'a> {
             None
         };
 
-        let (contract_source_code, contract_abi) =
-            if let Ok(client) = Client::new_from_env(Chain::Mainnet) {
-                try_join(
-                    client.contract_source_code(address),
-                    client.contract_abi(address),
-                )
+        let verified_contract = get_verified_contract(source_providers, address)
+            .await
+            .unwrap_or_default();
+
+        let code = provider.get_code(address, None).await?;
+        let proxy = Self::get_proxy(&provider, proxies, address, Some(&code)).await;
+
+        let contract_abi = if let Some(proxy) = proxy.as_ref() {
+            let implementation_abi = get_verified_contract(source_providers, proxy.implementation)
                 .await
-                .map_or((None, None), |res| (Some(res.0), Some(res.1)))
-            } else {
-                (None, None)
-            };
+                .and_then(|contract| contract.abi);
+            merge_abis(verified_contract.abi, implementation_abi)
+        } else {
+            verified_contract.abi
+        };
 
         let balance = provider.get_balance(address, None).await?;
 
+        let bytecode = if code.is_empty() {
+            None
+        } else {
+            let mut bytecode = ContractBytecode::new(code);
+            let signatures = lookup_signatures(&bytecode.dispatcher_selectors())
+                .await
+                .unwrap_or_default();
+            bytecode.resolve_selectors(contract_abi.as_ref(), &signatures);
+            Some(bytecode)
+        };
+
         Ok(Some(AddressInfo {
             address,
             balance,
             avatar_url,
             contract_abi,
-            contract_source_code,
+            source_files: verified_contract.source_files,
             ens_id,
+            proxy,
+            bytecode,
+            storage_layout: verified_contract.storage_layout,
         }))
     }
 
-    async fn get_decoded_input_data(transaction: Transaction) -> Result<Option<String>> {
-        let decoded_input_data = if let Ok(client) = Client::new_from_env(Chain::Mainnet) {
-            if let Some(to) = transaction.to {
-                let abi = client.contract_abi(to).await?;
+    async fn get_decoded_input_data(
+        endpoint: &'a str,
+        source_providers: &'a [Box<dyn SourceProvider>],
+        proxies: &ProxyImplementations,
+        transaction: Transaction,
+    ) -> Result<Option<String>> {
+        let abi = if let Some(to) = transaction.to {
+            Self::get_contract_abis(endpoint, source_providers, proxies, &[to])
+                .await
+                .remove(&to)
+        } else {
+            None
+        };
 
-                let s = serde_json::to_string(&abi)?;
+        let decoded_input_data = if let Some(abi) = abi {
+            let s = serde_json::to_string(&abi)?;
 
-                let dir = tempdir()?;
-                let file_path = dir.path().join("lazy-etherscan.tmp.abi.json");
-                let mut file = File::create(&file_path)?;
-                writeln!(file, "{}", s)?;
+            let dir = tempdir()?;
+            let file_path = dir.path().join("lazy-etherscan.tmp.abi.json");
+            let mut file = File::create(&file_path)?;
+            writeln!(file, "{}", s)?;
 
-                let output = Command::new("ethereum-input-data-decoder")
-                    .args([
-                        "--abi",
-                        file_path.to_str().unwrap(),
-                        &transaction.input.to_string(),
-                    ])
-                    .output()
-                    .map_or(None, |output| String::from_utf8(output.stdout).ok());
+            let output = Command::new("ethereum-input-data-decoder")
+                .args([
+                    "--abi",
+                    file_path.to_str().unwrap(),
+                    &transaction.input.to_string(),
+                ])
+                .output()
+                .map_or(None, |output| String::from_utf8(output.stdout).ok());
 
-                drop(file);
-                dir.close()?;
+            drop(file);
+            dir.close()?;
 
-                output
-            } else {
-                None
-            }
+            output
         } else {
             None
         };
@@ -407,6 +838,8 @@ impl<'a> Network<'a> {
 
     async fn get_transaction_with_receipt(
         endpoint: &'a str,
+        source_providers: &'a [Box<dyn SourceProvider>],
+        proxies: &ProxyImplementations,
         transaction_hash: TxHash,
     ) -> Result<Option<TransactionWithReceipt>> {
         let provider = Provider::<Http>::try_from(endpoint)?;
@@ -414,33 +847,52 @@ impl<'a> Network<'a> {
         let transaction_receipt = provider.get_transaction_receipt(transaction_hash).await?;
         if let Some(transaction) = transaction {
             if let Some(transaction_receipt) = transaction_receipt {
-                let decoded_input_data = if let Ok(client) = Client::new_from_env(Chain::Mainnet) {
-                    if let Some(to) = transaction.to {
-                        let abi = client.contract_abi(to).await?;
-
-                        let s = serde_json::to_string(&abi)?;
-
-                        let dir = tempdir()?;
-                        let file_path = dir.path().join("lazy-etherscan.tmp.abi.json");
-                        let mut file = File::create(&file_path)?;
-                        writeln!(file, "{}", s)?;
-
-                        let output = Command::new("ethereum-input-data-decoder")
-                            .args([
-                                "--abi",
-                                file_path.to_str().unwrap(),
-                                &transaction.input.to_string(),
-                            ])
-                            .output()
-                            .map_or(None, |output| String::from_utf8(output.stdout).ok());
-
-                        drop(file);
-                        dir.close()?;
-
-                        output
-                    } else {
-                        None
-                    }
+                let abi = if let Some(to) = transaction.to {
+                    Self::get_contract_abis(endpoint, source_providers, proxies, &[to])
+                        .await
+                        .remove(&to)
+                } else {
+                    None
+                };
+
+                let decoded_input_data = if let Some(abi) = abi {
+                    let s = serde_json::to_string(&abi)?;
+
+                    let dir = tempdir()?;
+                    let file_path = dir.path().join("lazy-etherscan.tmp.abi.json");
+                    let mut file = File::create(&file_path)?;
+                    writeln!(file, "{}", s)?;
+
+                    let output = Command::new("ethereum-input-data-decoder")
+                        .args([
+                            "--abi",
+                            file_path.to_str().unwrap(),
+                            &transaction.input.to_string(),
+                        ])
+                        .output()
+                        .map_or(None, |output| String::from_utf8(output.stdout).ok());
+
+                    drop(file);
+                    dir.close()?;
+
+                    output
+                } else {
+                    None
+                };
+
+                let revert_reason = if transaction_receipt.status == Some(U64::from(0)) {
+                    Self::get_revert_reason(endpoint, source_providers, proxies, &transaction)
+                        .await
+                        .unwrap_or(None)
+                } else {
+                    None
+                };
+
+                let base_fee_per_gas = if let Some(block_hash) = transaction.block_hash {
+                    provider
+                        .get_block(block_hash)
+                        .await?
+                        .and_then(|block| block.base_fee_per_gas)
                 } else {
                     None
                 };
@@ -449,6 +901,10 @@ impl<'a> Network<'a> {
                     transaction,
                     transaction_receipt,
                     decoded_input_data,
+                    state_changes: None,
+                    revert_reason,
+                    base_fee_per_gas,
+                    blob_sidecars: None,
                 }))
             } else {
                 Ok(None)
@@ -458,57 +914,963 @@ impl<'a> Network<'a> {
         }
     }
 
-    async fn get_latest_blocks(
+    async fn get_call_trace(
         endpoint: &'a str,
-        n: usize,
-    ) -> Result<Vec<BlockWithTransactionReceipts<Transaction>>> {
+        source_providers: &'a [Box<dyn SourceProvider>],
+        proxies: &ProxyImplementations,
+        transaction_hash: TxHash,
+    ) -> Result<CallTrace> {
         let provider = Provider::<Http>::try_from(endpoint)?;
-        let block_number = provider.get_block_number().await?;
+        let options = GethDebugTracingOptions {
+            tracer: Some(GethDebugTracerType::BuiltInTracer(
+                GethDebugBuiltInTracerType::CallTracer,
+            )),
+            ..GethDebugTracingOptions::default()
+        };
 
-        let mut blocks = vec![];
-        for i in 0..n {
-            let block = provider.get_block_with_txs(block_number - i);
-            blocks.push(block);
-        }
+        let mut root = match provider
+            .debug_trace_transaction(transaction_hash, options)
+            .await
+        {
+            Ok(GethTrace::Known(GethTraceFrame::CallTracer(frame))) => CallTraceNode::from(frame),
+            Ok(GethTrace::Unknown(value)) => serde_json::from_value::<CallFrame>(value)
+                .map(CallTraceNode::from)
+                .map_err(|err| anyhow!("Unexpected call trace of debug_traceTransaction: {err}"))?,
+            res => {
+                let debug_error = match res {
+                    Err(err) => err.to_string(),
+                    Ok(_) => "unexpected tracer output".to_owned(),
+                };
+                // Erigon and Reth expose the `trace_*` namespace instead of `debug_*`
+                let traces = provider
+                    .trace_transaction(transaction_hash)
+                    .await
+                    .map_err(|err| {
+                        anyhow!("debug_traceTransaction: {debug_error}, trace_transaction: {err}")
+                    })?;
+                CallTraceNode::from_parity_traces(&traces)
+                    .context("trace_transaction returned no call trace")?
+            }
+        };
 
-        let blocks = join_all(blocks).await;
+        let addresses = root.addresses().into_iter().collect::<Vec<_>>();
+        let abis = Self::get_contract_abis(endpoint, source_providers, proxies, &addresses).await;
+        root.decode_methods(&abis);
 
-        let mut latest_blocks = vec![];
-        for block in blocks.into_iter().flatten().flatten() {
-            latest_blocks.push(BlockWithTransactionReceipts {
-                block,
-                transaction_receipts: None,
-            });
-        }
-        Ok(latest_blocks)
+        Ok(CallTrace {
+            transaction_hash,
+            root,
+        })
     }
 
-    async fn get_transaction_receipts(
+    async fn get_state_changes(
         endpoint: &'a str,
-        transactions: &[Transaction],
-    ) -> Result<Vec<TransactionReceipt>> {
+        transaction: &TransactionWithReceipt,
+    ) -> Result<StateChanges> {
         let provider = Provider::<Http>::try_from(endpoint)?;
-        let query = transactions
-            .iter()
-            .map(|tx| provider.get_transaction_receipt(tx.hash))
-            .collect::<Vec<_>>();
-        let res = join_all(query).await;
-        let mut transaction_receips = vec![];
+        let options = GethDebugTracingOptions {
+            tracer: Some(GethDebugTracerType::BuiltInTracer(
+                GethDebugBuiltInTracerType::PreStateTracer,
+            )),
+            tracer_config: Some(GethDebugTracerConfig::BuiltInTracer(
+                GethDebugBuiltInTracerConfig::PreStateTracer(PreStateConfig {
+                    diff_mode: Some(true),
+                }),
+            )),
+            ..GethDebugTracingOptions::default()
+        };
 
-        for receipt in res.into_iter().flatten().flatten() {
-            transaction_receips.push(receipt);
+        match provider
+            .debug_trace_transaction(transaction.transaction.hash, options)
+            .await?
+        {
+            GethTrace::Known(GethTraceFrame::PreStateTracer(PreStateFrame::Diff(diff))) => {
+                Ok(StateChanges::new(diff, &transaction.transaction_receipt))
+            }
+            _ => bail!("The node did not return a prestate diff."),
         }
-
-        Ok(transaction_receips)
     }
 
-    async fn get_latest_transactions(
-        endpoint: &'a str,
-        n: usize,
-    ) -> Result<Vec<TransactionWithReceipt>> {
-        let provider = Provider::<Http>::try_from(endpoint)?;
-
-        let block = provider.get_block(BlockNumber::Latest).await?;
+    async fn update_app_with_token_decimals(
+        &mut self,
+        token_balance_changes: &[TokenBalanceChange],
+    ) -> Result<()> {
+        let tokens = {
+            let app = self.app.lock().await;
+            token_balance_changes
+                .iter()
+                .filter_map(|change| {
+                    ERC20Token::find_by_address(&app.erc20_tokens, change.token)
+                        .filter(|token| token.decimals.is_none())
+                        .map(|token| token.contract_address)
+                })
+                .collect::<HashSet<_>>()
+        };
+
+        let provider = Provider::<Http>::try_from(self.endpoint)?;
+        for token in tokens {
+            let request = TransactionRequest::new()
+                .to(token)
+                .data(Bytes::from(id("decimals()").to_vec()));
+            if let Ok(output) = provider.call(&request.into(), None).await {
+                if output.len() == 32 {
+                    let decimals = U256::from_big_endian(&output).low_u32() as u8;
+                    let mut app = self.app.lock().await;
+                    for erc20_token in app.erc20_tokens.iter_mut() {
+                        if erc20_token.contract_address == token {
+                            erc20_token.decimals = Some(decimals);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Fetches the verified ABIs of contracts. The ABI of a proxy is merged with the ABI of its
+    /// implementation.
+    async fn get_contract_abis(
+        endpoint: &'a str,
+        source_providers: &'a [Box<dyn SourceProvider>],
+        proxies: &ProxyImplementations,
+        addresses: &[Address],
+    ) -> HashMap<Address, Abi> {
+        let mut abis = HashMap::new();
+        if let Ok(provider) = Provider::<Http>::try_from(endpoint) {
+            for addresses in addresses.chunks(RATE_LIMIT) {
+                let query = addresses
+                    .iter()
+                    .map(|&address| {
+                        Self::get_contract_abi(&provider, source_providers, proxies, address)
+                    })
+                    .collect::<Vec<_>>();
+                let results = join_all(query).await;
+                for (address, abi) in addresses.iter().zip(results) {
+                    if let Some(abi) = abi {
+                        abis.insert(address.to_owned(), abi);
+                    }
+                }
+            }
+        }
+        abis
+    }
+
+    async fn get_contract_abi(
+        provider: &Provider<Http>,
+        source_providers: &[Box<dyn SourceProvider>],
+        proxies: &ProxyImplementations,
+        address: Address,
+    ) -> Option<Abi> {
+        let abi = get_verified_contract(source_providers, address)
+            .await
+            .and_then(|contract| contract.abi);
+        let proxy = Self::get_proxy(provider, proxies, address, None).await;
+        let implementation_abi = match proxy {
+            Some(proxy) => get_verified_contract(source_providers, proxy.implementation)
+                .await
+                .and_then(|contract| contract.abi),
+            None => None,
+        };
+        merge_abis(abi, implementation_abi)
+    }
+
+    /// The proxy at `address`, detected once per address. The code of the address is fetched
+    /// unless it is given.
+    async fn get_proxy(
+        provider: &Provider<Http>,
+        proxies: &ProxyImplementations,
+        address: Address,
+        code: Option<&Bytes>,
+    ) -> Option<ProxyInfo> {
+        if let Some(proxy) = proxies.lock().await.get(&address) {
+            return proxy.to_owned();
+        }
+        let code = match code {
+            Some(code) => code.to_owned(),
+            None => provider.get_code(address, None).await.ok()?,
+        };
+        let proxy = Self::get_proxy_info(provider, address, &code).await.ok()?;
+        proxies.lock().await.insert(address, proxy.to_owned());
+        proxy
+    }
+
+    /// Detects the standard proxy patterns by reading their storage slots and bytecode.
+    async fn get_proxy_info(
+        provider: &Provider<Http>,
+        address: Address,
+        code: &Bytes,
+    ) -> Result<Option<ProxyInfo>> {
+        if code.is_empty() {
+            return Ok(None);
+        }
+
+        if let Some(implementation) = minimal_proxy_implementation(code) {
+            return Ok(Some(ProxyInfo {
+                kind: ProxyKind::Eip1167,
+                implementation,
+                admin: None,
+                beacon: None,
+            }));
+        }
+
+        let (implementation, admin, beacon, proxiable, legacy) = try_join5(
+            provider.get_storage_at(address, EIP1967_IMPLEMENTATION_SLOT, None),
+            provider.get_storage_at(address, EIP1967_ADMIN_SLOT, None),
+            provider.get_storage_at(address, EIP1967_BEACON_SLOT, None),
+            provider.get_storage_at(address, EIP1822_PROXIABLE_SLOT, None),
+            provider.get_storage_at(address, OPEN_ZEPPELIN_IMPLEMENTATION_SLOT, None),
+        )
+        .await?;
+
+        let admin = slot_to_address(admin);
+
+        if let Some(implementation) = slot_to_address(implementation) {
+            return Ok(Some(ProxyInfo {
+                kind: ProxyKind::Eip1967,
+                implementation,
+                admin,
+                beacon: None,
+            }));
+        }
+
+        if let Some(beacon) = slot_to_address(beacon) {
+            if let Some(implementation) =
+                Self::call_address_getter(provider, beacon, "implementation()").await
+            {
+                return Ok(Some(ProxyInfo {
+                    kind: ProxyKind::Eip1967Beacon,
+                    implementation,
+                    admin,
+                    beacon: Some(beacon),
+                }));
+            }
+        }
+
+        for (kind, slot) in [
+            (ProxyKind::Eip1822, proxiable),
+            (ProxyKind::OpenZeppelinLegacy, legacy),
+        ] {
+            if let Some(implementation) = slot_to_address(slot) {
+                return Ok(Some(ProxyInfo {
+                    kind,
+                    implementation,
+                    admin,
+                    beacon: None,
+                }));
+            }
+        }
+
+        // Gnosis Safe proxies answer `masterCopy()` with the singleton stored in slot 0
+        let master_copy =
+            slot_to_address(provider.get_storage_at(address, H256::zero(), None).await?);
+        if master_copy.is_some()
+            && master_copy == Self::call_address_getter(provider, address, "masterCopy()").await
+        {
+            return Ok(master_copy.map(|implementation| ProxyInfo {
+                kind: ProxyKind::GnosisSafe,
+                implementation,
+                admin: None,
+                beacon: None,
+            }));
+        }
+
+        Ok(None)
+    }
+
+    async fn call_address_getter(
+        provider: &Provider<Http>,
+        address: Address,
+        signature: &str,
+    ) -> Option<Address> {
+        let request = TransactionRequest::new()
+            .to(address)
+            .data(Bytes::from(id(signature).to_vec()));
+        match provider.call(&request.into(), None).await {
+            Ok(output) if output.len() == 32 => slot_to_address(H256::from_slice(&output)),
+            _ => None,
+        }
+    }
+
+    /// Recovers why a failed transaction reverted. The transaction is replayed in its block with
+    /// `debug_traceTransaction` when the node supports it. Otherwise it is re-executed with
+    /// `eth_call` on top of its parent block, which is approximate: the transactions that precede
+    /// it in the same block are not applied, so a revert that depends on them may not be
+    /// reproduced. Returns `None` when no reason can be recovered, e.g. on errors of the node.
+    async fn get_revert_reason(
+        endpoint: &'a str,
+        source_providers: &'a [Box<dyn SourceProvider>],
+        proxies: &ProxyImplementations,
+        transaction: &Transaction,
+    ) -> Result<Option<String>> {
+        let provider = Provider::<Http>::try_from(endpoint)?;
+        let block_number = transaction
+            .block_number
+            .context("The transaction is still pending.")?;
+        let abi = if let Some(to) = transaction.to {
+            Self::get_contract_abis(endpoint, source_providers, proxies, &[to])
+                .await
+                .remove(&to)
+        } else {
+            None
+        };
+
+        let options = GethDebugTracingOptions {
+            tracer: Some(GethDebugTracerType::BuiltInTracer(
+                GethDebugBuiltInTracerType::CallTracer,
+            )),
+            tracer_config: Some(GethDebugTracerConfig::BuiltInTracer(
+                GethDebugBuiltInTracerConfig::CallTracer(CallConfig {
+                    only_top_call: Some(true),
+                    with_log: None,
+                }),
+            )),
+            ..GethDebugTracingOptions::default()
+        };
+        if let Ok(GethTrace::Known(GethTraceFrame::CallTracer(frame))) = provider
+            .debug_trace_transaction(transaction.hash, options)
+            .await
+        {
+            return Ok(match frame.output.filter(|output| !output.is_empty()) {
+                Some(output) => Some(decode_revert_data(&output, abi.as_ref())),
+                // e.g. `out of gas`
+                None => frame.error,
+            });
+        }
+
+        let mut request = TransactionRequest::new()
+            .from(transaction.from)
+            .value(transaction.value)
+            .gas(transaction.gas)
+            .data(transaction.input.to_owned());
+        if let Some(to) = transaction.to {
+            request = request.to(to);
+        }
+        if let Some(gas_price) = transaction.gas_price {
+            request = request.gas_price(gas_price);
+        }
+
+        match provider
+            .call(
+                &request.into(),
+                Some(BlockId::Number(BlockNumber::Number(
+                    block_number.saturating_sub(U64::from(1)),
+                ))),
+            )
+            .await
+        {
+            Ok(_) => Ok(None),
+            Err(err) => Ok(err.as_error_response().and_then(|response| {
+                if let Some(data) = response.as_revert_data() {
+                    Some(decode_revert_data(&data, abi.as_ref()))
+                } else if response.message.contains("execution reverted") {
+                    Some(response.message.to_owned())
+                } else {
+                    // Errors of the node such as `missing trie node` or rate limits are not
+                    // reasons of the revert
+                    None
+                }
+            })),
+        }
+    }
+
+    /// Reads the queried slot and the state variables of the storage layout at the given block.
+    async fn get_storage(
+        endpoint: &'a str,
+        address: Address,
+        query: &StorageQuery,
+        storage_layout: Option<&StorageLayout>,
+    ) -> Result<StorageSnapshot> {
+        let provider = Provider::<Http>::try_from(endpoint)?;
+        let block_number = provider
+            .get_block(query.block)
+            .await?
+            .and_then(|block| block.number)
+            .context("Block not found")?;
+        let block = Some(BlockId::Number(BlockNumber::Number(block_number)));
+
+        let slot = if query.slot.trim().is_empty() {
+            if storage_layout.is_none() {
+                bail!("Enter a slot to read");
+            }
+            None
+        } else {
+            let slot = query.resolve(storage_layout)?;
+            let value = provider.get_storage_at(address, slot, block).await?;
+            Some((query.to_string(), slot, value))
+        };
+
+        let variables = if let Some(storage_layout) = storage_layout {
+            let words = join_all(
+                storage_layout
+                    .storage
+                    .iter()
+                    .map(|variable| provider.get_storage_at(address, variable.slot(), block)),
+            )
+            .await;
+            storage_layout
+                .storage
+                .iter()
+                .zip(words)
+                .map(|(variable, word)| {
+                    (
+                        variable.label.to_owned(),
+                        match word {
+                            Ok(word) => format_variable(storage_layout, variable, word),
+                            Err(err) => err.to_string(),
+                        },
+                    )
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+
+        Ok(StorageSnapshot {
+            block_number,
+            slot,
+            variables,
+        })
+    }
+
+    /// Fetches the sidecars of the beacon block including the transaction and keeps its blobs.
+    async fn get_blob_sidecars(
+        endpoint: &'a str,
+        beacon_endpoint: Option<&'a str>,
+        transaction: &TransactionWithReceipt,
+    ) -> Result<Vec<BlobSidecar>> {
+        let client = beacon::client(beacon_endpoint)?;
+        let provider = Provider::<Http>::try_from(endpoint)?;
+        let block = provider
+            .get_block(
+                transaction
+                    .transaction
+                    .block_hash
+                    .context("The transaction is pending")?,
+            )
+            .await?
+            .context("Block not found")?;
+
+        let genesis_time = beacon::get_genesis_time(&client).await?;
+        let slot = beacon::timestamp_to_slot(block.timestamp.as_u64(), genesis_time);
+        let blob_versioned_hashes = blob_versioned_hashes(&transaction.transaction);
+
+        Ok(beacon::get_blob_sidecars(&client, slot)
+            .await?
+            .into_iter()
+            .filter(|sidecar| blob_versioned_hashes.contains(&sidecar.versioned_hash))
+            .collect())
+    }
+
+    async fn get_beacon_block(
+        beacon_endpoint: Option<&'a str>,
+        id: BeaconBlockId,
+    ) -> Result<BeaconBlock> {
+        let client = beacon::client(beacon_endpoint)?;
+        beacon::get_beacon_block(&client, id).await
+    }
+
+    /// Fetches the validator from the beacon node, and its withdrawals in the latest `n` blocks
+    /// from the execution node.
+    async fn get_validator(
+        endpoint: &'a str,
+        beacon_endpoint: Option<&'a str>,
+        n: u64,
+        id: &ValidatorId,
+    ) -> Result<Validator> {
+        let client = beacon::client(beacon_endpoint)?;
+        let mut validator = beacon::get_validator(&client, id).await?;
+
+        if let Some(address) = validator.withdrawal_address() {
+            let index = U64::from(validator.index);
+            validator.withdrawals = Some(
+                Self::get_withdrawals(endpoint, beacon_endpoint, n, Some(address))
+                    .await
+                    .map(|mut withdrawal_scan| {
+                        withdrawal_scan
+                            .withdrawals
+                            .retain(|withdrawal| withdrawal.withdrawal.validator_index == index);
+                        withdrawal_scan
+                    })
+                    .map_err(|err| err.to_string()),
+            );
+        }
+        Ok(validator)
+    }
+
+    async fn get_epoch(beacon_endpoint: Option<&'a str>, epoch: Option<u64>) -> Result<Epoch> {
+        let client = beacon::client(beacon_endpoint)?;
+        beacon::get_epoch(&client, epoch).await
+    }
+
+    /// Collects the withdrawals of the latest `n` blocks, or only those to `address` if given.
+    async fn get_withdrawals(
+        endpoint: &'a str,
+        beacon_endpoint: Option<&'a str>,
+        n: u64,
+        address: Option<Address>,
+    ) -> Result<WithdrawalScan> {
+        let provider = Provider::<Http>::try_from(endpoint)?;
+        let to_block = provider.get_block_number().await?;
+        let from_block = to_block.saturating_sub(U64::from(n.max(1) - 1));
+
+        let block_numbers = (from_block.as_u64()..=to_block.as_u64()).collect::<Vec<_>>();
+        let mut withdrawals = vec![];
+        let mut missing_blocks = vec![];
+        for block_numbers in block_numbers.chunks(RATE_LIMIT) {
+            let blocks = join_all(
+                block_numbers
+                    .iter()
+                    .map(|&block_number| provider.get_block(block_number)),
+            )
+            .await;
+            for (&block_number, block) in block_numbers.iter().zip(blocks) {
+                let block_number = U64::from(block_number);
+                let Ok(Some(block)) = block else {
+                    missing_blocks.push(block_number);
+                    continue;
+                };
+                for withdrawal in block.withdrawals.unwrap_or_default() {
+                    if address.is_none_or(|address| address == withdrawal.address) {
+                        withdrawals.push(ScannedWithdrawal {
+                            block_number,
+                            timestamp: block.timestamp.as_u64(),
+                            withdrawal,
+                            is_withdrawable: None,
+                        });
+                    }
+                }
+            }
+        }
+
+        // Without the beacon node, full exits are estimated from the amounts
+        let is_estimate = Self::classify_exits(beacon_endpoint, &mut withdrawals)
+            .await
+            .is_err();
+
+        Ok(WithdrawalScan {
+            from_block,
+            to_block,
+            address,
+            withdrawals,
+            missing_blocks,
+            is_estimate,
+        })
+    }
+
+    /// Tells full exits apart by the withdrawable epoch of their validator.
+    async fn classify_exits(
+        beacon_endpoint: Option<&'a str>,
+        withdrawals: &mut [ScannedWithdrawal],
+    ) -> Result<()> {
+        let client = beacon::client(beacon_endpoint)?;
+        let genesis_time = beacon::get_genesis_time(&client).await?;
+        let indices = withdrawals
+            .iter()
+            .map(|withdrawal| withdrawal.withdrawal.validator_index.as_u64())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let withdrawable_epochs = beacon::get_withdrawable_epochs(&client, &indices).await?;
+
+        for withdrawal in withdrawals.iter_mut() {
+            let epoch = beacon::timestamp_to_slot(withdrawal.timestamp, genesis_time)
+                / beacon::SLOTS_PER_EPOCH;
+            withdrawal.is_withdrawable = Some(
+                withdrawable_epochs
+                    .get(&withdrawal.withdrawal.validator_index.as_u64())
+                    .is_some_and(|&withdrawable_epoch| withdrawable_epoch <= epoch),
+            );
+        }
+        Ok(())
+    }
+
+    /// Whether the node rejected `eth_getLogs` for the size of its block range or of its result,
+    /// which splitting the range may fix.
+    fn is_logs_limit_error(err: &ProviderError) -> bool {
+        if err
+            .as_error_response()
+            .is_some_and(|response| response.code == -32005)
+        {
+            return true;
+        }
+        let message = err.to_string().to_lowercase();
+        [
+            "query returned more than",
+            "block range",
+            "response size",
+            "too many results",
+        ]
+        .iter()
+        .any(|pattern| message.contains(pattern))
+    }
+
+    /// Runs `eth_getLogs`, splitting the block range in halves whenever the node rejects it for
+    /// its size.
+    async fn get_logs(
+        endpoint: &'a str,
+        source_providers: &'a [Box<dyn SourceProvider>],
+        proxies: &ProxyImplementations,
+        request: &LogsRequest,
+    ) -> Result<LogsResult> {
+        let provider = Provider::<Http>::try_from(endpoint)?;
+
+        let resolve_block_number = |block_number: BlockNumber| {
+            let provider = &provider;
+            async move {
+                match block_number {
+                    BlockNumber::Number(number) => Ok(number),
+                    block_number => provider
+                        .get_block(block_number)
+                        .await?
+                        .and_then(|block| block.number)
+                        .with_context(|| format!("Block {block_number} was not found")),
+                }
+            }
+        };
+        let from_block = resolve_block_number(request.from_block).await?;
+        let to_block = resolve_block_number(request.to_block).await?;
+        if from_block > to_block {
+            bail!("from block is after to block");
+        }
+
+        let abis =
+            Self::get_contract_abis(endpoint, source_providers, proxies, &request.addresses).await;
+        let abi = request
+            .addresses
+            .first()
+            .and_then(|address| abis.get(address));
+
+        let mut filter = Filter::new().address(request.addresses.to_owned());
+        let event = match request.event.as_deref() {
+            Some(event) if event.len() == 66 && event.starts_with("0x") => {
+                filter = filter.topic0(event.parse::<H256>()?);
+                None
+            }
+            Some(event) => {
+                let event = resolve_event(event, abi)?;
+                filter = filter.topic0(event.signature());
+                Some(event)
+            }
+            None => None,
+        };
+        for (i, topics) in request.topics.iter().enumerate() {
+            if !topics.is_empty() {
+                filter = match i {
+                    0 => filter.topic1(topics.to_owned()),
+                    1 => filter.topic2(topics.to_owned()),
+                    _ => filter.topic3(topics.to_owned()),
+                };
+            }
+        }
+
+        let mut ranges = vec![(from_block.as_u64(), to_block.as_u64())];
+        let mut logs: Vec<Log> = vec![];
+        let mut requests = 0;
+        while let Some((from, to)) = ranges.pop() {
+            requests += 1;
+            if requests > MAX_LOGS_REQUESTS {
+                bail!("Too many requests. Narrow the block range or the filters.");
+            }
+            match provider
+                .get_logs(&filter.to_owned().from_block(from).to_block(to))
+                .await
+            {
+                Ok(mut range_logs) => logs.append(&mut range_logs),
+                Err(err) if from == to || !Self::is_logs_limit_error(&err) => {
+                    return Err(err.into())
+                }
+                Err(_) => {
+                    // The lower half is popped first, so the logs stay in order
+                    let middle = from + (to - from) / 2;
+                    ranges.push((middle + 1, to));
+                    ranges.push((from, middle));
+                }
+            }
+        }
+
+        // A given event decodes logs of any contract, otherwise the ABI of the emitter is used
+        let event_abi = event.as_ref().map(|event| {
+            let mut abi = Abi::default();
+            abi.events
+                .insert(event.name.to_owned(), vec![event.to_owned()]);
+            abi
+        });
+        let logs = logs
+            .into_iter()
+            .map(|log| LogEntry {
+                block_number: log.block_number,
+                transaction_hash: log.transaction_hash,
+                log_index: log.log_index,
+                log: DecodedLog::new(
+                    log.address,
+                    log.topics,
+                    log.data,
+                    event_abi.as_ref().or(abis.get(&log.address)),
+                ),
+            })
+            .collect();
+
+        Ok(LogsResult {
+            from_block,
+            to_block,
+            logs,
+            requests,
+            events: abi.map_or(vec![], |abi| abi.events().map(event_signature).collect()),
+        })
+    }
+
+    async fn call_contract_function(
+        endpoint: &'a str,
+        source_providers: &'a [Box<dyn SourceProvider>],
+        proxies: &ProxyImplementations,
+        address: Address,
+        function: &Function,
+        arguments: &[Token],
+        block: BlockId,
+    ) -> Result<Vec<String>> {
+        let provider = Provider::<Http>::try_from(endpoint)?;
+        let request = TransactionRequest::new()
+            .to(address)
+            .data(function.encode_input(arguments)?);
+        let output = match provider.call(&request.into(), Some(block)).await {
+            Ok(output) => output,
+            Err(err) => match err
+                .as_error_response()
+                .and_then(|response| response.as_revert_data())
+            {
+                Some(data) => bail!(
+                    "Reverted: {}",
+                    decode_revert_data(
+                        &data,
+                        Self::get_contract_abis(endpoint, source_providers, proxies, &[address])
+                            .await
+                            .get(&address)
+                    )
+                ),
+                None => return Err(err.into()),
+            },
+        };
+        let tokens = function.decode_output(&output)?;
+
+        Ok(function
+            .outputs
+            .iter()
+            .zip(tokens.iter())
+            .enumerate()
+            .map(|(i, (param, token))| {
+                format!("{}: {}", param_label(i, param), format_token(token))
+            })
+            .collect())
+    }
+
+    /// Runs a call with `eth_call` and `eth_estimateGas`, and with `debug_traceCall` to collect
+    /// the gas used and the emitted logs when the node supports it.
+    async fn simulate(
+        endpoint: &'a str,
+        source_providers: &'a [Box<dyn SourceProvider>],
+        proxies: &ProxyImplementations,
+        request: &SimulationRequest,
+    ) -> Result<SimulationResult> {
+        let provider = Provider::<Http>::try_from(endpoint)?;
+
+        let abi = if let Some(to) = request.to {
+            Self::get_contract_abis(endpoint, source_providers, proxies, &[to])
+                .await
+                .remove(&to)
+        } else {
+            None
+        };
+
+        let (data, function) = match &request.calldata {
+            Calldata::Raw(data) => (data.to_owned(), None),
+            Calldata::Function {
+                signature,
+                arguments,
+            } => {
+                let function = if signature.contains('(') {
+                    AbiParser::default().parse_function(signature)?
+                } else {
+                    abi.as_ref()
+                        .and_then(|abi| abi.functions_by_name(signature).ok())
+                        .and_then(|functions| {
+                            functions
+                                .iter()
+                                .find(|function| function.inputs.len() == arguments.len())
+                        })
+                        .with_context(|| {
+                            format!("Function {signature} was not found in the verified ABI.")
+                        })?
+                        .to_owned()
+                };
+                let arguments = parse_arguments(&function.inputs, arguments)?;
+                (
+                    Bytes::from(function.encode_input(&arguments)?),
+                    Some(function),
+                )
+            }
+        };
+
+        let mut transaction_request = TransactionRequest::new().value(request.value).data(data);
+        if let Some(from) = request.from {
+            transaction_request = transaction_request.from(from);
+        }
+        if let Some(to) = request.to {
+            transaction_request = transaction_request.to(to);
+        }
+        let transaction: TypedTransaction = transaction_request.into();
+
+        let (is_success, return_data, revert_reason) =
+            match provider.call(&transaction, Some(request.block)).await {
+                Ok(output) => (true, output, None),
+                Err(err) => match err
+                    .as_error_response()
+                    .and_then(|response| response.as_revert_data())
+                {
+                    Some(data) => {
+                        let reason = decode_revert_data(&data, abi.as_ref());
+                        (false, data, Some(reason))
+                    }
+                    None => (false, Bytes::default(), Some(err.to_string())),
+                },
+            };
+
+        let gas_estimate = if is_success {
+            provider
+                .estimate_gas(&transaction, Some(request.block))
+                .await
+                .ok()
+        } else {
+            None
+        };
+
+        let options = GethDebugTracingCallOptions {
+            tracing_options: GethDebugTracingOptions {
+                tracer: Some(GethDebugTracerType::BuiltInTracer(
+                    GethDebugBuiltInTracerType::CallTracer,
+                )),
+                tracer_config: Some(GethDebugTracerConfig::BuiltInTracer(
+                    GethDebugBuiltInTracerConfig::CallTracer(CallConfig {
+                        only_top_call: None,
+                        with_log: Some(true),
+                    }),
+                )),
+                ..GethDebugTracingOptions::default()
+            },
+            ..GethDebugTracingCallOptions::default()
+        };
+        let frame = match provider
+            .debug_trace_call(transaction, Some(request.block), options)
+            .await
+        {
+            Ok(GethTrace::Known(GethTraceFrame::CallTracer(frame))) => Some(frame),
+            Ok(GethTrace::Unknown(value)) => serde_json::from_value::<CallFrame>(value).ok(),
+            _ => None,
+        };
+
+        let logs = if let Some(frame) = frame.as_ref() {
+            let logs = collect_logs(frame);
+            let addresses = logs
+                .iter()
+                .map(|(address, _, _)| *address)
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect::<Vec<_>>();
+            let abis =
+                Self::get_contract_abis(endpoint, source_providers, proxies, &addresses).await;
+            Some(
+                logs.into_iter()
+                    .map(|(address, topics, data)| {
+                        DecodedLog::new(address, topics, data, abis.get(&address))
+                    })
+                    .collect(),
+            )
+        } else {
+            None
+        };
+
+        let decoded_output = match function.as_ref() {
+            Some(function) if is_success => {
+                function
+                    .decode_output(&return_data)
+                    .map_or(vec![], |tokens| {
+                        function
+                            .outputs
+                            .iter()
+                            .zip(tokens.iter())
+                            .enumerate()
+                            .map(|(i, (param, token))| {
+                                format!("{}: {}", param_label(i, param), format_token(token))
+                            })
+                            .collect()
+                    })
+            }
+            _ => vec![],
+        };
+
+        Ok(SimulationResult {
+            is_success,
+            revert_reason,
+            gas_estimate,
+            gas_used: frame.map(|frame| frame.gas_used),
+            return_data,
+            decoded_output,
+            logs,
+        })
+    }
+
+    async fn get_latest_blocks(
+        endpoint: &'a str,
+        n: usize,
+    ) -> Result<Vec<BlockWithTransactionReceipts<Transaction>>> {
+        let provider = Provider::<Http>::try_from(endpoint)?;
+        let block_number = provider.get_block_number().await?;
+
+        let mut blocks = vec![];
+        for i in 0..n {
+            let block = provider.get_block_with_txs(block_number - i);
+            blocks.push(block);
+        }
+
+        let blocks = join_all(blocks).await;
+
+        let mut latest_blocks = vec![];
+        for block in blocks.into_iter().flatten().flatten() {
+            latest_blocks.push(BlockWithTransactionReceipts {
+                block,
+                transaction_receipts: None,
+            });
+        }
+        Ok(latest_blocks)
+    }
+
+    async fn get_transaction_receipts(
+        endpoint: &'a str,
+        transactions: &[Transaction],
+    ) -> Result<Vec<TransactionReceipt>> {
+        let provider = Provider::<Http>::try_from(endpoint)?;
+        let query = transactions
+            .iter()
+            .map(|tx| provider.get_transaction_receipt(tx.hash))
+            .collect::<Vec<_>>();
+        let res = join_all(query).await;
+        let mut transaction_receips = vec![];
+
+        for receipt in res.into_iter().flatten().flatten() {
+            transaction_receips.push(receipt);
+        }
+
+        Ok(transaction_receips)
+    }
+
+    async fn get_latest_transactions(
+        endpoint: &'a str,
+        n: usize,
+    ) -> Result<Vec<TransactionWithReceipt>> {
+        let provider = Provider::<Http>::try_from(endpoint)?;
+
+        let block = provider.get_block(BlockNumber::Latest).await?;
+        // Every transaction is in the latest block
+        let base_fee_per_gas = block.as_ref().and_then(|block| block.base_fee_per_gas);
 
         let transaction_futures = if let Some(block) = block {
             block
@@ -543,45 +1905,259 @@ impl<'a> Network<'a> {
                 transaction: transactions[i].to_owned(),
                 transaction_receipt: receipts[i].to_owned(),
                 decoded_input_data: None,
+                state_changes: None,
+                revert_reason: None,
+                base_fee_per_gas,
+                blob_sidecars: None,
             });
         }
 
         Ok(result)
     }
 
-    async fn get_statistics(endpoint: &'a str) -> Result<Statistics> {
+    async fn get_statistics(
+        endpoint: &'a str,
+        beacon_endpoint: Option<&'a str>,
+    ) -> Result<Statistics> {
         let provider = Provider::<Http>::try_from(endpoint)?;
 
-        let mut ethusd = None;
-        let mut node_count = None;
-        let mut suggested_base_fee = None;
-        let mut med_gas_price = None;
-        if let Ok(client) = Client::new_from_env(Chain::Mainnet) {
-            let (eth_price, total_node_count, gas_oracle) =
-                try_join3(client.eth_price(), client.node_count(), client.gas_oracle()).await?;
-
-            ethusd = Some(eth_price.ethusd);
-            node_count = Some(total_node_count.total_node_count);
-            suggested_base_fee = Some(gas_oracle.suggested_base_fee);
-            med_gas_price = Some(gas_oracle.propose_gas_price);
-        }
-
-        let (last_safe_block, last_finalized_block) = try_join(
+        let (last_safe_block, last_finalized_block, latest_block_number) = try_join3(
             provider.get_block_with_txs(BlockNumber::Safe),
             provider.get_block_with_txs(BlockNumber::Finalized),
+            provider.get_block_number(),
         )
         .await?;
 
+        // The beacon node is optional, so its errors do not hide the other statistics
+        let finality = match beacon::client(beacon_endpoint) {
+            Ok(client) => beacon::get_finality(&client).await.ok(),
+            Err(_) => None,
+        };
+
         Ok(Statistics {
-            ethusd,
-            node_count,
-            suggested_base_fee,
-            med_gas_price,
             last_safe_block,
             last_finalized_block,
+            latest_block_number: Some(latest_block_number),
+            finality,
+        })
+    }
+
+    /// The Etherscan client when a statistic read from `source` comes from Etherscan, or `None`
+    /// when it comes from the node.
+    fn etherscan_client(source: StatisticsSource) -> Result<Option<Client>> {
+        let client = Client::new_from_env(Chain::Mainnet);
+        if source.uses_etherscan(client.is_ok()) {
+            Ok(Some(client?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn get_ether_price(
+        provider: &Provider<Http>,
+        source: StatisticsSource,
+        price_feed: Address,
+    ) -> Result<f64> {
+        match Self::etherscan_client(source)? {
+            Some(client) => Ok(client.eth_price().await?.ethusd),
+            None => get_chainlink_price(provider, price_feed).await,
+        }
+    }
+
+    /// The suggested base fee and the median gas price. The node estimates them from the fee
+    /// history of the last 20 blocks.
+    async fn get_gas_prices(endpoint: &'a str, source: StatisticsSource) -> Result<(U256, U256)> {
+        match Self::etherscan_client(source)? {
+            Some(client) => {
+                let gas_oracle = client.gas_oracle().await?;
+                Ok((gas_oracle.suggested_base_fee, gas_oracle.propose_gas_price))
+            }
+            None => {
+                let fee_history = Self::get_fee_history(endpoint, 20).await?;
+                let base_fee = fee_history
+                    .next_base_fee()
+                    .context("The fee history is empty.")?;
+                let [_, medium, _] = fee_history
+                    .tip_suggestions()
+                    .context("The fee history has no rewards.")?;
+                Ok((base_fee, base_fee + medium))
+            }
+        }
+    }
+
+    async fn get_node_statistic(
+        provider: &Provider<Http>,
+        source: StatisticsSource,
+    ) -> Result<NodeStatistic> {
+        match Self::etherscan_client(source)? {
+            Some(client) => Ok(NodeStatistic::NodeCount(
+                client.node_count().await?.total_node_count,
+            )),
+            None => Ok(NodeStatistic::Node(Self::get_node_info(provider).await?)),
+        }
+    }
+
+    async fn get_metric(
+        endpoint: &'a str,
+        config: StatisticsConfig,
+        tile: &TileConfig,
+    ) -> Result<Reading> {
+        let provider = Provider::<Http>::try_from(endpoint)?;
+        // A tile can read its metric from another source than the one given on the command line
+        let source = |default: StatisticsSource| tile.source.unwrap_or(default);
+        let block_number = |block: Option<Block<H256>>| -> Result<Reading> {
+            Ok(Reading::new(
+                block
+                    .and_then(|block| block.number)
+                    .context("Block Number is None")?
+                    .as_u64() as f64,
+            ))
+        };
+
+        Ok(match tile.metric {
+            Metric::EtherPrice => Reading::new(
+                Self::get_ether_price(&provider, source(config.ether_price), config.price_feed)
+                    .await?,
+            ),
+            Metric::SuggestedBaseFee | Metric::MedGasPrice => {
+                let (suggested_base_fee, med_gas_price) =
+                    Self::get_gas_prices(endpoint, source(config.gas)).await?;
+                Reading::new(to_gwei(match tile.metric {
+                    Metric::SuggestedBaseFee => suggested_base_fee,
+                    _ => med_gas_price,
+                }))
+            }
+            Metric::NodeCount => {
+                match Self::get_node_statistic(&provider, source(config.node)).await? {
+                    NodeStatistic::NodeCount(node_count) => Reading::new(node_count as f64),
+                    NodeStatistic::Node(node_info) => Reading {
+                        value: node_info.peer_count as f64,
+                        title: Some(match node_info.sync_progress {
+                            Some(progress) => format!(
+                                "NODE ({}, {progress:.1}% synced)",
+                                node_info.client_version
+                            ),
+                            None => format!("NODE ({})", node_info.client_version),
+                        }),
+                        format: Some("{value} peers"),
+                    },
+                }
+            }
+            Metric::LastSafeBlock => block_number(provider.get_block(BlockNumber::Safe).await?)?,
+            Metric::LastFinalizedBlock => {
+                block_number(provider.get_block(BlockNumber::Finalized).await?)?
+            }
+            Metric::BlobBaseFee => Reading::new(
+                Self::get_fee_history(endpoint, 1)
+                    .await?
+                    .next_blob_base_fee()
+                    .context("The node does not support blobs.")?
+                    .low_u128() as f64,
+            ),
+            Metric::PendingTransactionCount => Reading::new(
+                provider
+                    .get_block(BlockNumber::Pending)
+                    .await?
+                    .context("The node has no pending block.")?
+                    .transactions
+                    .len() as f64,
+            ),
+            Metric::Tps { blocks } => {
+                let (blocks, elapsed) = Self::get_recent_blocks(&provider, blocks).await?;
+                let transaction_count = blocks
+                    .iter()
+                    .map(|block| block.transactions.len())
+                    .sum::<usize>();
+                Reading::new(transaction_count as f64 / elapsed)
+            }
+            Metric::BurntEthPerHour { blocks } => {
+                let (blocks, elapsed) = Self::get_recent_blocks(&provider, blocks).await?;
+                let burnt = blocks
+                    .iter()
+                    .map(|block| block.base_fee_per_gas.unwrap_or_default() * block.gas_used)
+                    .fold(U256::zero(), |sum, burnt| sum + burnt);
+                Reading::new(to_gwei(burnt) / 1e9 / elapsed * 3600.0)
+            }
+            Metric::Balance { address } => {
+                Reading::new(to_gwei(provider.get_balance(address, None).await?) / 1e9)
+            }
         })
     }
 
+    /// The latest `n` blocks and the seconds since the block before them.
+    async fn get_recent_blocks(
+        provider: &Provider<Http>,
+        n: u64,
+    ) -> Result<(Vec<Block<H256>>, f64)> {
+        let latest_block_number = provider.get_block_number().await?;
+        let oldest_block_number = latest_block_number.saturating_sub(U64::from(n.max(1)));
+        let blocks = join_all(
+            (oldest_block_number.as_u64()..=latest_block_number.as_u64())
+                .map(|number| provider.get_block(number)),
+        )
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .collect::<Option<Vec<_>>>()
+        .context("Some blocks were not found.")?;
+
+        let [oldest_block, recent_blocks @ ..] = blocks.as_slice() else {
+            bail!("No blocks were found.");
+        };
+        let elapsed = recent_blocks
+            .last()
+            .map_or(U256::zero(), |latest_block| {
+                latest_block
+                    .timestamp
+                    .saturating_sub(oldest_block.timestamp)
+            })
+            .as_u64()
+            .max(1) as f64;
+
+        Ok((recent_blocks.to_vec(), elapsed))
+    }
+
+    async fn get_node_info(provider: &Provider<Http>) -> Result<NodeInfo> {
+        let (client_version, peer_count, syncing) = try_join3(
+            provider.client_version(),
+            provider.request::<_, U64>("net_peerCount", ()),
+            provider.syncing(),
+        )
+        .await?;
+
+        let sync_progress = match syncing {
+            SyncingStatus::IsFalse => None,
+            SyncingStatus::IsSyncing(progress) => Some(
+                (progress.current_block.as_u64() as f64
+                    / progress.highest_block.as_u64().max(1) as f64)
+                    * 100.0,
+            ),
+        };
+
+        Ok(NodeInfo {
+            client_version,
+            peer_count: peer_count.as_u64(),
+            sync_progress,
+        })
+    }
+
+    async fn get_fee_history(endpoint: &'a str, block_count: u64) -> Result<FeeHistory> {
+        let provider = Provider::<Http>::try_from(endpoint)?;
+
+        // The ethers `fee_history` drops the blob fields, so the raw response is deserialized
+        Ok(provider
+            .request::<_, FeeHistory>(
+                "eth_feeHistory",
+                (
+                    U64::from(block_count),
+                    BlockNumber::Latest,
+                    REWARD_PERCENTILES,
+                ),
+            )
+            .await?)
+    }
+
     async fn update_app_with_ens_ids(
         &mut self,
         addresses: &[Address],
@@ -602,6 +2178,71 @@ impl<'a> Network<'a> {
         Ok(())
     }
 
+    /// Shows `block`, in place of the searching route when `is_searching`, then resolves the ENS
+    /// names and the method signatures of its transactions.
+    async fn update_app_with_block(
+        &mut self,
+        block: Option<BlockWithTransactionReceipts<Transaction>>,
+        is_searching: bool,
+    ) {
+        {
+            let mut app = self.app.lock().await;
+            if is_searching {
+                app.pop_current_route();
+            }
+            app.set_route(Route::new(
+                RouteId::Block(block.to_owned()),
+                ActiveBlock::Main,
+            ));
+        }
+
+        if let Some(block) = block {
+            let mut addresses = vec![];
+            for transaction in block.block.transactions.iter() {
+                addresses.push(transaction.from);
+                if let Some(to) = transaction.to {
+                    addresses.push(to);
+                }
+            }
+
+            let _ = self.update_app_with_ens_ids(&addresses).await;
+            let selectors = block
+                .block
+                .transactions
+                .iter()
+                .filter_map(selector)
+                .collect::<Vec<_>>();
+            let _ = self.update_app_with_signatures(&selectors).await;
+        }
+    }
+
+    /// Looks up the signatures of the called `selectors` that are not known yet, so that filters
+    /// can match methods by name.
+    async fn update_app_with_signatures(&mut self, selectors: &[[u8; 4]]) -> Result<()> {
+        let selectors = {
+            let app = self.app.lock().await;
+            selectors
+                .iter()
+                .copied()
+                .filter(|selector| !app.signatures.contains_key(selector))
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+
+        for selectors in selectors.chunks(RATE_LIMIT) {
+            let signatures = lookup_signatures(selectors).await?;
+            let mut app = self.app.lock().await;
+            for selector in selectors {
+                app.signatures.insert(
+                    *selector,
+                    signatures.get(selector).cloned().unwrap_or_default(),
+                );
+            }
+        }
+        Ok(())
+    }
+
     async fn lookup_addresses(
         endpoint: &'a str,
         addresses: &[Address],
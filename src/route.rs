@@ -1,4 +1,12 @@
-use crate::ethers::types::{AddressInfo, BlockWithTransactionReceipts, TransactionWithReceipt};
+use crate::{
+    beacon::{BeaconBlock, Epoch, Validator},
+    ethers::{
+        fee_history::FeeHistory,
+        trace::CallTrace,
+        types::{AddressInfo, BlockWithTransactionReceipts, TransactionWithReceipt},
+        withdrawals::WithdrawalScan,
+    },
+};
 use ethers::core::types::Transaction;
 
 #[derive(Clone)]
@@ -11,6 +19,17 @@ pub enum RouteId {
     WithdrawalsOfBlock(Option<BlockWithTransactionReceipts<Transaction>>),
     Transaction(Option<TransactionWithReceipt>),
     InputDataOfTransaction(Option<TransactionWithReceipt>),
+    StateChangesOfTransaction(Option<TransactionWithReceipt>),
+    BlobsOfTransaction(Option<TransactionWithReceipt>),
+    CallTraceOfTransaction(Result<CallTrace, String>),
+    BeaconBlock(Option<BeaconBlock>),
+    Validator(Result<Validator, String>),
+    Epoch(Option<Epoch>),
+    Withdrawals(Option<WithdrawalScan>),
+    Reorgs,
+    Logs,
+    GasTracker(Option<FeeHistory>),
+    Simulation,
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -1,14 +1,27 @@
 mod address_info;
+mod beacon_block;
 mod block;
+mod call_trace;
+mod epoch;
+mod filter_bar;
+mod finality;
+mod gas_tracker;
 mod latest_status;
+mod logs;
+mod reorgs;
 mod searching;
+mod simulation;
 mod statistics;
 mod transaction;
+mod validator;
 mod welcome;
+mod withdrawals;
 use crate::{
     app::{App, InputMode},
+    ethers::types::ERC20Token,
     route::{ActiveBlock, RouteId},
 };
+use ethers::core::types::Address;
 use ratatui::{prelude::*, widgets::*};
 
 /// /home
@@ -51,6 +64,7 @@ pub fn render_home_layout<B: Backend>(f: &mut Frame<B>, app: &mut App) {
     let message = Paragraph::new(" <up>/<down>: k/j, <esc>: Cancel, q: Quit, ?: Keybindings, 1-2: Jump to panel, s: Focus on the Search bar")
         .style(Style::default().fg(Color::White));
     f.render_widget(message, navigation_bar);
+    filter_bar::render(f, app, navigation_bar);
 
     match app.input_mode {
         InputMode::Normal =>
@@ -83,9 +97,39 @@ pub fn render_home_layout<B: Backend>(f: &mut Frame<B>, app: &mut App) {
             RouteId::WithdrawalsOfBlock(block_with_transaction_receipts) => {
                 block::render(f, app, block_with_transaction_receipts, rest);
             }
-            RouteId::Transaction(transaction) | RouteId::InputDataOfTransaction(transaction) => {
+            RouteId::Transaction(transaction)
+            | RouteId::InputDataOfTransaction(transaction)
+            | RouteId::StateChangesOfTransaction(transaction)
+            | RouteId::BlobsOfTransaction(transaction) => {
                 transaction::render(f, app, transaction, rest);
             }
+            RouteId::CallTraceOfTransaction(call_trace) => {
+                call_trace::render(f, app, call_trace, rest);
+            }
+            RouteId::BeaconBlock(beacon_block) => {
+                beacon_block::render(f, app, beacon_block, rest);
+            }
+            RouteId::Validator(validator) => {
+                validator::render(f, app, validator, rest);
+            }
+            RouteId::Epoch(epoch) => {
+                epoch::render(f, app, epoch, rest);
+            }
+            RouteId::Withdrawals(withdrawal_scan) => {
+                withdrawals::render(f, app, withdrawal_scan, rest);
+            }
+            RouteId::Simulation => {
+                simulation::render(f, app, rest);
+            }
+            RouteId::Reorgs => {
+                reorgs::render(f, app, rest);
+            }
+            RouteId::Logs => {
+                logs::render(f, app, rest);
+            }
+            RouteId::GasTracker(fee_history) => {
+                gas_tracker::render(f, app, fee_history, rest);
+            }
             RouteId::Welcome => {
                 welcome::render(f, app, rest);
             }
@@ -102,16 +146,30 @@ pub fn render_home_layout<B: Backend>(f: &mut Frame<B>, app: &mut App) {
             return;
         };
 
-        let [statistics, latest_status] = *Layout::default()
+        let [statistics, finality_rect, latest_status] = *Layout::default()
             .direction(Direction::Vertical)
             .margin(0)
-            .constraints([Constraint::Min(9), Constraint::Min(0)].as_ref())
+            .constraints(
+                [
+                    Constraint::Length(app.dashboard.height()),
+                    Constraint::Length(if app.statistics.finality.is_some() {
+                        finality::HEIGHT
+                    } else {
+                        0
+                    }),
+                    Constraint::Min(0),
+                ]
+                .as_ref(),
+            )
             .split(sidebar)
         else {
             return;
         };
 
         let _ = statistics::render(f, app, statistics);
+        if let Some(finality) = app.statistics.finality.to_owned() {
+            finality::render(f, &finality, finality_rect);
+        }
         latest_status::render(f, app, latest_status);
 
         match app.get_current_route().get_id() {
@@ -127,9 +185,39 @@ pub fn render_home_layout<B: Backend>(f: &mut Frame<B>, app: &mut App) {
             RouteId::WithdrawalsOfBlock(block) => {
                 block::render(f, app, block, detail);
             }
-            RouteId::Transaction(transaction) | RouteId::InputDataOfTransaction(transaction) => {
+            RouteId::Transaction(transaction)
+            | RouteId::InputDataOfTransaction(transaction)
+            | RouteId::StateChangesOfTransaction(transaction)
+            | RouteId::BlobsOfTransaction(transaction) => {
                 transaction::render(f, app, transaction, detail);
             }
+            RouteId::CallTraceOfTransaction(call_trace) => {
+                call_trace::render(f, app, call_trace, detail);
+            }
+            RouteId::BeaconBlock(beacon_block) => {
+                beacon_block::render(f, app, beacon_block, detail);
+            }
+            RouteId::Validator(validator) => {
+                validator::render(f, app, validator, detail);
+            }
+            RouteId::Epoch(epoch) => {
+                epoch::render(f, app, epoch, detail);
+            }
+            RouteId::Withdrawals(withdrawal_scan) => {
+                withdrawals::render(f, app, withdrawal_scan, detail);
+            }
+            RouteId::Simulation => {
+                simulation::render(f, app, detail);
+            }
+            RouteId::Reorgs => {
+                reorgs::render(f, app, detail);
+            }
+            RouteId::Logs => {
+                logs::render(f, app, detail);
+            }
+            RouteId::GasTracker(fee_history) => {
+                gas_tracker::render(f, app, fee_history, detail);
+            }
             RouteId::Welcome => {
                 welcome::render(f, app, detail);
             }
@@ -162,6 +250,7 @@ pub fn render_home_layout<B: Backend>(f: &mut Frame<B>, app: &mut App) {
                 ))
                 .fg(Color::White),
             ),
+            Line::from(Span::raw(format!(" {:<4}: {}", "/", "Filter the table")).fg(Color::White)),
         ])
         .style(Style::default().fg(Color::Green))
         .block(block.to_owned());
@@ -193,3 +282,14 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         ])
         .split(popup_layout[1])[1]
 }
+
+/// The ticker or ENS name of `address`, or the address itself.
+fn address_label(app: &App, address: Address) -> String {
+    if let Some(token) = ERC20Token::find_by_address(&app.erc20_tokens, address) {
+        token.ticker.to_string()
+    } else if let Some(Some(ens_id)) = app.address2ens_id.get(&address) {
+        ens_id.to_owned()
+    } else {
+        format!("{:#x}", address)
+    }
+}
@@ -1,6 +1,12 @@
+mod bytecode;
+mod read_contract;
+mod source_code;
+mod storage;
+mod syntax;
+
 use crate::{
     app::{address::SelectableContractDetailItem, App},
-    ethers::types::AddressInfo,
+    ethers::{contract_call::read_functions, types::AddressInfo},
     route::ActiveBlock,
 };
 use ethers::core::utils::format_ether;
@@ -26,14 +32,6 @@ pub fn render<B: Backend>(
             .borders(Borders::ALL)
             .border_type(BorderType::Plain);
 
-        let [detail_rect, contract_detail_rect] = *Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Max(7), Constraint::Min(3)].as_ref())
-            .split(rect)
-        else {
-            return;
-        };
-
         let mut details = vec![];
 
         if let Some(token) = app
@@ -71,27 +69,53 @@ pub fn render<B: Backend>(
             .fg(Color::White),
         ));
 
-        let source_code_lines =
-            if let Some(contract_source_code) = address_info.contract_source_code {
-                let mut details = vec![];
-                let source_code = contract_source_code.items[0]
-                    .source_code()
-                    .replace("\\n", "\n");
-                let source_code = source_code.split('\n').collect::<Vec<_>>();
+        if let Some(proxy) = address_info.proxy.as_ref() {
+            details.push(Line::from(vec![
+                Span::raw(format!("{:<17}: {} → ", "PROXY", proxy.kind)).fg(Color::White),
+                Span::raw(format!("{:#x}", proxy.implementation)).fg(Color::Cyan),
+                Span::raw(" (press <enter> to open the implementation)").fg(Color::Gray),
+            ]));
+
+            if let Some(admin) = proxy.admin {
+                details.push(Line::from(vec![
+                    Span::raw(format!("{:<17}: ", "PROXY ADMIN")).fg(Color::White),
+                    Span::raw(format!("{:#x}", admin)).fg(Color::Cyan),
+                ]));
+            }
+
+            if let Some(beacon) = proxy.beacon {
+                details.push(Line::from(vec![
+                    Span::raw(format!("{:<17}: ", "BEACON")).fg(Color::White),
+                    Span::raw(format!("{:#x}", beacon)).fg(Color::Cyan),
+                ]));
+            }
+        }
+
+        let [detail_rect, contract_detail_rect] = *Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Max((details.len() as u16 + 4).max(7)),
+                    Constraint::Min(3),
+                ]
+                .as_ref(),
+            )
+            .split(rect)
+        else {
+            return;
+        };
+
+        let functions = address_info
+            .contract_abi
+            .as_ref()
+            .map_or(vec![], read_functions);
+
+        let selected_item = SelectableContractDetailItem::from(
+            app.contract_list_state
+                .selected()
+                .unwrap_or(SelectableContractDetailItem::ContractSourceCode.into()),
+        );
 
-                for (idx, line) in source_code.iter().enumerate() {
-                    details.push(Line::from(vec![
-                        Span::raw(format!("{:>3}  ", idx + 1)).fg(Color::Gray),
-                        Span::raw(line.to_string()).fg(Color::White),
-                    ]));
-                }
-                details
-            } else {
-                vec![]
-            };
-        app.source_code_scroll_state = app
-            .source_code_scroll_state
-            .content_length(source_code_lines.len() as u16);
         let abi_lines = if let Some(contract_abi) = address_info.contract_abi {
             let mut details = vec![];
             let contract_abi =
@@ -112,98 +136,101 @@ pub fn render<B: Backend>(
         app.abi_scroll_state = app.abi_scroll_state.content_length(abi_lines.len() as u16);
 
         if app.is_toggled {
-            let chunks = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([Constraint::Ratio(2, 3), Constraint::Ratio(1, 3)])
-                .split(contract_detail_rect);
-
-            // render SOURCE CODE
-            let block = Block::default().padding(Padding::new(1, 0, 0, 1));
-            f.render_widget(
-                Paragraph::new(source_code_lines.to_owned())
-                    .alignment(Alignment::Left)
-                    .block(
-                        if let SelectableContractDetailItem::ContractSourceCode =
-                            SelectableContractDetailItem::from(
-                                app.contract_list_state.selected().unwrap_or(
-                                    SelectableContractDetailItem::ContractSourceCode.into(),
-                                ),
-                            )
-                        {
-                            Block::default()
-                                .borders(Borders::ALL)
-                                .green()
-                                .title(Span::styled(
-                                    "SOURCE CODE",
-                                    Style::default().add_modifier(Modifier::BOLD).green(),
-                                ))
-                        } else {
-                            Block::default()
-                                .borders(Borders::ALL)
-                                .gray()
-                                .title(Span::styled(
-                                    "SOURCE CODE",
-                                    Style::default().add_modifier(Modifier::BOLD),
-                                ))
-                        },
-                    )
-                    .scroll((app.source_code_scroll, 0))
-                    .wrap(Wrap { trim: false }),
-                block.inner(chunks[0]),
-            );
+            if let SelectableContractDetailItem::ReadContract = selected_item {
+                read_contract::render(f, app, &functions, contract_detail_rect);
+            } else if let (SelectableContractDetailItem::Bytecode, Some(contract_bytecode)) =
+                (selected_item, address_info.bytecode.as_ref())
+            {
+                bytecode::render(f, app, contract_bytecode, contract_detail_rect);
+            } else if let SelectableContractDetailItem::Storage = selected_item {
+                storage::render(
+                    f,
+                    app,
+                    address_info.storage_layout.is_some(),
+                    contract_detail_rect,
+                );
+            } else {
+                let chunks = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Ratio(2, 3), Constraint::Ratio(1, 3)])
+                    .split(contract_detail_rect);
 
-            f.render_stateful_widget(
-                Scrollbar::default()
-                    .orientation(ScrollbarOrientation::VerticalRight)
-                    .begin_symbol(Some("▲"))
-                    .end_symbol(Some("▼")),
-                block.inner(chunks[0]),
-                &mut app.source_code_scroll_state,
-            );
+                // render SOURCE CODE
+                let block = Block::default().padding(Padding::new(1, 0, 0, 1));
+                source_code::render(
+                    f,
+                    app,
+                    &address_info.source_files,
+                    if let SelectableContractDetailItem::ContractSourceCode =
+                        SelectableContractDetailItem::from(
+                            app.contract_list_state
+                                .selected()
+                                .unwrap_or(SelectableContractDetailItem::ContractSourceCode.into()),
+                        )
+                    {
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .green()
+                            .title(Span::styled(
+                                "SOURCE CODE",
+                                Style::default().add_modifier(Modifier::BOLD).green(),
+                            ))
+                    } else {
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .gray()
+                            .title(Span::styled(
+                                "SOURCE CODE",
+                                Style::default().add_modifier(Modifier::BOLD),
+                            ))
+                    },
+                    block.inner(chunks[0]),
+                );
 
-            // render ABI
-            let block = Block::default().padding(Padding::new(0, 1, 0, 1));
-            f.render_widget(
-                Paragraph::new(abi_lines.to_owned())
-                    .alignment(Alignment::Left)
-                    .block(
-                        if let SelectableContractDetailItem::ContractAbi =
-                            SelectableContractDetailItem::from(
-                                app.contract_list_state.selected().unwrap_or(
-                                    SelectableContractDetailItem::ContractSourceCode.into(),
-                                ),
-                            )
-                        {
-                            Block::default()
-                                .borders(Borders::ALL)
-                                .green()
-                                .title(Span::styled(
-                                    "ABI",
-                                    Style::default().add_modifier(Modifier::BOLD).green(),
-                                ))
-                        } else {
-                            Block::default()
-                                .borders(Borders::ALL)
-                                .gray()
-                                .title(Span::styled(
-                                    "ABI",
-                                    Style::default().add_modifier(Modifier::BOLD),
-                                ))
-                        },
-                    )
-                    .scroll((app.abi_scroll, 0))
-                    .wrap(Wrap { trim: false }),
-                block.inner(chunks[1]),
-            );
+                // render ABI
+                let block = Block::default().padding(Padding::new(0, 1, 0, 1));
+                f.render_widget(
+                    Paragraph::new(abi_lines.to_owned())
+                        .alignment(Alignment::Left)
+                        .block(
+                            if let SelectableContractDetailItem::ContractAbi =
+                                SelectableContractDetailItem::from(
+                                    app.contract_list_state.selected().unwrap_or(
+                                        SelectableContractDetailItem::ContractSourceCode.into(),
+                                    ),
+                                )
+                            {
+                                Block::default()
+                                    .borders(Borders::ALL)
+                                    .green()
+                                    .title(Span::styled(
+                                        "ABI",
+                                        Style::default().add_modifier(Modifier::BOLD).green(),
+                                    ))
+                            } else {
+                                Block::default()
+                                    .borders(Borders::ALL)
+                                    .gray()
+                                    .title(Span::styled(
+                                        "ABI",
+                                        Style::default().add_modifier(Modifier::BOLD),
+                                    ))
+                            },
+                        )
+                        .scroll((app.abi_scroll, 0))
+                        .wrap(Wrap { trim: false }),
+                    block.inner(chunks[1]),
+                );
 
-            f.render_stateful_widget(
-                Scrollbar::default()
-                    .orientation(ScrollbarOrientation::VerticalRight)
-                    .begin_symbol(Some("▲"))
-                    .end_symbol(Some("▼")),
-                block.inner(chunks[1]),
-                &mut app.abi_scroll_state,
-            );
+                f.render_stateful_widget(
+                    Scrollbar::default()
+                        .orientation(ScrollbarOrientation::VerticalRight)
+                        .begin_symbol(Some("▲"))
+                        .end_symbol(Some("▼")),
+                    block.inner(chunks[1]),
+                    &mut app.abi_scroll_state,
+                );
+            }
         } else {
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
@@ -212,7 +239,7 @@ pub fn render<B: Backend>(
 
             let block = Block::default().padding(Padding::horizontal(2));
 
-            let titles = ["SOURCE CODE", "ABI"]
+            let titles = ["SOURCE CODE", "ABI", "READ CONTRACT", "BYTECODE", "STORAGE"]
                 .iter()
                 .map(|t| Line::from(t.to_owned()))
                 .collect();
@@ -228,49 +255,57 @@ pub fn render<B: Backend>(
                 .highlight_style(Style::default().bold().green());
             f.render_widget(tabs, block.inner(chunks[0]));
 
-            let inner = match SelectableContractDetailItem::from(
-                app.contract_list_state
-                    .selected()
-                    .unwrap_or(SelectableContractDetailItem::ContractSourceCode.into()),
-            ) {
+            let block = Block::default().padding(Padding::new(2, 2, 0, 1));
+
+            match selected_item {
                 SelectableContractDetailItem::ContractSourceCode => {
-                    Paragraph::new(source_code_lines.to_owned())
-                        .block(
-                            Block::default()
-                                .borders(Borders::RIGHT | Borders::LEFT | Borders::BOTTOM),
-                        )
-                        .alignment(Alignment::Left)
-                        .scroll((app.source_code_scroll, 0))
-                        .wrap(Wrap { trim: false })
-                }
-                SelectableContractDetailItem::ContractAbi => Paragraph::new(abi_lines.to_owned())
-                    .block(
+                    source_code::render(
+                        f,
+                        app,
+                        &address_info.source_files,
                         Block::default().borders(Borders::RIGHT | Borders::LEFT | Borders::BOTTOM),
-                    )
-                    .alignment(Alignment::Left)
-                    .scroll((app.abi_scroll, 0))
-                    .wrap(Wrap { trim: false }),
-            };
-            let block = Block::default().padding(Padding::new(2, 2, 0, 1));
-            f.render_widget(inner, block.inner(chunks[1]));
+                        block.inner(chunks[1]),
+                    );
+                }
+                SelectableContractDetailItem::ContractAbi => {
+                    f.render_widget(
+                        Paragraph::new(abi_lines.to_owned())
+                            .block(
+                                Block::default()
+                                    .borders(Borders::RIGHT | Borders::LEFT | Borders::BOTTOM),
+                            )
+                            .alignment(Alignment::Left)
+                            .scroll((app.abi_scroll, 0))
+                            .wrap(Wrap { trim: false }),
+                        block.inner(chunks[1]),
+                    );
 
-            f.render_stateful_widget(
-                Scrollbar::default()
-                    .orientation(ScrollbarOrientation::VerticalRight)
-                    .begin_symbol(Some("▲"))
-                    .end_symbol(Some("▼")),
-                block.inner(chunks[1]),
-                &mut match SelectableContractDetailItem::from(
-                    app.contract_list_state
-                        .selected()
-                        .unwrap_or(SelectableContractDetailItem::ContractSourceCode.into()),
-                ) {
-                    SelectableContractDetailItem::ContractSourceCode => {
-                        app.source_code_scroll_state
+                    f.render_stateful_widget(
+                        Scrollbar::default()
+                            .orientation(ScrollbarOrientation::VerticalRight)
+                            .begin_symbol(Some("▲"))
+                            .end_symbol(Some("▼")),
+                        block.inner(chunks[1]),
+                        &mut app.abi_scroll_state,
+                    );
+                }
+                SelectableContractDetailItem::ReadContract => {
+                    read_contract::render(f, app, &functions, block.inner(chunks[1]));
+                }
+                SelectableContractDetailItem::Bytecode => {
+                    if let Some(contract_bytecode) = address_info.bytecode.as_ref() {
+                        bytecode::render(f, app, contract_bytecode, block.inner(chunks[1]));
                     }
-                    SelectableContractDetailItem::ContractAbi => app.abi_scroll_state,
-                },
-            );
+                }
+                SelectableContractDetailItem::Storage => {
+                    storage::render(
+                        f,
+                        app,
+                        address_info.storage_layout.is_some(),
+                        block.inner(chunks[1]),
+                    );
+                }
+            }
         }
 
         let details = Paragraph::new(details)
@@ -0,0 +1,157 @@
+use crate::{app::App, ethers::bytecode::ContractBytecode};
+use ethers::core::utils::hex;
+use ratatui::{prelude::*, widgets::*};
+
+const BYTES_PER_ROW: usize = 32;
+
+pub fn render<B: Backend>(
+    f: &mut Frame<B>,
+    app: &mut App,
+    bytecode: &ContractBytecode,
+    rect: Rect,
+) {
+    let [info_rect, code_rect] = *Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 3), Constraint::Ratio(2, 3)].as_ref())
+        .split(rect)
+    else {
+        return;
+    };
+
+    let mut info = vec![Line::from(
+        Span::raw(format!("{:<9}: {} bytes", "SIZE", bytecode.code.len())).fg(Color::White),
+    )];
+
+    if let Some(metadata) = bytecode.metadata.as_ref() {
+        info.push(Line::from(
+            Span::raw(format!(
+                "{:<9}: {}{}",
+                "COMPILER",
+                metadata.compiler.as_deref().unwrap_or("Unknown"),
+                if metadata.is_experimental {
+                    " (experimental)"
+                } else {
+                    ""
+                }
+            ))
+            .fg(Color::White),
+        ));
+        if let Some(ipfs) = metadata.ipfs.as_ref() {
+            info.push(Line::from(
+                Span::raw(format!("{:<9}: {ipfs}", "IPFS")).fg(Color::White),
+            ));
+        }
+        if let Some(bzzr) = metadata.bzzr.as_ref() {
+            info.push(Line::from(
+                Span::raw(format!("{:<9}: {bzzr}", "SWARM")).fg(Color::White),
+            ));
+        }
+    } else {
+        info.push(Line::from(
+            Span::raw(format!("{:<9}: No metadata", "COMPILER")).fg(Color::White),
+        ));
+    }
+
+    info.push(Line::from(""));
+    info.push(Line::from(
+        Span::raw(format!("Selectors ({})", bytecode.selectors.len())).add_modifier(Modifier::BOLD),
+    ));
+    for (selector, signatures) in bytecode.selectors.iter() {
+        let mut spans = vec![Span::raw(format!("0x{} ", hex::encode(selector))).fg(Color::Cyan)];
+        if signatures.is_empty() {
+            spans.push(Span::raw("Unknown").fg(Color::Gray));
+        } else {
+            spans.push(Span::raw(signatures.join(" | ")).fg(Color::White));
+        }
+        info.push(Line::from(spans));
+    }
+
+    f.render_widget(
+        Paragraph::new(info)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Info")
+                    .padding(Padding::horizontal(1)),
+            )
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: false }),
+        info_rect,
+    );
+
+    let code_block = Block::default()
+        .borders(Borders::ALL)
+        .title(if app.bytecode.is_raw {
+            "Raw (x: Disassembly)"
+        } else {
+            "Disassembly (x: Raw)"
+        })
+        .padding(Padding::horizontal(1));
+    let inner = code_block.inner(code_rect);
+
+    let line_count = if app.bytecode.is_raw {
+        bytecode.code.len().div_ceil(BYTES_PER_ROW)
+    } else {
+        bytecode.instructions.len()
+    };
+    app.bytecode.scroll = app.bytecode.scroll.min(line_count.saturating_sub(1) as u16);
+    app.bytecode.scroll_state = app
+        .bytecode
+        .scroll_state
+        .content_length(line_count as u16)
+        .position(app.bytecode.scroll);
+
+    // Only the visible rows are built since the bytecode may be up to 24KiB.
+    let start = app.bytecode.scroll as usize;
+    let end = (start + inner.height as usize).min(line_count);
+    let lines = if app.bytecode.is_raw {
+        (start..end)
+            .map(|row| {
+                let offset = row * BYTES_PER_ROW;
+                let chunk =
+                    &bytecode.code[offset..(offset + BYTES_PER_ROW).min(bytecode.code.len())];
+                Line::from(vec![
+                    Span::raw(format!("{:06x}  ", offset)).fg(Color::Gray),
+                    Span::raw(hex::encode(chunk)).fg(Color::White),
+                ])
+            })
+            .collect::<Vec<_>>()
+    } else {
+        bytecode.instructions[start..end]
+            .iter()
+            .map(|instruction| {
+                let mut spans = vec![
+                    Span::raw(format!("{:06x}  ", instruction.offset)).fg(Color::Gray),
+                    Span::raw(instruction.name()).fg(if instruction.opcode == 0x5b {
+                        Color::Yellow
+                    } else {
+                        Color::White
+                    }),
+                ];
+                if !instruction.push_data.is_empty() {
+                    spans.push(
+                        Span::raw(format!(" 0x{}", hex::encode(&instruction.push_data)))
+                            .fg(Color::Cyan),
+                    );
+                }
+                Line::from(spans)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(code_block)
+            .alignment(Alignment::Left),
+        code_rect,
+    );
+
+    f.render_stateful_widget(
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(Some("▲"))
+            .end_symbol(Some("▼")),
+        inner,
+        &mut app.bytecode.scroll_state,
+    );
+}
@@ -0,0 +1,131 @@
+use crate::app::App;
+use ethers::core::abi::Function;
+use ratatui::{prelude::*, widgets::*};
+
+const LABEL_WIDTH: usize = 24;
+
+pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, functions: &[Function], rect: Rect) {
+    let [functions_rect, call_rect] = *Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 3), Constraint::Ratio(2, 3)].as_ref())
+        .split(rect)
+    else {
+        return;
+    };
+
+    let items = functions
+        .iter()
+        .map(|function| {
+            ListItem::new(format!(
+                "{}({}) → ({})",
+                function.name,
+                function
+                    .inputs
+                    .iter()
+                    .map(|param| param.kind.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+                function
+                    .outputs
+                    .iter()
+                    .map(|param| param.kind.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Functions")
+                .border_style(if app.contract_call.form.is_none() {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default().fg(Color::White)
+                }),
+        )
+        .style(Style::default().fg(Color::White))
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+
+    f.render_stateful_widget(
+        list,
+        functions_rect,
+        &mut app.contract_call.functions_list_state,
+    );
+
+    let call_block = Block::default()
+        .borders(Borders::ALL)
+        .title("Call")
+        .padding(Padding::horizontal(1))
+        .border_style(if app.contract_call.form.is_some() {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default().fg(Color::White)
+        });
+
+    let mut lines = vec![];
+
+    if let Some(form) = app.contract_call.form.as_ref() {
+        for (i, field) in form.fields.iter().enumerate() {
+            let mut line = Line::from(vec![
+                Span::raw(format!("{:<LABEL_WIDTH$}: ", field.label)).fg(Color::White),
+                Span::raw(field.value.to_owned()).fg(Color::Cyan),
+            ]);
+            if i == form.selected {
+                line.patch_style(Style::default().add_modifier(Modifier::BOLD));
+            }
+            lines.push(line);
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(
+            Span::raw(if form.is_editing {
+                "<esc>/<enter>: Stop editing, <tab>: Next field"
+            } else {
+                "i: Edit, <enter>: Call, <esc>: Close"
+            })
+            .fg(Color::Gray),
+        ));
+
+        if form.is_editing {
+            if let Some(field) = form.fields.get(form.selected) {
+                let inner = call_block.inner(call_rect);
+                f.set_cursor(
+                    inner.x
+                        + (field.label.chars().count().max(LABEL_WIDTH) + 2) as u16
+                        + field.cursor_position as u16,
+                    inner.y + form.selected as u16,
+                );
+            }
+        }
+    } else {
+        lines.push(Line::from(
+            Span::raw("<enter>: Open the selected function").fg(Color::Gray),
+        ));
+    }
+
+    match app.contract_call.result.as_ref() {
+        Some(Ok(outputs)) => {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::raw("Result").add_modifier(Modifier::BOLD)));
+            for output in outputs {
+                lines.push(Line::from(Span::raw(output.to_owned()).fg(Color::White)));
+            }
+        }
+        Some(Err(err)) => {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::raw(err.to_owned()).fg(Color::Red)));
+        }
+        None => {}
+    }
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(call_block)
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: false }),
+        call_rect,
+    );
+}
@@ -0,0 +1,149 @@
+use super::syntax::highlight;
+use crate::{
+    app::App,
+    ethers::source_files::{file_tree, SourceFile},
+};
+use ratatui::{prelude::*, widgets::*};
+
+pub fn render<B: Backend>(
+    f: &mut Frame<B>,
+    app: &mut App,
+    files: &[SourceFile],
+    block: Block,
+    rect: Rect,
+) {
+    let inner = block.inner(rect);
+    f.render_widget(block, rect);
+
+    let rows = file_tree(files);
+    if app.source_code.files_list_state.selected().is_none() {
+        app.source_code.select_file(&rows, true);
+    }
+    let Some(file) = files.get(app.source_code.selected_file_index(&rows)) else {
+        return;
+    };
+
+    let [tree_rect, code_rect] = *Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 4), Constraint::Ratio(3, 4)].as_ref())
+        .split(inner)
+    else {
+        return;
+    };
+
+    let items = rows
+        .iter()
+        .map(|row| {
+            let indent = "  ".repeat(row.depth);
+            if row.file_index.is_some() {
+                ListItem::new(format!("{indent}{}", row.name))
+                    .style(Style::default().fg(Color::White))
+            } else {
+                ListItem::new(format!("{indent}{}", row.name))
+                    .style(Style::default().fg(Color::Gray))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    f.render_stateful_widget(
+        List::new(items)
+            .block(Block::default().borders(Borders::RIGHT).border_style(
+                if app.source_code.is_file_tree_focused {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default().fg(Color::White)
+                },
+            ))
+            .highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(Color::Green),
+            ),
+        tree_rect,
+        &mut app.source_code.files_list_state,
+    );
+
+    let (code_rect, search_rect) = if app.source_code.search.is_some() {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)].as_ref())
+            .split(code_rect);
+        (chunks[0], Some(chunks[1]))
+    } else {
+        (code_rect, None)
+    };
+
+    let query = app.source_code.query();
+    let cache = &mut app.source_code.cache;
+    if cache.file.as_ref() != Some(file) {
+        cache.lines = highlight(&file.content, file.language);
+        cache.file = Some(file.to_owned());
+        cache.query = None;
+        cache.matches = vec![];
+    }
+    if cache.query != query {
+        cache.matches = query.as_ref().map_or(vec![], |query| file.matches(query));
+        cache.query = query;
+    }
+
+    let cache = &app.source_code.cache;
+    let width = cache.lines.len().to_string().len();
+    let scroll = app.source_code_scroll as usize;
+
+    // Only the visible lines are rendered, the paragraph is not scrolled
+    let lines = cache
+        .lines
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(code_rect.height as usize)
+        .map(|(idx, spans)| {
+            let number = Span::raw(format!(" {:>width$}  ", idx + 1));
+            let number = if cache.matches.contains(&idx) {
+                if idx == scroll {
+                    number.fg(Color::Black).bg(Color::Green)
+                } else {
+                    number.fg(Color::Black).bg(Color::Yellow)
+                }
+            } else {
+                number.fg(Color::Gray)
+            };
+            Line::from([vec![number], spans.to_owned()].concat())
+        })
+        .collect::<Vec<_>>();
+    let matches = cache.matches.len();
+
+    app.source_code_scroll_state = app
+        .source_code_scroll_state
+        .content_length(app.source_code.cache.lines.len() as u16);
+
+    f.render_widget(Paragraph::new(lines).alignment(Alignment::Left), code_rect);
+
+    f.render_stateful_widget(
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(Some("▲"))
+            .end_symbol(Some("▼")),
+        code_rect,
+        &mut app.source_code_scroll_state,
+    );
+
+    if let (Some(search), Some(search_rect)) = (app.source_code.search.as_ref(), search_rect) {
+        if let Some(field) = search.fields.first() {
+            f.render_widget(
+                Paragraph::new(Line::from(vec![
+                    Span::raw("/").fg(Color::Green),
+                    Span::raw(field.value.to_owned()).fg(Color::White),
+                    Span::raw(format!("  ({matches} matches, n/N: next/previous)")).fg(Color::Gray),
+                ])),
+                search_rect,
+            );
+            if search.is_editing {
+                f.set_cursor(
+                    search_rect.x + 1 + field.cursor_position as u16,
+                    search_rect.y,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{app::App, ethers::storage::StorageSnapshot};
+use ratatui::{prelude::*, widgets::*};
+
+const LABEL_WIDTH: usize = 17;
+
+pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, has_storage_layout: bool, rect: Rect) {
+    let [query_rect, variables_rect] = *Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)].as_ref())
+        .split(rect)
+    else {
+        return;
+    };
+
+    let query_block = Block::default()
+        .borders(Borders::ALL)
+        .title("Read Storage")
+        .padding(Padding::horizontal(1));
+
+    let form = &app.storage.form;
+    let mut lines = vec![];
+    for (i, field) in form.fields.iter().enumerate() {
+        let mut line = Line::from(vec![
+            Span::raw(format!("{:<LABEL_WIDTH$}: ", field.label)).fg(Color::White),
+            Span::raw(field.value.to_owned()).fg(Color::Cyan),
+        ]);
+        if i == form.selected {
+            line.patch_style(Style::default().add_modifier(Modifier::BOLD));
+        }
+        lines.push(line);
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(
+        Span::raw(if form.is_editing {
+            "<esc>/<enter>: Stop editing, <tab>: Next field"
+        } else {
+            "i: Edit, <enter>: Read, j/k: Scroll"
+        })
+        .fg(Color::Gray),
+    ));
+    lines.push(Line::from(
+        Span::raw("keys: address, uint, bytes32 or string, `type:value` or `[index]`")
+            .fg(Color::Gray),
+    ));
+
+    if form.is_editing {
+        if let Some(field) = form.fields.get(form.selected) {
+            let inner = query_block.inner(query_rect);
+            f.set_cursor(
+                inner.x
+                    + (field.label.chars().count().max(LABEL_WIDTH) + 2) as u16
+                    + field.cursor_position as u16,
+                inner.y + form.selected as u16,
+            );
+        }
+    }
+
+    if let Some(err) = app.storage.error.as_ref() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::raw(err.to_owned()).fg(Color::Red)));
+    }
+
+    // The latest read comes first
+    let reads = app
+        .storage
+        .snapshots
+        .iter()
+        .rev()
+        .filter_map(|snapshot| {
+            snapshot
+                .slot
+                .as_ref()
+                .map(|slot| (snapshot.block_number, slot))
+        })
+        .collect::<Vec<_>>();
+    if !reads.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(
+            Span::raw("History").add_modifier(Modifier::BOLD),
+        ));
+    }
+    for (block_number, (query, slot, value)) in reads {
+        lines.push(Line::from(vec![
+            Span::raw(format!("#{block_number} ")).fg(Color::Gray),
+            Span::raw(query.to_owned()).fg(Color::White),
+        ]));
+        lines.push(Line::from(vec![
+            Span::raw("  slot  ").fg(Color::Gray),
+            Span::raw(format!("{:#x}", slot)).fg(Color::White),
+        ]));
+        lines.push(Line::from(vec![
+            Span::raw("  value ").fg(Color::Gray),
+            Span::raw(format!("{:#x}", value)).fg(Color::Cyan),
+        ]));
+    }
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(query_block)
+            .alignment(Alignment::Left)
+            .scroll((app.storage.scroll, 0))
+            .wrap(Wrap { trim: false }),
+        query_rect,
+    );
+
+    let mut variables = vec![];
+    if !has_storage_layout {
+        variables.push(Line::from(
+            Span::raw("The storage layout is not available for this contract.").fg(Color::Gray),
+        ));
+    } else if let Some(snapshot) = app.storage.snapshots.last() {
+        variables.push(Line::from(
+            Span::raw(format!("Block #{}", snapshot.block_number)).add_modifier(Modifier::BOLD),
+        ));
+        // Values that differ from the previous read at another block are highlighted
+        let previous = app.storage.snapshots.iter().rev().find(|previous| {
+            previous.block_number != snapshot.block_number && !previous.variables.is_empty()
+        });
+        for (label, value) in snapshot.variables.iter() {
+            let mut spans = vec![
+                Span::raw(format!("{:<LABEL_WIDTH$}: ", label)).fg(Color::White),
+                Span::raw(value.to_owned()).fg(Color::Cyan),
+            ];
+            if let Some((previous, previous_value)) = previous
+                .and_then(|previous| find_variable(previous, label).map(|value| (previous, value)))
+            {
+                if previous_value != value {
+                    spans.push(
+                        Span::raw(format!(
+                            " (was {} at #{})",
+                            previous_value, previous.block_number
+                        ))
+                        .fg(Color::Yellow),
+                    );
+                }
+            }
+            variables.push(Line::from(spans));
+        }
+    } else {
+        variables.push(Line::from(
+            Span::raw("<enter>: Read the state variables").fg(Color::Gray),
+        ));
+    }
+
+    f.render_widget(
+        Paragraph::new(variables)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("State Variables")
+                    .padding(Padding::horizontal(1)),
+            )
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: false }),
+        variables_rect,
+    );
+}
+
+fn find_variable<'a>(snapshot: &'a StorageSnapshot, label: &str) -> Option<&'a String> {
+    snapshot
+        .variables
+        .iter()
+        .find(|(previous_label, _)| previous_label == label)
+        .map(|(_, value)| value)
+}
@@ -0,0 +1,214 @@
+use crate::ethers::source_files::SourceLanguage;
+use ratatui::prelude::*;
+
+const SOLIDITY_KEYWORDS: [&str; 62] = [
+    "abstract",
+    "anonymous",
+    "as",
+    "assembly",
+    "break",
+    "catch",
+    "constant",
+    "constructor",
+    "continue",
+    "contract",
+    "delete",
+    "do",
+    "else",
+    "emit",
+    "enum",
+    "error",
+    "event",
+    "external",
+    "fallback",
+    "for",
+    "function",
+    "if",
+    "immutable",
+    "import",
+    "indexed",
+    "interface",
+    "internal",
+    "is",
+    "library",
+    "memory",
+    "modifier",
+    "new",
+    "override",
+    "payable",
+    "pragma",
+    "private",
+    "public",
+    "pure",
+    "receive",
+    "return",
+    "returns",
+    "revert",
+    "require",
+    "storage",
+    "calldata",
+    "struct",
+    "try",
+    "type",
+    "unchecked",
+    "using",
+    "view",
+    "virtual",
+    "while",
+    "true",
+    "false",
+    "this",
+    "super",
+    "let",
+    "switch",
+    "case",
+    "default",
+    "solidity",
+];
+
+const VYPER_KEYWORDS: [&str; 38] = [
+    "and",
+    "as",
+    "assert",
+    "break",
+    "constant",
+    "continue",
+    "def",
+    "elif",
+    "else",
+    "enum",
+    "event",
+    "external",
+    "for",
+    "from",
+    "if",
+    "immutable",
+    "implements",
+    "import",
+    "in",
+    "indexed",
+    "interface",
+    "internal",
+    "log",
+    "nonpayable",
+    "not",
+    "or",
+    "pass",
+    "payable",
+    "public",
+    "pure",
+    "raise",
+    "return",
+    "self",
+    "struct",
+    "view",
+    "True",
+    "False",
+    "range",
+];
+
+const TYPES: [&str; 10] = [
+    "address", "bool", "string", "bytes", "byte", "mapping", "HashMap", "DynArray", "String",
+    "Bytes",
+];
+
+fn is_type(word: &str) -> bool {
+    if TYPES.contains(&word) {
+        return true;
+    }
+    ["uint", "int", "bytes", "fixed", "ufixed", "decimal"]
+        .iter()
+        .any(|prefix| {
+            word.strip_prefix(prefix)
+                .is_some_and(|size| size.chars().all(|c| c.is_ascii_digit() || c == 'x'))
+        })
+}
+
+/// Length in bytes of the leading characters of `rest` matching `is_part`.
+fn token_len(rest: &str, is_part: impl Fn(char) -> bool) -> usize {
+    rest.find(|c: char| !is_part(c)).unwrap_or(rest.len())
+}
+
+/// Length in bytes of the string literal opening `rest`, up to its closing quote.
+fn string_len(rest: &str, quote: char) -> usize {
+    let mut chars = rest.char_indices().skip(1);
+    while let Some((j, c)) = chars.next() {
+        if c == quote {
+            return j + 1;
+        }
+        if c == '\\' {
+            chars.next();
+        }
+    }
+    rest.len()
+}
+
+fn is_identifier(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Highlights source code line by line. Block comments may span several lines.
+pub fn highlight(content: &str, language: SourceLanguage) -> Vec<Vec<Span<'static>>> {
+    let keywords: &[&str] = match language {
+        SourceLanguage::Solidity => &SOLIDITY_KEYWORDS,
+        SourceLanguage::Vyper => &VYPER_KEYWORDS,
+    };
+    let line_comment = match language {
+        SourceLanguage::Solidity => "//",
+        SourceLanguage::Vyper => "#",
+    };
+
+    let mut is_in_block_comment = false;
+    let mut lines = vec![];
+
+    for line in content.lines() {
+        let mut spans = vec![];
+        let mut i = 0;
+
+        while let Some(c) = line[i..].chars().next() {
+            let rest = &line[i..];
+
+            let (len, color) = if is_in_block_comment {
+                let end = rest.find("*/");
+                is_in_block_comment = end.is_none();
+                (end.map_or(rest.len(), |end| end + 2), Color::DarkGray)
+            } else if rest.starts_with(line_comment) {
+                (rest.len(), Color::DarkGray)
+            } else if language == SourceLanguage::Solidity && rest.starts_with("/*") {
+                is_in_block_comment = true;
+                (2, Color::DarkGray)
+            } else if c == '"' || c == '\'' {
+                (string_len(rest, c), Color::Green)
+            } else if c.is_ascii_digit() {
+                (
+                    token_len(rest, |c| c.is_ascii_alphanumeric() || c == '_'),
+                    Color::Yellow,
+                )
+            } else if c.is_alphabetic() || c == '_' || c == '$' {
+                let len = token_len(rest, is_identifier);
+                let word = &rest[..len];
+                let color = if keywords.contains(&word) {
+                    Color::Magenta
+                } else if is_type(word) {
+                    Color::Cyan
+                } else {
+                    Color::White
+                };
+                (len, color)
+            } else {
+                let len = c.len_utf8()
+                    + token_len(&rest[c.len_utf8()..], |c| {
+                        !c.is_alphanumeric() && !matches!(c, '_' | '$' | '"' | '\'' | '/' | '#')
+                    });
+                (len, Color::White)
+            };
+
+            spans.push(Span::raw(rest[..len].to_owned()).fg(color));
+            i += len;
+        }
+
+        lines.push(spans);
+    }
+
+    lines
+}
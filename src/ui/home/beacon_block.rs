@@ -0,0 +1,118 @@
+use crate::{app::App, beacon::BeaconBlock, route::ActiveBlock};
+use ratatui::{prelude::*, widgets::*};
+
+pub fn render<B: Backend>(
+    f: &mut Frame<B>,
+    app: &mut App,
+    beacon_block: Option<BeaconBlock>,
+    rect: Rect,
+) {
+    let border_style = if let ActiveBlock::Main = app.get_current_route().get_active_block() {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    if let Some(beacon_block) = beacon_block {
+        let detail_block = Block::default()
+            .title(format!("Beacon Block #{}", beacon_block.slot))
+            .border_style(border_style)
+            .padding(Padding::new(2, 2, 1, 1))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Plain);
+
+        let mut details = vec![
+            Line::from(
+                Span::raw(format!(
+                    "{:<20}: {} (epoch {})",
+                    "Slot",
+                    beacon_block.slot,
+                    beacon_block.epoch()
+                ))
+                .fg(Color::White),
+            ),
+            Line::from(
+                Span::raw(format!("{:<20}: {:#x}", "Block Root", beacon_block.root))
+                    .fg(Color::White),
+            ),
+            Line::from(vec![
+                Span::raw(format!("{:<20}: ", "Proposer Index")).fg(Color::White),
+                Span::raw(beacon_block.proposer_index.to_string()).fg(Color::Cyan),
+            ]),
+            Line::from(
+                Span::raw(format!(
+                    "{:<20}: {:#x}",
+                    "Parent Root", beacon_block.parent_root
+                ))
+                .fg(Color::White),
+            ),
+            Line::from(
+                Span::raw(format!(
+                    "{:<20}: {:#x}",
+                    "State Root", beacon_block.state_root
+                ))
+                .fg(Color::White),
+            ),
+            Line::from(
+                Span::raw(format!("{:<20}: {}", "Graffiti", beacon_block.graffiti))
+                    .fg(Color::White),
+            ),
+            Line::from(
+                Span::raw(format!(
+                    "{:<20}: {}",
+                    "Attestations", beacon_block.attestation_count
+                ))
+                .fg(Color::White),
+            ),
+        ];
+
+        if let Some((participants, size)) = beacon_block.sync_participation {
+            details.push(Line::from(
+                Span::raw(format!(
+                    "{:<20}: {}/{} ({:.2}%)",
+                    "Sync Participation",
+                    participants,
+                    size,
+                    participants as f64 / size as f64 * 100.0
+                ))
+                .fg(Color::White),
+            ));
+        }
+
+        if let Some(hash) = beacon_block.execution_block_hash {
+            details.push(Line::from(""));
+            details.push(Line::from(vec![
+                Span::raw(format!("{:<20}: ", "Execution Block")).fg(Color::White),
+                Span::raw(format!(
+                    "#{} {:#x}",
+                    beacon_block
+                        .execution_block_number
+                        .map_or("".to_string(), |number| number.to_string()),
+                    hash
+                ))
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+            ]));
+            details.push(Line::from(
+                Span::raw("<enter>: Open the execution block").fg(Color::Gray),
+            ));
+        }
+
+        f.render_widget(
+            Paragraph::new(details)
+                .block(detail_block)
+                .alignment(Alignment::Left)
+                .wrap(Wrap { trim: true }),
+            rect,
+        );
+    } else {
+        let detail_block = Block::default()
+            .title("Beacon Block Not Found")
+            .border_style(border_style)
+            .padding(Padding::new(2, 2, 1, 1))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Plain);
+
+        f.render_widget(detail_block, rect);
+    }
+}
@@ -58,7 +58,7 @@ pub fn render<B: Backend>(
 
         let BlockWithTransactionReceipts {
             block,
-            transaction_receipts: _,
+            transaction_receipts,
         } = block_with_transaction_receipts;
 
         let detail_block = Block::default()
@@ -75,7 +75,13 @@ pub fn render<B: Backend>(
             .border_type(BorderType::Plain);
 
         block_info::render(f, app, &block, block_info_rect);
-        fee_info::render(f, app, &block, fee_info_rect);
+        fee_info::render(
+            f,
+            app,
+            &block,
+            transaction_receipts.as_deref(),
+            fee_info_rect,
+        );
         gas_info::render(f, app, &block, gas_info_rect);
 
         f.render_widget(detail_block, rect);
@@ -2,7 +2,7 @@ use crate::{
     app::{block::SelectableBlockDetailItem, App},
     route::{ActiveBlock, RouteId},
 };
-use ethers::core::types::{Block as EBlock, Transaction};
+use ethers::core::types::{Block as EBlock, Transaction, U64};
 use ratatui::{prelude::*, widgets::*};
 
 pub fn render<B: Backend>(
@@ -23,7 +23,7 @@ pub fn render<B: Backend>(
                 Style::default().fg(Color::White)
             },
         )
-        .padding(Padding::new(2, 2, 2, 0))
+        .padding(Padding::new(2, 2, 1, 0))
         .borders(Borders::BOTTOM)
         .border_type(BorderType::Plain);
 
@@ -32,7 +32,7 @@ pub fn render<B: Backend>(
             Span::raw(format!("{:<20}: {}", "Block Height", block.number.unwrap()))
                 .fg(Color::White),
         ),
-        //format!("{:<20}: {}", "Status", TODO),
+        Line::from(status_spans(app, block.number.unwrap())),
         Line::from(
             Span::raw(format!(
                 "{:<20}: {}",
@@ -41,9 +41,22 @@ pub fn render<B: Backend>(
             ))
             .fg(Color::White),
         ),
-        //format!("{:<20}: Block proposed on slot {}, epoch {}", "Proposed On", TODO),
     ];
 
+    if block
+        .hash
+        .is_some_and(|hash| app.chain_store.is_orphaned(hash))
+    {
+        lines.insert(
+            0,
+            Line::from(
+                Span::raw("Reorged out: this block is no longer part of the canonical chain")
+                    .fg(Color::Red)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        );
+    }
+
     let transactions_span = Span::raw(format!(
         "{:<20}: {} {} transactions",
         "Transactions ",
@@ -92,6 +105,24 @@ pub fn render<B: Backend>(
         ));
     }
 
+    //if past the merge
+    if SelectableBlockDetailItem::has_beacon_block(block) {
+        let beacon_block_span = Span::raw(format!(
+            "{:<20}: ▶ Show the beacon block proposing this block",
+            "Proposed On"
+        ))
+        .fg(Color::White);
+        lines.push(Line::from(
+            if app.block_detail_list_state.selected()
+                == Some(SelectableBlockDetailItem::BeaconBlock.into())
+            {
+                beacon_block_span.add_modifier(Modifier::BOLD)
+            } else {
+                beacon_block_span
+            },
+        ));
+    }
+
     let paragraph = Paragraph::new(lines)
         .block(detail_block.to_owned())
         .alignment(Alignment::Left)
@@ -99,3 +130,13 @@ pub fn render<B: Backend>(
 
     f.render_widget(paragraph, rect);
 }
+
+/// `Status: finalized (N confirmations)` once the statistics are loaded.
+fn status_spans(app: &App, number: U64) -> Vec<Span<'static>> {
+    let mut spans = vec![Span::raw(format!("{:<20}: ", "Status")).fg(Color::White)];
+    if let Some((status, confirmations)) = app.statistics.block_status(number) {
+        spans.push(Span::raw(status.to_string()).fg(status.color()));
+        spans.push(Span::raw(format!(" ({confirmations} confirmations)")).fg(Color::White));
+    }
+    spans
+}
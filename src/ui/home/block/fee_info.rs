@@ -1,14 +1,19 @@
 use crate::{
     app::{block::SelectableBlockDetailItem, App},
+    ethers::transaction::TransactionFee,
     route::{ActiveBlock, RouteId},
 };
-use ethers::core::types::{Block as EBlock, Transaction};
+use ethers::core::{
+    types::{Block as EBlock, Transaction, TransactionReceipt, U256},
+    utils::format_ether,
+};
 use ratatui::{prelude::*, widgets::*};
 
 pub fn render<B: Backend>(
     f: &mut Frame<B>,
     app: &mut App,
     block: &EBlock<Transaction>,
+    transaction_receipts: Option<&[TransactionReceipt]>,
     rect: Rect,
 ) {
     let detail_block = Block::default()
@@ -58,6 +63,57 @@ pub fn render<B: Backend>(
         ),
     ];
 
+    // Fees are summed once all receipts are loaded
+    if let Some(fees) = transaction_receipts
+        .filter(|transaction_receipts| transaction_receipts.len() == block.transactions.len())
+        .and_then(|transaction_receipts| {
+            block
+                .transactions
+                .iter()
+                .map(|transaction| {
+                    transaction_receipts
+                        .iter()
+                        .find(|receipt| receipt.transaction_hash == transaction.hash)
+                        .and_then(|receipt| {
+                            TransactionFee::new(transaction, receipt, block.base_fee_per_gas).ok()
+                        })
+                })
+                .collect::<Option<Vec<_>>>()
+        })
+    {
+        let sum = |f: fn(&TransactionFee) -> U256| {
+            fees.iter().fold(U256::zero(), |acc, fee| acc + f(fee))
+        };
+        details.push(Line::from(
+            Span::raw(format!(
+                "{:<20}: {} ETH",
+                "Transaction Fees",
+                format_ether(sum(TransactionFee::total))
+            ))
+            .fg(Color::White),
+        ));
+        if block.base_fee_per_gas.is_some() {
+            details.push(Line::from(
+                Span::raw(format!(
+                    "{:<20}: {} ETH",
+                    "Priority Fees",
+                    format_ether(sum(|fee| fee.tip.unwrap_or_default()))
+                ))
+                .fg(Color::White),
+            ));
+            details.push(Line::from(
+                Span::raw(format!(
+                    "{:<20}: {} ETH",
+                    "Burnt Fees",
+                    format_ether(sum(
+                        |fee| fee.burnt.unwrap_or_default() + fee.blob.unwrap_or_default()
+                    ))
+                ))
+                .fg(Color::White),
+            ));
+        }
+    }
+
     if let Some(total_difficulty) = block.total_difficulty {
         details.push(Line::from(
             Span::raw(format!("{:<20}: {}", "Total Difficulty", total_difficulty)).fg(Color::White),
@@ -1,5 +1,6 @@
 use crate::{
     app::{block::SelectableBlockDetailItem, App},
+    ethers::transaction::{blob_base_fee, blob_versioned_hashes},
     route::{ActiveBlock, RouteId},
 };
 use ethers::core::{
@@ -56,6 +57,38 @@ pub fn render<B: Backend>(
         ));
     }
 
+    // if past Cancun
+    if let (Some(blob_gas_used), Some(excess_blob_gas)) =
+        (block.blob_gas_used, block.excess_blob_gas)
+    {
+        let blob_count = block
+            .transactions
+            .iter()
+            .map(|transaction| blob_versioned_hashes(transaction).len())
+            .sum::<usize>();
+        details.append(&mut vec![
+            Line::from(
+                Span::raw(format!(
+                    "{:<20}: {} ({} blobs)",
+                    "Blob Gas Used", blob_gas_used, blob_count
+                ))
+                .fg(Color::White),
+            ),
+            Line::from(
+                Span::raw(format!("{:<20}: {}", "Excess Blob Gas", excess_blob_gas))
+                    .fg(Color::White),
+            ),
+            Line::from(
+                Span::raw(format!(
+                    "{:<20}: {} Gwei",
+                    "Blob Base Fee",
+                    format_units(blob_base_fee(block).unwrap_or_default(), "gwei").unwrap()
+                ))
+                .fg(Color::White),
+            ),
+        ]);
+    }
+
     let parent_hash_spans = vec![
         Span::raw(format!("{:<20}: ", "Parent Hash")).fg(Color::White),
         Span::styled(
@@ -64,7 +97,6 @@ pub fn render<B: Backend>(
         ),
     ];
     details.append(&mut vec![
-        //format!("{:<20}: {}", "Burnt Fees", TODO),
         //format!("{:<20}: {}", "Extra Data", TODO),
         Line::from(Span::raw("More Details".to_string()).fg(Color::White)),
         Line::from(
@@ -1,12 +1,16 @@
 use crate::{
-    app::App,
-    ethers::types::{BlockWithTransactionReceipts, ERC20Token},
+    app::{block_transactions::transaction_type_label, filter::FilterTarget, App},
+    ethers::{
+        transaction::{effective_gas_price, TransactionFee},
+        types::{BlockWithTransactionReceipts, ERC20Token},
+    },
     route::{ActiveBlock, RouteId},
+    ui::home::filter_bar,
     widget::Spinner,
 };
 use anyhow::Result;
 use ethers::core::{
-    types::{Transaction, TransactionReceipt, U64},
+    types::{Transaction, TransactionReceipt, U256, U64},
     utils::{format_ether, format_units},
 };
 use ratatui::{prelude::*, widgets::*};
@@ -17,6 +21,11 @@ pub fn render<B: Backend>(
     block_with_transaction_receipts: &BlockWithTransactionReceipts<Transaction>,
     rect: Rect,
 ) -> Result<()> {
+    if app.block_transactions.is_grouped {
+        render_groups(f, app, block_with_transaction_receipts, rect);
+        return Ok(());
+    }
+
     let BlockWithTransactionReceipts {
         block,
         transaction_receipts,
@@ -63,10 +72,14 @@ pub fn render<B: Backend>(
         .style(normal_style)
         .height(1)
         .bottom_margin(1);
-    let items = block
-        .transactions
-        .iter()
-        .enumerate()
+    let items = app
+        .block_transactions
+        .indices(
+            block_with_transaction_receipts,
+            app.filter.get(FilterTarget::BlockTransactions),
+        )
+        .into_iter()
+        .map(|i| (i, &block.transactions[i]))
         .map(|(i, tx)| {
             if let Some(transaction_receipts) = transaction_receipts {
                 create_row(
@@ -76,9 +89,10 @@ pub fn render<B: Backend>(
                     transaction_receipts
                         .iter()
                         .find(|receipt| receipt.transaction_hash == tx.hash),
+                    block.base_fee_per_gas,
                 )
             } else {
-                create_row(i, tx, app, None)
+                create_row(i, tx, app, None, block.base_fee_per_gas)
             }
         })
         .collect::<Vec<_>>();
@@ -120,18 +134,8 @@ pub fn render<B: Backend>(
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Transactions")
-                .fg(
-                    if let ActiveBlock::Main = app.get_current_route().get_active_block() {
-                        if let RouteId::TransactionsOfBlock(_) = app.get_current_route().get_id() {
-                            Color::Green
-                        } else {
-                            Color::White
-                        }
-                    } else {
-                        Color::White
-                    },
-                ),
+                .title(title(app, block_with_transaction_receipts))
+                .fg(border_color(app)),
         )
         .highlight_style(selected_style)
         .widths(&widths);
@@ -140,11 +144,113 @@ pub fn render<B: Backend>(
     Ok(())
 }
 
+fn render_groups<B: Backend>(
+    f: &mut Frame<B>,
+    app: &mut App,
+    block_with_transaction_receipts: &BlockWithTransactionReceipts<Transaction>,
+    rect: Rect,
+) {
+    let header_cells = ["To", "Txns", "Gas Used", "Value (ETH)"]
+        .iter()
+        .map(|h| Cell::from(*h).style(Style::default().add_modifier(Modifier::BOLD)));
+    let header = Row::new(header_cells)
+        .style(Style::default().fg(Color::White))
+        .height(1)
+        .bottom_margin(1);
+
+    let rows = app
+        .block_transactions
+        .groups(
+            block_with_transaction_receipts,
+            app.filter.get(FilterTarget::BlockTransactions),
+        )
+        .into_iter()
+        .map(|group| {
+            let to = match group.to {
+                Some(to) => {
+                    if let Some(token) = ERC20Token::find_by_address(&app.erc20_tokens, to) {
+                        Cell::from(token.ticker).fg(Color::Cyan)
+                    } else if let Some(Some(ens_id)) = app.address2ens_id.get(&to) {
+                        Cell::from(ens_id.to_owned()).fg(Color::Cyan)
+                    } else {
+                        Cell::from(format!("{to:?}")).fg(Color::White)
+                    }
+                }
+                None => Cell::from("ContractDeployment").fg(Color::LightCyan),
+            };
+            Row::new(vec![
+                to,
+                Cell::from(group.count.to_string()).fg(Color::White),
+                Cell::from(group.gas_used.to_string()).fg(Color::White),
+                Cell::from(format_ether(group.value)).fg(Color::White),
+            ])
+            .height(1)
+            .bottom_margin(1)
+        })
+        .collect::<Vec<_>>();
+
+    let table = Table::new(rows)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title(app, block_with_transaction_receipts))
+                .fg(border_color(app)),
+        )
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+        .widths(&[
+            Constraint::Max(44), //To
+            Constraint::Max(6),  //Txns
+            Constraint::Max(12), //Gas Used
+            Constraint::Max(20), //Value (ETH)
+        ]);
+
+    f.render_stateful_widget(table, rect, &mut app.transactions_table_state);
+}
+
+fn title(app: &App, block: &BlockWithTransactionReceipts<Transaction>) -> String {
+    let view = app.block_transactions.to_string();
+    format!(
+        "Transactions{}{} - o/O: Sort, f: Status, t: Type, m: Method, g: Group by To{}",
+        if view.is_empty() {
+            "".to_string()
+        } else {
+            format!(" ({view})")
+        },
+        filter_bar::title(
+            app,
+            FilterTarget::BlockTransactions,
+            app.block_transactions
+                .indices(block, app.filter.get(FilterTarget::BlockTransactions))
+                .len(),
+            block.block.transactions.len()
+        ),
+        if app.block_transactions.has_filters() || app.block_transactions.is_grouped {
+            ", <esc>: Clear"
+        } else {
+            ""
+        }
+    )
+}
+
+fn border_color(app: &App) -> Color {
+    if let ActiveBlock::Main = app.get_current_route().get_active_block() {
+        if let RouteId::TransactionsOfBlock(_) = app.get_current_route().get_id() {
+            Color::Green
+        } else {
+            Color::White
+        }
+    } else {
+        Color::White
+    }
+}
+
 fn create_row<'a>(
     i: usize,
     tx: &Transaction,
     app: &App,
     transaction_receipt: Option<&TransactionReceipt>,
+    base_fee_per_gas: Option<U256>,
 ) -> Vec<Cell<'a>> {
     let mut row = vec![
         Cell::from(format!(" {} ", i + 1)).fg(Color::White),
@@ -158,22 +264,7 @@ fn create_row<'a>(
         } else {
             Cell::from("ContractDeployment").fg(Color::LightCyan)
         },
-        Cell::from(
-            (match tx.transaction_type {
-                Some(i) => {
-                    if i == U64::from(1) {
-                        "AccessList"
-                    } else if i == U64::from(2) {
-                        "EIP-1559"
-                    } else {
-                        "Unknown"
-                    }
-                }
-                None => "Legacy",
-            })
-            .to_string(),
-        )
-        .fg(Color::White),
+        Cell::from(transaction_type_label(tx.transaction_type).to_string()).fg(Color::White),
         Cell::from(
             if let Some(token) = ERC20Token::find_by_address(&app.erc20_tokens, tx.from) {
                 token.ticker.to_string()
@@ -228,10 +319,9 @@ fn create_row<'a>(
     if app.is_toggled {
         row.push(
             Cell::from(if let Some(transaction_receipt) = transaction_receipt {
-                transaction_receipt
-                    .gas_used
-                    .map_or(Spinner::default().to_string(), |gas_used| {
-                        format_ether(tx.gas_price.unwrap() * gas_used)
+                TransactionFee::new(tx, transaction_receipt, base_fee_per_gas)
+                    .map_or(Spinner::default().to_string(), |fee| {
+                        format_ether(fee.total())
                     })
             } else {
                 Spinner::default().to_string()
@@ -240,12 +330,16 @@ fn create_row<'a>(
         );
     }
 
+    // Until the receipt is loaded, the gas price of the transaction object is shown
+    let gas_price = if let Some(transaction_receipt) = transaction_receipt {
+        effective_gas_price(tx, transaction_receipt, base_fee_per_gas)
+    } else {
+        tx.gas_price
+    };
     row.push(
-        Cell::from(
-            format_units(tx.gas_price.unwrap(), "gwei")
-                .unwrap()
-                .to_string(),
-        )
+        Cell::from(gas_price.map_or("".to_owned(), |gas_price| {
+            format_units(gas_price, "gwei").unwrap_or_default()
+        }))
         .fg(Color::White),
     );
 
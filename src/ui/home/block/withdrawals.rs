@@ -1,7 +1,8 @@
 use crate::{
-    app::App,
+    app::{filter::FilterTarget, App},
     ethers::types::BlockWithTransactionReceipts,
     route::{ActiveBlock, RouteId},
+    ui::home::filter_bar,
 };
 use ethers::core::types::Transaction;
 use ratatui::{prelude::*, widgets::*};
@@ -26,10 +27,11 @@ pub fn render<B: Backend>(
         .style(normal_style)
         .height(1)
         .bottom_margin(1);
+    let indices = app.block_withdrawal_indices(block);
     let items = block.withdrawals.as_ref().map_or(vec![], |withdrawals| {
-        withdrawals
+        indices
             .iter()
-            .enumerate()
+            .map(|&i| (i, &withdrawals[i]))
             .map(|(i, withdrawal)| {
                 vec![
                     Cell::from(format!("{}", i + 1)).fg(Color::White),
@@ -51,7 +53,18 @@ pub fn render<B: Backend>(
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Withdrawals")
+                .title(format!(
+                    "Withdrawals{}",
+                    filter_bar::title(
+                        app,
+                        FilterTarget::BlockWithdrawals,
+                        indices.len(),
+                        block
+                            .withdrawals
+                            .as_ref()
+                            .map_or(0, |withdrawals| withdrawals.len())
+                    )
+                ))
                 .fg(
                     if let ActiveBlock::Main = app.get_current_route().get_active_block() {
                         if let RouteId::WithdrawalsOfBlock(_) = app.get_current_route().get_id() {
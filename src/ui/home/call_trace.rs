@@ -0,0 +1,123 @@
+use super::address_label;
+use crate::{
+    app::App,
+    ethers::trace::{CallTrace, CallTraceNode},
+    route::ActiveBlock,
+};
+use ethers::core::utils::format_ether;
+use ratatui::{prelude::*, widgets::*};
+
+pub fn render<B: Backend>(
+    f: &mut Frame<B>,
+    app: &mut App,
+    call_trace: Result<CallTrace, String>,
+    rect: Rect,
+) {
+    match call_trace {
+        Ok(call_trace) => {
+            let nodes = call_trace.root.flatten(&app.collapsed_call_trace_nodes);
+
+            let items = nodes
+                .iter()
+                .map(|(path, node)| {
+                    ListItem::new(Line::from(create_spans(
+                        app,
+                        node,
+                        path.len(),
+                        app.collapsed_call_trace_nodes.contains(path),
+                    )))
+                })
+                .collect::<Vec<_>>();
+
+            let list = List::new(items)
+                .block(
+                    Block::default()
+                        .title(format!("Call Trace {:#x}", call_trace.transaction_hash))
+                        .border_style(
+                            if let ActiveBlock::Main = app.get_current_route().get_active_block() {
+                                Style::default().fg(Color::Green)
+                            } else {
+                                Style::default().fg(Color::White)
+                            },
+                        )
+                        .padding(Padding::new(2, 2, 1, 1))
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Plain),
+                )
+                .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+
+            f.render_stateful_widget(list, rect, &mut app.call_trace_list_state);
+        }
+        Err(err) => {
+            let detail_block = Block::default()
+                .title("Call Trace Not Found")
+                .border_style(
+                    if let ActiveBlock::Main = app.get_current_route().get_active_block() {
+                        Style::default().fg(Color::Green)
+                    } else {
+                        Style::default().fg(Color::White)
+                    },
+                )
+                .padding(Padding::new(2, 2, 1, 1))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain);
+
+            f.render_widget(
+                Paragraph::new(Span::raw(err).fg(Color::Red))
+                    .wrap(Wrap { trim: false })
+                    .block(detail_block),
+                rect,
+            );
+        }
+    }
+}
+
+fn create_spans<'a>(
+    app: &App,
+    node: &CallTraceNode,
+    depth: usize,
+    is_collapsed: bool,
+) -> Vec<Span<'a>> {
+    let mut spans = vec![
+        Span::raw(format!(
+            "{}{} ",
+            "  ".repeat(depth),
+            if node.calls.is_empty() {
+                " "
+            } else if is_collapsed {
+                "▶"
+            } else {
+                "▼"
+            }
+        ))
+        .fg(Color::White),
+        Span::raw(format!("[{}] ", node.call_type)).fg(if node.is_reverted() {
+            Color::Red
+        } else {
+            Color::LightYellow
+        }),
+        Span::raw(format!("{} → ", address_label(app, node.from))).fg(Color::Cyan),
+        Span::raw(node.to.map_or("".to_owned(), |to| address_label(app, to))).fg(Color::Cyan),
+        Span::raw(format!(
+            "::{}",
+            node.method.to_owned().unwrap_or(if node.input.len() >= 4 {
+                format!("0x{}", ethers::utils::hex::encode(&node.input[..4]))
+            } else {
+                "fallback".to_owned()
+            })
+        ))
+        .fg(Color::LightMagenta),
+    ];
+
+    if let Some(value) = node.value.filter(|value| !value.is_zero()) {
+        spans.push(Span::raw(format!("  value: {} ETH", format_ether(value))).fg(Color::White));
+    }
+
+    spans.push(Span::raw(format!("  gas used: {}", node.gas_used)).fg(Color::Gray));
+
+    if let Some(error) = node.error.as_ref() {
+        spans.push(Span::raw(format!("  ✗ {error}")).fg(Color::Red));
+    }
+
+    spans
+}
@@ -0,0 +1,92 @@
+use crate::{
+    app::App,
+    beacon::{Epoch, SlotStatus},
+    route::ActiveBlock,
+};
+use ratatui::{prelude::*, widgets::*};
+
+pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, epoch: Option<Epoch>, rect: Rect) {
+    let border_style = if let ActiveBlock::Main = app.get_current_route().get_active_block() {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    if let Some(epoch) = epoch {
+        let header_cells = ["Slot", "Proposer", "Status", "Block", "Txns", "Gas Used"]
+            .iter()
+            .map(|h| Cell::from(*h).style(Style::default().add_modifier(Modifier::BOLD)));
+        let header = Row::new(header_cells)
+            .style(Style::default().fg(Color::White))
+            .height(1)
+            .bottom_margin(1);
+
+        let rows = epoch.slots.iter().map(|slot| {
+            let (status, color) = match slot.status {
+                SlotStatus::Proposed => ("proposed", Color::Green),
+                SlotStatus::Missed => ("missed", Color::Red),
+                SlotStatus::Orphaned => ("orphaned", Color::Yellow),
+                SlotStatus::Scheduled => ("scheduled", Color::Gray),
+            };
+            let optional = |value: Option<String>| value.unwrap_or("-".to_string());
+            Row::new(vec![
+                Cell::from(slot.slot.to_string()).fg(Color::White),
+                Cell::from(optional(slot.proposer_index.map(|index| index.to_string())))
+                    .fg(Color::Cyan),
+                Cell::from(status).fg(color),
+                Cell::from(optional(
+                    slot.execution_block_number
+                        .map(|number| format!("#{number}")),
+                ))
+                .fg(Color::White),
+                Cell::from(optional(
+                    slot.transaction_count.map(|count| count.to_string()),
+                ))
+                .fg(Color::White),
+                Cell::from(optional(slot.gas_used.map(|gas_used| gas_used.to_string())))
+                    .fg(Color::White),
+            ])
+            .height(1)
+        });
+
+        let missed = epoch
+            .slots
+            .iter()
+            .filter(|slot| matches!(slot.status, SlotStatus::Missed | SlotStatus::Orphaned))
+            .count();
+
+        let table = Table::new(rows)
+            .header(header)
+            .block(
+                Block::default()
+                    .title(format!(
+                        "Epoch {} ({} missed) - <enter>: Beacon Block, b: Block, ←/→: Epoch",
+                        epoch.epoch, missed
+                    ))
+                    .border_style(border_style)
+                    .padding(Padding::new(2, 2, 1, 1))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Plain),
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .widths(&[
+                Constraint::Length(10), //Slot
+                Constraint::Length(10), //Proposer
+                Constraint::Length(10), //Status
+                Constraint::Length(11), //Block
+                Constraint::Length(6),  //Txns
+                Constraint::Length(10), //Gas Used
+            ]);
+
+        f.render_stateful_widget(table, rect, &mut app.epoch_table_state);
+    } else {
+        let detail_block = Block::default()
+            .title("Epoch Not Found")
+            .border_style(border_style)
+            .padding(Padding::new(2, 2, 1, 1))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Plain);
+
+        f.render_widget(detail_block, rect);
+    }
+}
@@ -0,0 +1,35 @@
+use crate::app::{filter::FilterTarget, App};
+use ratatui::{prelude::*, widgets::*};
+
+/// The filter bar opened with `/`, drawn over the navigation bar.
+pub fn render<B: Backend>(f: &mut Frame<B>, app: &App, rect: Rect) {
+    let Some((_, form)) = app.filter.bar.as_ref() else {
+        return;
+    };
+    let Some(field) = form.fields.first() else {
+        return;
+    };
+
+    let mut spans = vec![
+        Span::raw("/").fg(Color::Green),
+        Span::raw(field.value.to_owned()).fg(Color::White),
+    ];
+    match app.filter.error.as_ref() {
+        Some(error) => spans.push(Span::raw(format!("  {error}")).fg(Color::Red)),
+        None => spans
+            .push(Span::raw("  (<enter>: Apply, empty to clear, <esc>: Cancel)").fg(Color::Gray)),
+    }
+    f.render_widget(Clear, rect);
+    f.render_widget(Paragraph::new(Line::from(spans)), rect);
+    if form.is_editing {
+        f.set_cursor(rect.x + 1 + field.cursor_position as u16, rect.y);
+    }
+}
+
+/// The filter of `target` for the title of its table, e.g. ` - /status == fail (3 of 120)`, or
+/// an empty string without a filter.
+pub fn title(app: &App, target: FilterTarget, count: usize, total: usize) -> String {
+    app.filter.get(target).map_or("".to_string(), |filter| {
+        format!(" - /{} ({count} of {total})", filter.text)
+    })
+}
@@ -0,0 +1,91 @@
+use crate::beacon::Finality;
+use chrono::Utc;
+use ratatui::{prelude::*, widgets::*};
+
+pub const HEIGHT: u16 = 7;
+
+pub fn render<B: Backend>(f: &mut Frame<B>, finality: &Finality, rect: Rect) {
+    let block = Block::default()
+        .title(if finality.is_stalled() {
+            Line::from(
+                Span::raw("FINALITY STALLED")
+                    .fg(Color::Red)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            Line::from("FINALITY")
+        })
+        .border_style(Style::default().fg(if finality.is_stalled() {
+            Color::Red
+        } else {
+            Color::White
+        }))
+        .padding(Padding::horizontal(1))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Plain);
+
+    let since_finality = Utc::now()
+        .timestamp()
+        .saturating_sub(finality.finalized_at as i64);
+
+    let lines = vec![
+        Line::from(
+            Span::raw(format!(
+                "{:<12}: epoch {}, slot {}",
+                "Head",
+                finality.current_epoch(),
+                finality.head_slot
+            ))
+            .fg(Color::White),
+        ),
+        Line::from(
+            Span::raw(format!(
+                "{:<12}: epoch {} {:#x}",
+                "Justified", finality.justified.epoch, finality.justified.root
+            ))
+            .fg(Color::White),
+        ),
+        Line::from(
+            Span::raw(format!(
+                "{:<12}: epoch {} {:#x}",
+                "Finalized", finality.finalized.epoch, finality.finalized.root
+            ))
+            .fg(Color::White),
+        ),
+        Line::from(vec![
+            Span::raw(format!("{:<12}: ", "Finalized")).fg(Color::White),
+            Span::raw(format!(
+                "{} mins ago, {} epochs behind",
+                since_finality / 60,
+                finality
+                    .current_epoch()
+                    .saturating_sub(finality.finalized.epoch)
+            ))
+            .fg(if finality.is_stalled() {
+                Color::Red
+            } else {
+                Color::White
+            }),
+        ]),
+        Line::from(
+            Span::raw(format!(
+                "{:<12}: {}",
+                "Sync Part.",
+                finality
+                    .participation
+                    .map_or("-".to_string(), |participation| format!(
+                        "{:.2}%",
+                        participation * 100.0
+                    ))
+            ))
+            .fg(Color::White),
+        ),
+    ];
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .alignment(Alignment::Left),
+        rect,
+    );
+}
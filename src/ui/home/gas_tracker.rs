@@ -0,0 +1,244 @@
+use crate::{
+    app::App,
+    ethers::fee_history::{to_gwei, FeeHistory},
+    route::ActiveBlock,
+};
+use ethers::core::{types::U256, utils::format_units};
+use ratatui::{prelude::*, widgets::*};
+
+pub fn render<B: Backend>(
+    f: &mut Frame<B>,
+    app: &mut App,
+    fee_history: Option<FeeHistory>,
+    rect: Rect,
+) {
+    let border_style = if let ActiveBlock::Main = app.get_current_route().get_active_block() {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    let Some(fee_history) = fee_history else {
+        let detail_block = Block::default()
+            .title("Fee History Not Found")
+            .border_style(border_style)
+            .padding(Padding::new(2, 2, 1, 1))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Plain);
+
+        f.render_widget(detail_block, rect);
+        return;
+    };
+
+    let detail_block = Block::default()
+        .title(format!(
+            "Gas Tracker (#{} - #{}) - r: Refresh",
+            fee_history.oldest_block,
+            fee_history.newest_block()
+        ))
+        .border_style(border_style)
+        .padding(Padding::new(2, 2, 1, 0))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Plain);
+    let inner = detail_block.inner(rect);
+    f.render_widget(detail_block, rect);
+
+    let blob_base_fees = fee_history
+        .base_fee_per_blob_gas
+        .to_owned()
+        .filter(|base_fees| base_fees.iter().any(|base_fee| !base_fee.is_zero()));
+
+    let [summary_rect, base_fee_rect, gas_used_rect, tip_rect, blob_rect] = *Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(4),
+                Constraint::Ratio(1, 3),
+                Constraint::Length(5),
+                Constraint::Ratio(1, 3),
+                Constraint::Length(if blob_base_fees.is_some() { 5 } else { 0 }),
+            ]
+            .as_ref(),
+        )
+        .split(inner)
+    else {
+        return;
+    };
+
+    let gwei = |value: U256| format!("{} Gwei", format_units(value, "gwei").unwrap_or_default());
+    let optional = |value: Option<U256>| value.map_or("-".to_string(), gwei);
+    let tips = fee_history.tip_suggestions();
+    let lines = vec![
+        Line::from(vec![
+            Span::raw(format!("{:<20}: ", "Next Base Fee")).fg(Color::White),
+            Span::raw(optional(fee_history.next_base_fee())).fg(Color::Cyan),
+        ]),
+        Line::from(vec![
+            Span::raw(format!("{:<20}: ", "Next Blob Base Fee")).fg(Color::White),
+            Span::raw(optional(fee_history.next_blob_base_fee())).fg(Color::Cyan),
+        ]),
+        Line::from(vec![
+            Span::raw(format!("{:<20}: ", "Priority Fee")).fg(Color::White),
+            Span::raw(format!("Low {}", optional(tips.map(|tips| tips[0])))).fg(Color::Green),
+            Span::raw(" / ").fg(Color::White),
+            Span::raw(format!("Medium {}", optional(tips.map(|tips| tips[1])))).fg(Color::Yellow),
+            Span::raw(" / ").fg(Color::White),
+            Span::raw(format!("High {}", optional(tips.map(|tips| tips[2])))).fg(Color::Red),
+        ]),
+    ];
+    f.render_widget(Paragraph::new(lines), summary_rect);
+
+    let oldest_block = fee_history.oldest_block.low_u64() as f64;
+    let series = |values: Vec<U256>| {
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| (oldest_block + i as f64, to_gwei(value)))
+            .collect::<Vec<_>>()
+    };
+
+    let base_fees = series(fee_history.base_fee_per_gas.to_owned());
+    render_chart(
+        f,
+        &fee_history,
+        "Base Fee (Gwei)",
+        vec![Dataset::default()
+            .name("base fee")
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Cyan))
+            .data(&base_fees)],
+        &[&base_fees],
+        base_fee_rect,
+    );
+
+    let gas_used_ratios = fee_history
+        .gas_used_ratio
+        .iter()
+        .map(|ratio| (ratio * 100.0).round() as u64)
+        .collect::<Vec<_>>();
+    f.render_widget(
+        Sparkline::default()
+            .block(
+                Block::default()
+                    .title(format!(
+                        "Gas Used Ratio (last {}%, target 50%)",
+                        gas_used_ratios.last().unwrap_or(&0)
+                    ))
+                    .borders(Borders::TOP)
+                    .border_style(Style::default().fg(Color::White)),
+            )
+            .data(&gas_used_ratios)
+            .max(100)
+            .style(Style::default().fg(Color::Magenta)),
+        gas_used_rect,
+    );
+
+    let rewards = fee_history.reward.to_owned().unwrap_or_default();
+    let percentile = |i: usize| {
+        series(
+            rewards
+                .iter()
+                .map(|reward| reward.get(i).copied().unwrap_or_default())
+                .collect(),
+        )
+    };
+    let (low, medium, high) = (percentile(0), percentile(1), percentile(2));
+    let datasets = [
+        ("10th", Color::Green, &low),
+        ("50th", Color::Yellow, &medium),
+        ("90th", Color::Red, &high),
+    ]
+    .into_iter()
+    .map(|(name, color, data)| {
+        Dataset::default()
+            .name(name)
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(color))
+            .data(data)
+    })
+    .collect::<Vec<_>>();
+    render_chart(
+        f,
+        &fee_history,
+        "Priority Fee Percentiles (Gwei)",
+        datasets,
+        &[&low, &medium, &high],
+        tip_rect,
+    );
+
+    if let Some(blob_base_fees) = blob_base_fees {
+        // Blob base fees are often a few wei, so they are plotted in wei
+        let blob_base_fees = blob_base_fees
+            .iter()
+            .map(|base_fee| base_fee.low_u64())
+            .collect::<Vec<_>>();
+        f.render_widget(
+            Sparkline::default()
+                .block(
+                    Block::default()
+                        .title(format!(
+                            "Blob Base Fee (last {} wei)",
+                            blob_base_fees.last().unwrap_or(&0)
+                        ))
+                        .borders(Borders::TOP)
+                        .border_style(Style::default().fg(Color::White)),
+                )
+                .data(&blob_base_fees)
+                .style(Style::default().fg(Color::Blue)),
+            blob_rect,
+        );
+    }
+}
+
+fn render_chart<B: Backend>(
+    f: &mut Frame<B>,
+    fee_history: &FeeHistory,
+    title: &str,
+    datasets: Vec<Dataset>,
+    data: &[&Vec<(f64, f64)>],
+    rect: Rect,
+) {
+    let x_bounds = [
+        fee_history.oldest_block.low_u64() as f64,
+        fee_history.newest_block().low_u64() as f64 + 1.0,
+    ];
+    let y_max = data
+        .iter()
+        .flat_map(|data| data.iter().map(|(_, y)| *y))
+        .fold(0.0, f64::max)
+        .max(f64::EPSILON)
+        * 1.1;
+
+    let chart = Chart::new(datasets)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(Color::White)),
+        )
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds(x_bounds)
+                .labels(
+                    x_bounds
+                        .iter()
+                        .map(|x| Span::raw(format!("#{x}")))
+                        .collect(),
+                ),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, y_max])
+                .labels(vec![
+                    Span::raw("0"),
+                    Span::raw(format!("{:.2}", y_max / 2.0)),
+                    Span::raw(format!("{y_max:.2}")),
+                ]),
+        );
+
+    f.render_widget(chart, rect);
+}
@@ -1,11 +1,12 @@
 use crate::{
-    app::App,
+    app::{filter::FilterTarget, App},
     ethers::types::{BlockWithTransactionReceipts, ERC20Token},
     route::ActiveBlock,
+    ui::home::filter_bar,
     widget::Spinner,
 };
 use chrono::Utc;
-use ethers::core::utils::format_ether;
+use ethers::core::{types::U64, utils::format_ether};
 use ratatui::{prelude::*, widgets::*};
 
 pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, rect: Rect) {
@@ -19,7 +20,16 @@ pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, rect: Rect) {
     };
 
     let latest_blocks_block = Block::default()
-        .title("Latest Blocks")
+        .title(if let Some(reorg) = app.chain_store.reorgs.last() {
+            format!(
+                "Latest Blocks - {} reorgs, last at #{} (depth {})",
+                app.chain_store.reorgs.len(),
+                reorg.new_head,
+                reorg.depth
+            )
+        } else {
+            "Latest Blocks".to_string()
+        })
         .border_style(Style::default().fg(
             if let ActiveBlock::LatestBlocks = app.get_current_route().get_active_block() {
                 Color::Green
@@ -31,7 +41,17 @@ pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, rect: Rect) {
         .border_type(BorderType::Plain);
 
     let latest_transactions_block = Block::default()
-        .title("Latest Transactions")
+        .title(format!(
+            "Latest Transactions{}",
+            app.latest_transactions
+                .as_ref()
+                .map_or("".to_string(), |latest_transactions| filter_bar::title(
+                    app,
+                    FilterTarget::LatestTransactions,
+                    latest_transactions.visible_items().len(),
+                    latest_transactions.items.len()
+                ))
+        ))
         .border_style(Style::default().fg(
             if let ActiveBlock::LatestTransactions = app.get_current_route().get_active_block() {
                 Color::Green
@@ -44,15 +64,16 @@ pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, rect: Rect) {
 
     let header = vec![
         ListItem::new(format!(
-            " {:^12} | {:^11} | {:^12} | {:^13} |",
-            "Block Height", "Hash", "Transactions", "Time"
+            " {:^12} | {:^11} | {:^12} | {:^13} | {:^16} |",
+            "Block Height", "Hash", "Transactions", "Time", "Status"
         )),
         ListItem::new(format!(
-            "{}+{}+{}+{}|",
+            "{}+{}+{}+{}+{}|",
             "-".repeat(14),
             "-".repeat(13),
             "-".repeat(14),
             "-".repeat(15),
+            "-".repeat(18),
         )),
     ];
     let block_list = if let Some(latest_blocks) = app.latest_blocks.as_ref() {
@@ -64,13 +85,28 @@ pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, rect: Rect) {
                 transaction_receipts: _,
             } = block_with_transaction_receipts;
 
-            res.push(ListItem::new(format!(
-                "{:>13} | {:>12} | {:>7} txns | {:>4} secs ago |",
+            let is_reorged = app.chain_store.is_reorged(block.number.unwrap());
+            let item = ListItem::new(format!(
+                "{:>13} | {:>12} | {:>7} txns | {:>4} secs ago | {:<16} |",
                 block.number.unwrap(),
                 block.hash.unwrap(),
                 block.transactions.len(),
-                (Utc::now() - block.time().unwrap()).num_seconds()
-            )));
+                (Utc::now() - block.time().unwrap()).num_seconds(),
+                if is_reorged {
+                    "reorged".to_string()
+                } else {
+                    status_label(app, block.number)
+                }
+            ));
+            // Blocks are colored by their finality status, and heights replaced by a reorg in red
+            res.push(if is_reorged {
+                item.style(Style::default().fg(Color::Red))
+            } else {
+                match app.statistics.block_status(block.number.unwrap()) {
+                    Some((status, _)) => item.style(Style::default().fg(status.color())),
+                    None => item,
+                }
+            });
         }
         List::new(res)
     } else {
@@ -95,22 +131,23 @@ pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, rect: Rect) {
 
     let header = vec![
         ListItem::new(format!(
-            "{:^22} | {:^22} | {:^11} |",
-            "From", "To", "Value (ETH)"
+            "{:^22} | {:^22} | {:^11} | {:^16} |",
+            "From", "To", "Value (ETH)", "Status"
         )),
         ListItem::new(format!(
-            "{}+{}+{}|",
+            "{}+{}+{}+{}|",
             "-".repeat(23),
             "-".repeat(24),
             "-".repeat(13),
+            "-".repeat(18),
         )),
     ];
     let transaction_list = if let Some(latest_transactions) = app.latest_transactions.as_ref() {
         let mut res = header.to_owned();
 
-        for tx in latest_transactions.items.clone() {
-            res.push(ListItem::new(format!(
-                "{:^22} | {:^22} | {:>10} |",
+        for tx in latest_transactions.visible_items() {
+            let item = ListItem::new(format!(
+                "{:^22} | {:^22} | {:>10} | {:<16} |",
                 if let Some(token) =
                     ERC20Token::find_by_address(&app.erc20_tokens, tx.transaction.from)
                 {
@@ -135,8 +172,19 @@ pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, rect: Rect) {
                         format!("{}", to)
                     }
                 }),
-                &format_ether(tx.transaction.value)[..11]
-            )));
+                &format_ether(tx.transaction.value)[..11],
+                status_label(app, tx.transaction.block_number)
+            ));
+            res.push(
+                match tx
+                    .transaction
+                    .block_number
+                    .and_then(|number| app.statistics.block_status(number))
+                {
+                    Some((status, _)) => item.style(Style::default().fg(status.color())),
+                    None => item,
+                },
+            );
         }
         List::new(res)
     } else {
@@ -162,3 +210,13 @@ pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, rect: Rect) {
     f.render_widget(latest_blocks_block, latest_blocks_rect);
     f.render_widget(latest_transactions_block, latest_transactions_rect);
 }
+
+/// `finalized (N)` with the confirmation count of the block `number`, so that the status does not
+/// rely on the row colour alone.
+fn status_label(app: &App, number: Option<U64>) -> String {
+    number
+        .and_then(|number| app.statistics.block_status(number))
+        .map_or("".to_string(), |(status, confirmations)| {
+            format!("{status} ({confirmations})")
+        })
+}
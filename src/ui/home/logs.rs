@@ -0,0 +1,201 @@
+use super::address_label;
+use crate::{
+    app::{filter::FilterTarget, logs::PAGE_SIZE, App},
+    route::ActiveBlock,
+    ui::home::filter_bar,
+};
+use ratatui::{prelude::*, widgets::*};
+
+const LABEL_WIDTH: usize = 14;
+
+pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, rect: Rect) {
+    let [form_rect, result_rect] = *Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(app.logs.form.fields.len() as u16 + 5),
+                Constraint::Min(0),
+            ]
+            .as_ref(),
+        )
+        .split(rect)
+    else {
+        return;
+    };
+
+    let border_style = if let ActiveBlock::Main = app.get_current_route().get_active_block() {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    let form_block = Block::default()
+        .title("Logs")
+        .border_style(border_style)
+        .padding(Padding::horizontal(1))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Plain);
+
+    let form = &app.logs.form;
+    let mut lines = form
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let mut line = Line::from(vec![
+                Span::raw(format!("{:<LABEL_WIDTH$}: ", field.label)).fg(Color::White),
+                Span::raw(field.value.to_owned()).fg(Color::Cyan),
+            ]);
+            if i == form.selected {
+                line.patch_style(Style::default().add_modifier(Modifier::BOLD));
+            }
+            line
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+    lines.push(Line::from(
+        Span::raw(if form.is_editing {
+            "<esc>/<enter>: Stop editing, <tab>: Next field"
+        } else {
+            "i: Edit, <tab>: Next field, t: Next event, <enter>: Query, w: Export CSV"
+        })
+        .fg(Color::Gray),
+    ));
+    lines.push(Line::from(
+        Span::raw("Comma separated values. Topics: address, uint, bytes32 or `string:value`")
+            .fg(Color::Gray),
+    ));
+
+    if form.is_editing {
+        if let Some(field) = form.fields.get(form.selected) {
+            let inner = form_block.inner(form_rect);
+            f.set_cursor(
+                inner.x
+                    + (field.label.chars().count().max(LABEL_WIDTH) + 2) as u16
+                    + field.cursor_position as u16,
+                inner.y + form.selected as u16,
+            );
+        }
+    }
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(form_block)
+            .alignment(Alignment::Left),
+        form_rect,
+    );
+
+    let result_block = Block::default()
+        .border_style(border_style)
+        .padding(Padding::horizontal(1))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Plain);
+
+    let result = match app.logs.result.as_ref() {
+        Some(Ok(result)) => result,
+        Some(Err(err)) => {
+            f.render_widget(
+                Paragraph::new(Line::from(Span::raw(err.to_owned()).fg(Color::Red)))
+                    .block(result_block.title("Result"))
+                    .wrap(Wrap { trim: false }),
+                result_rect,
+            );
+            return;
+        }
+        None => {
+            f.render_widget(result_block.title("Result"), result_rect);
+            return;
+        }
+    };
+
+    let header_cells = ["Block", "Tx Hash", "Index", "Address", "Event"]
+        .iter()
+        .map(|h| Cell::from(*h).style(Style::default().add_modifier(Modifier::BOLD)));
+    let header = Row::new(header_cells)
+        .style(Style::default().fg(Color::White))
+        .height(1)
+        .bottom_margin(1);
+
+    let rows = app
+        .logs
+        .visible_logs()
+        .into_iter()
+        .skip(app.logs.page * PAGE_SIZE)
+        .take(PAGE_SIZE)
+        .map(|entry| {
+            let event = entry.log.event.as_ref().map_or(
+                entry
+                    .log
+                    .topics
+                    .first()
+                    .map_or("".to_string(), |topic0| format!("{:#x}", topic0)),
+                |(name, params)| {
+                    format!(
+                        "{name}({})",
+                        params
+                            .iter()
+                            .map(|(param, value)| format!("{param}: {value}"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                },
+            );
+            Row::new(vec![
+                Cell::from(
+                    entry
+                        .block_number
+                        .map_or("".to_string(), |number| format!("#{number}")),
+                )
+                .fg(Color::White),
+                Cell::from(
+                    entry
+                        .transaction_hash
+                        .map_or("".to_string(), |hash| format!("{}", hash)),
+                )
+                .fg(Color::White),
+                Cell::from(
+                    entry
+                        .log_index
+                        .map_or("".to_string(), |index| index.to_string()),
+                )
+                .fg(Color::White),
+                Cell::from(address_label(app, entry.log.address)).fg(Color::Cyan),
+                Cell::from(event).fg(Color::White),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    let title = format!(
+        "{} logs in #{} - #{} ({} requests){} - page {}/{} - ←/→: Page, <enter>: Transaction{}",
+        result.logs.len(),
+        result.from_block,
+        result.to_block,
+        result.requests,
+        filter_bar::title(
+            app,
+            FilterTarget::Logs,
+            app.logs.logs_len(),
+            result.logs.len()
+        ),
+        app.logs.page + 1,
+        app.logs.page_count(),
+        app.logs
+            .message
+            .as_ref()
+            .map_or("".to_string(), |message| format!(" - {message}"))
+    );
+
+    let table = Table::new(rows)
+        .header(header)
+        .block(result_block.title(title))
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+        .widths(&[
+            Constraint::Length(11),
+            Constraint::Length(13),
+            Constraint::Length(6),
+            Constraint::Length(20),
+            Constraint::Min(0),
+        ]);
+
+    f.render_stateful_widget(table, result_rect, &mut app.logs.table_state);
+}
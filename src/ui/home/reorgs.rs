@@ -0,0 +1,68 @@
+use crate::{app::App, route::ActiveBlock};
+use ratatui::{prelude::*, widgets::*};
+
+pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, rect: Rect) {
+    let header_cells = ["Detected At", "Depth", "Orphaned Block", "Hash", "Txns"]
+        .iter()
+        .map(|h| Cell::from(*h).style(Style::default().add_modifier(Modifier::BOLD)));
+    let header = Row::new(header_cells)
+        .style(Style::default().fg(Color::White))
+        .height(1)
+        .bottom_margin(1);
+
+    let orphaned_blocks = app.chain_store.orphaned_blocks();
+    let rows = orphaned_blocks
+        .iter()
+        .map(|(reorg, block)| {
+            Row::new(vec![
+                Cell::from(format!("#{}", reorg.new_head)).fg(Color::White),
+                Cell::from(reorg.depth.to_string()).fg(Color::White),
+                Cell::from(
+                    block
+                        .block
+                        .number
+                        .map_or("".to_string(), |number| format!("#{number}")),
+                )
+                .fg(Color::Red),
+                Cell::from(
+                    block
+                        .block
+                        .hash
+                        .map_or("".to_string(), |hash| format!("{:#x}", hash)),
+                )
+                .fg(Color::White),
+                Cell::from(block.block.transactions.len().to_string()).fg(Color::White),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    let table = Table::new(rows)
+        .header(header)
+        .block(
+            Block::default()
+                .title(format!(
+                    "Reorgs ({} detected) - <enter>: Orphaned Block",
+                    app.chain_store.reorgs.len()
+                ))
+                .border_style(
+                    if let ActiveBlock::Main = app.get_current_route().get_active_block() {
+                        Style::default().fg(Color::Green)
+                    } else {
+                        Style::default().fg(Color::White)
+                    },
+                )
+                .padding(Padding::new(2, 2, 1, 1))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain),
+        )
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+        .widths(&[
+            Constraint::Length(12),
+            Constraint::Length(6),
+            Constraint::Length(15),
+            Constraint::Length(66),
+            Constraint::Length(6),
+        ]);
+
+    f.render_stateful_widget(table, rect, &mut app.reorgs_table_state);
+}
@@ -0,0 +1,187 @@
+use super::address_label;
+use crate::{app::App, ethers::simulation::SimulationResult, route::ActiveBlock};
+use ethers::core::utils::hex;
+use ratatui::{prelude::*, widgets::*};
+
+const LABEL_WIDTH: usize = 19;
+
+pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, rect: Rect) {
+    let [form_rect, result_rect] = *Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(app.simulation.form.fields.len() as u16 + 4),
+                Constraint::Min(0),
+            ]
+            .as_ref(),
+        )
+        .split(rect)
+    else {
+        return;
+    };
+
+    let border_style = if let ActiveBlock::Main = app.get_current_route().get_active_block() {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    let form_block = Block::default()
+        .title("Simulate")
+        .border_style(border_style)
+        .padding(Padding::horizontal(1))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Plain);
+
+    let form = &app.simulation.form;
+    let mut lines = form
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let mut line = Line::from(vec![
+                Span::raw(format!("{:<LABEL_WIDTH$}: ", field.label)).fg(Color::White),
+                Span::raw(field.value.to_owned()).fg(Color::Cyan),
+            ]);
+            if i == form.selected {
+                line.patch_style(Style::default().add_modifier(Modifier::BOLD));
+            }
+            line
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+    lines.push(Line::from(
+        Span::raw(if form.is_editing {
+            "<esc>/<enter>: Stop editing, <tab>: Next field"
+        } else {
+            "i: Edit, <tab>: Next field, <enter>: Simulate, j/k: Scroll the result"
+        })
+        .fg(Color::Gray),
+    ));
+
+    if form.is_editing {
+        if let Some(field) = form.fields.get(form.selected) {
+            let inner = form_block.inner(form_rect);
+            f.set_cursor(
+                inner.x
+                    + (field.label.chars().count().max(LABEL_WIDTH) + 2) as u16
+                    + field.cursor_position as u16,
+                inner.y + form.selected as u16,
+            );
+        }
+    }
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(form_block)
+            .alignment(Alignment::Left),
+        form_rect,
+    );
+
+    let result_lines = match app.simulation.result.as_ref() {
+        Some(Ok(result)) => create_result_lines(app, result),
+        Some(Err(err)) => vec![Line::from(Span::raw(err.to_owned()).fg(Color::Red))],
+        None => vec![],
+    };
+
+    f.render_widget(
+        Paragraph::new(result_lines)
+            .block(
+                Block::default()
+                    .title("Result")
+                    .border_style(border_style)
+                    .padding(Padding::horizontal(1))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Plain),
+            )
+            .alignment(Alignment::Left)
+            .scroll((app.simulation.result_scroll, 0))
+            .wrap(Wrap { trim: false }),
+        result_rect,
+    );
+}
+
+fn create_result_lines<'a>(app: &App, result: &SimulationResult) -> Vec<Line<'a>> {
+    let mut lines = vec![Line::from(vec![
+        Span::raw(format!("{:<17}: ", "STATUS")).fg(Color::White),
+        if result.is_success {
+            Span::raw("Success").fg(Color::Green)
+        } else {
+            Span::raw("Reverted").fg(Color::Red)
+        },
+    ])];
+
+    if let Some(revert_reason) = result.revert_reason.as_ref() {
+        lines.push(Line::from(vec![
+            Span::raw(format!("{:<17}: ", "REVERT REASON")).fg(Color::White),
+            Span::raw(revert_reason.to_owned()).fg(Color::Red),
+        ]));
+    }
+
+    if let Some(gas_estimate) = result.gas_estimate {
+        lines.push(Line::from(
+            Span::raw(format!("{:<17}: {}", "GAS ESTIMATE", gas_estimate)).fg(Color::White),
+        ));
+    }
+
+    if let Some(gas_used) = result.gas_used {
+        lines.push(Line::from(
+            Span::raw(format!("{:<17}: {}", "GAS USED", gas_used)).fg(Color::White),
+        ));
+    }
+
+    lines.push(Line::from(
+        Span::raw(format!(
+            "{:<17}: 0x{}",
+            "RETURN DATA",
+            hex::encode(&result.return_data)
+        ))
+        .fg(Color::White),
+    ));
+
+    for output in result.decoded_output.iter() {
+        lines.push(Line::from(
+            Span::raw(format!("{:<17}  {}", "", output)).fg(Color::White),
+        ));
+    }
+
+    lines.push(Line::from(""));
+
+    if let Some(logs) = result.logs.as_ref() {
+        lines.push(Line::from(
+            Span::raw(format!("LOGS ({})", logs.len())).add_modifier(Modifier::BOLD),
+        ));
+        for (i, log) in logs.iter().enumerate() {
+            lines.push(Line::from(vec![
+                Span::raw(format!("{:>3}  ", i)).fg(Color::Gray),
+                Span::raw(address_label(app, log.address)).fg(Color::Cyan),
+            ]));
+            if let Some((name, params)) = log.event.as_ref() {
+                lines.push(Line::from(
+                    Span::raw(format!("     {name}")).add_modifier(Modifier::BOLD),
+                ));
+                for (param, value) in params {
+                    lines.push(Line::from(
+                        Span::raw(format!("       {param}: {value}")).fg(Color::White),
+                    ));
+                }
+            } else {
+                for (j, topic) in log.topics.iter().enumerate() {
+                    lines.push(Line::from(
+                        Span::raw(format!("     topic{j}: {:#x}", topic)).fg(Color::White),
+                    ));
+                }
+                lines.push(Line::from(
+                    Span::raw(format!("     data: 0x{}", hex::encode(&log.data))).fg(Color::White),
+                ));
+            }
+        }
+    } else {
+        lines.push(Line::from(
+            Span::raw("Logs are not available because the node does not support debug_traceCall.")
+                .fg(Color::Gray),
+        ));
+    }
+
+    lines
+}
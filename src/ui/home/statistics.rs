@@ -1,137 +1,99 @@
 use crate::{
-    app::{statistics::Statistics, App},
+    app::{dashboard::Tile, App},
     widget::Spinner,
 };
-use anyhow::{bail, Context, Result};
-use ethers::core::utils::format_units;
+use anyhow::{bail, Result};
 use ratatui::{prelude::*, widgets::*};
 
 pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App, rect: Rect) -> Result<()> {
-    let [right_statistics, left_statistics] = *Layout::default()
-        .direction(Direction::Horizontal)
-        .margin(0)
-        .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)].as_ref())
-        .split(rect)
-    else {
-        bail!("Failed to create statistics columns.")
-    };
-
-    let [statistics0, statistics1, statistics2] = *Layout::default()
-        .direction(Direction::Vertical)
-        .margin(0)
-        .constraints(
-            [
-                Constraint::Ratio(1, 3),
-                Constraint::Ratio(1, 3),
-                Constraint::Ratio(1, 3),
-            ]
-            .as_ref(),
-        )
-        .split(right_statistics)
-    else {
-        bail!("Failed to create statistics rows.")
-    };
-
-    let [statistics3, statistics4, statistics5] = *Layout::default()
+    let rows = app.dashboard.tiles.chunks(2).collect::<Vec<_>>();
+    let row_rects = Layout::default()
         .direction(Direction::Vertical)
         .margin(0)
         .constraints(
-            [
-                Constraint::Ratio(1, 3),
-                Constraint::Ratio(1, 3),
-                Constraint::Ratio(1, 3),
-            ]
-            .as_ref(),
+            rows.iter()
+                .map(|row| {
+                    Constraint::Length(row.iter().map(Tile::height).max().unwrap_or_default())
+                })
+                .collect::<Vec<_>>(),
         )
-        .split(left_statistics)
-    else {
-        bail!("Failed to create statistics rows.")
-    };
-
-    let statistic_items = [
-        statistics0,
-        statistics1,
-        statistics2,
-        statistics3,
-        statistics4,
-        statistics5,
-    ];
+        .split(rect);
 
-    let statistic_titles = [
-        "ETHER PRICE",
-        "SUGGESTED BASE FEE",
-        "LAST SAFE BLOCK",
-        "NODE COUNT",
-        "MED GAS PRICE",
-        "LAST FINALIZED BLOCK",
-    ];
-
-    for (i, &statistic_item) in statistic_items.iter().enumerate() {
-        let block = Block::default()
-            .title(statistic_titles[i])
-            .border_style(Style::default().fg(Color::White))
-            .borders(Borders::ALL)
-            .border_type(BorderType::Plain);
-
-        let [text_rect] = *Layout::default()
-            .direction(Direction::Vertical)
+    for (row, row_rect) in rows.iter().zip(row_rects.iter()) {
+        let [left, right] = *Layout::default()
+            .direction(Direction::Horizontal)
             .margin(0)
-            .constraints([Constraint::Length(1)].as_ref())
-            .split(statistic_item)
+            .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)].as_ref())
+            .split(*row_rect)
         else {
-            bail!("Failed to create a rect.")
+            bail!("Failed to create statistics columns.")
         };
 
-        let text = if i == Statistics::ETHUSD_INDEX {
-            if let Some(ethusd) = app.statistics.ethusd.as_ref() {
-                format!("{:.4} USD/ETH", ethusd)
-            } else {
-                Spinner::default().to_string()
-            }
-        } else if i == Statistics::SUGGESTED_BASE_FEE_INDEX {
-            if let Some(suggested_base_fee) = app.statistics.suggested_base_fee {
-                format!("{} Gwei", format_units(suggested_base_fee, "gwei")?)
-            } else {
-                Spinner::default().to_string()
-            }
-        } else if i == Statistics::NODE_COUNT_INDEX {
-            if let Some(node_count) = app.statistics.node_count.as_ref() {
-                format!("{node_count} nodes")
-            } else {
-                Spinner::default().to_string()
-            }
-        } else if i == Statistics::LAST_SAFE_BLOCK_INDEX {
-            if let Some(block) = app.statistics.last_safe_block.as_ref() {
-                format!("#{}", block.number.context("Block Number is None")?)
-            } else {
-                Spinner::default().to_string()
-            }
-        } else if i == Statistics::MED_GAS_PRICE_INDEX {
-            if let Some(med_gas_price) = app.statistics.med_gas_price {
-                format!(
-                    "{} Gwei",
-                    format_units(med_gas_price, "gwei").context("Failed to parse gas price")?
-                )
-            } else {
-                Spinner::default().to_string()
-            }
-        } else if i == Statistics::LAST_FINALIZED_BLOCK_INDEX {
-            if let Some(block) = app.statistics.last_finalized_block.as_ref() {
-                format!("#{}", block.number.context("Block Number is None")?)
-            } else {
-                Spinner::default().to_string()
-            }
-        } else {
-            Spinner::default().to_string()
-        };
+        for (tile, tile_rect) in row.iter().zip([left, right]) {
+            render_tile(f, tile, tile_rect);
+        }
+    }
+    Ok(())
+}
+
+fn render_tile<B: Backend>(f: &mut Frame<B>, tile: &Tile, rect: Rect) {
+    let block = Block::default()
+        .title(tile.title())
+        .border_style(Style::default().fg(Color::White))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Plain);
+    let inner = block.inner(rect);
+    f.render_widget(block, rect);
 
-        let paragraph = Paragraph::new(vec![Line::from(Span::raw(text).fg(Color::White))])
-            .block(block.to_owned())
-            .alignment(Alignment::Right)
-            .wrap(Wrap { trim: true });
+    let [text_rect, sparkline_rect] = *Layout::default()
+        .direction(Direction::Vertical)
+        .margin(0)
+        .constraints([Constraint::Length(1), Constraint::Min(0)].as_ref())
+        .split(inner)
+    else {
+        return;
+    };
 
-        f.render_widget(paragraph, text_rect);
-        f.render_widget(block, statistic_item.to_owned());
+    let (text, color) = match tile.reading.as_ref() {
+        Some(Ok(_)) => (tile.text().unwrap_or_default(), Color::White),
+        Some(Err(_)) => (tile.text().unwrap_or_default(), Color::Red),
+        None => (Spinner::default().to_string(), Color::White),
+    };
+    let paragraph = Paragraph::new(vec![Line::from(Span::raw(text).fg(color))])
+        .alignment(Alignment::Right)
+        .wrap(Wrap { trim: true });
+    f.render_widget(paragraph, text_rect);
+
+    if tile.config.sparkline {
+        // Sparklines take integers, so the values are scaled between their minimum and maximum
+        let min = tile.history.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = tile
+            .history
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+        let data = tile
+            .history
+            .iter()
+            .skip(
+                tile.history
+                    .len()
+                    .saturating_sub(sparkline_rect.width as usize),
+            )
+            .map(|value| {
+                if max > min {
+                    ((value - min) / (max - min) * 99.0) as u64 + 1
+                } else {
+                    1
+                }
+            })
+            .collect::<Vec<_>>();
+        f.render_widget(
+            Sparkline::default()
+                .data(&data)
+                .max(100)
+                .style(Style::default().fg(Color::Cyan)),
+            sparkline_rect,
+        );
     }
-    Ok(())
 }
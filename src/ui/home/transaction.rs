@@ -1,14 +1,18 @@
+use super::address_label;
 use crate::{
     app::transaction::{SelectableInputDataDetailItem, SelectableTransactionDetailItem},
+    beacon::BlobSidecar,
     ethers::{
-        transaction::calculate_transaction_fee,
+        state_diff::StateChanges,
+        transaction::{blob_versioned_hashes, TransactionFee},
         types::{ERC20Token, TransactionWithReceipt},
     },
     route::{ActiveBlock, RouteId},
+    widget::Spinner,
     App,
 };
 use ethers::core::{
-    types::U64,
+    types::{Transaction, TransactionReceipt, I256, U256, U64},
     utils::{format_ether, format_units},
 };
 use ratatui::{prelude::*, widgets::*};
@@ -24,6 +28,10 @@ pub fn render<B: Backend>(
             transaction,
             transaction_receipt,
             decoded_input_data,
+            state_changes,
+            revert_reason,
+            base_fee_per_gas,
+            blob_sidecars,
         } = transaction_with_receipt;
 
         let detail_block = Block::default()
@@ -39,14 +47,6 @@ pub fn render<B: Backend>(
             .borders(Borders::ALL)
             .border_type(BorderType::Plain);
 
-        let [detail_rect, input_data_rect] = *Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Max(15), Constraint::Min(1)].as_ref())
-            .split(rect)
-        else {
-            return;
-        };
-
         let mut details = vec![
             Line::from(
                 Span::raw(format!(
@@ -65,15 +65,51 @@ pub fn render<B: Backend>(
                     }
                 }),
             ]),
+        ];
+
+        if let Some(revert_reason) = revert_reason {
+            details.push(Line::from(vec![
+                Span::raw(format!("{:<17}: ", "Revert Reason")).fg(Color::White),
+                Span::styled(revert_reason, Style::default().fg(Color::Red)),
+            ]));
+        }
+
+        if transaction
+            .block_hash
+            .is_some_and(|hash| app.chain_store.is_orphaned(hash))
+        {
+            details.push(Line::from(
+                Span::raw(
+                    "Reorged out: the block including this transaction is no longer canonical",
+                )
+                .fg(Color::Red)
+                .add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        details.append(&mut vec![
             Line::from(
-                Span::raw(format!(
-                    "{:<17}: #{}",
-                    "Block",
+                [
+                    vec![Span::raw(format!(
+                        "{:<17}: #{} ",
+                        "Block",
+                        transaction
+                            .block_number
+                            .map_or("pending...".to_owned(), |number| number.to_string())
+                    ))
+                    .fg(Color::White)],
                     transaction
                         .block_number
-                        .map_or("pending...".to_owned(), |number| number.to_string())
-                ))
-                .fg(Color::White),
+                        .and_then(|number| app.statistics.block_status(number))
+                        .map_or(vec![], |(status, confirmations)| {
+                            vec![
+                                Span::raw(status.to_string()).fg(status.color()),
+                                Span::raw(format!(" ({confirmations} confirmations)"))
+                                    .fg(Color::White),
+                            ]
+                        }),
+                ]
+                .concat(),
             ),
             Line::from(
                 if app.transaction_detail_list_state.selected()
@@ -199,45 +235,91 @@ pub fn render<B: Backend>(
                 Span::raw(format!(
                     "{:<17}: {}",
                     "Transaction Type",
-                    transaction.transaction_type.map_or("Legacy", |ty| {
-                        if ty == U64::from(1) {
-                            "1"
-                        } else {
-                            "2(EIP-1559)"
-                        }
-                    })
+                    transaction
+                        .transaction_type
+                        .map_or("Legacy".to_owned(), |ty| match ty.as_u64() {
+                            0 => "0 (Legacy)".to_owned(),
+                            1 => "1 (EIP-2930)".to_owned(),
+                            2 => "2 (EIP-1559)".to_owned(),
+                            3 => "3 (EIP-4844)".to_owned(),
+                            ty => ty.to_string(),
+                        })
                 ))
                 .fg(Color::White),
             ),
-            Line::from(Span::raw(format!("{:<17}: {}", "Gas", transaction.gas)).fg(Color::White)),
             Line::from(
-                Span::raw(format!(
-                    "{:<17}: {} ETH",
-                    "Value",
-                    format_ether(transaction.value)
-                ))
-                .fg(Color::White),
+                Span::raw(format!("{:<17}: {}", "Gas Limit", transaction.gas)).fg(Color::White),
             ),
             Line::from(
                 Span::raw(format!(
                     "{:<17}: {} ETH",
-                    "Transaction Fee",
-                    calculate_transaction_fee(&transaction, &transaction_receipt, None)
-                        .unwrap_or("".to_string())
+                    "Value",
+                    format_ether(transaction.value)
                 ))
                 .fg(Color::White),
             ),
-        ];
+        ]);
+
+        let fee = TransactionFee::new(&transaction, &transaction_receipt, base_fee_per_gas).ok();
+        details.push(Line::from(
+            Span::raw(format!(
+                "{:<17}: {} ETH",
+                "Transaction Fee",
+                fee.map_or("".to_owned(), |fee| format_ether(fee.total()))
+            ))
+            .fg(Color::White),
+        ));
+        details.append(&mut create_fee_lines(
+            &transaction,
+            &transaction_receipt,
+            base_fee_per_gas,
+            fee,
+        ));
 
-        if let Some(gas_price) = transaction.gas_price {
+        if let Some(access_list) = transaction
+            .access_list
+            .as_ref()
+            .filter(|access_list| !access_list.0.is_empty())
+        {
+            let access_list_span = Span::raw(format!(
+                "{:<17}: {} {} addresses, {} storage keys",
+                "Access List",
+                if app.is_access_list_expanded {
+                    "▼"
+                } else {
+                    "▶"
+                },
+                access_list.0.len(),
+                access_list
+                    .0
+                    .iter()
+                    .map(|item| item.storage_keys.len())
+                    .sum::<usize>()
+            ))
+            .fg(Color::White);
             details.push(Line::from(
-                Span::raw(format!(
-                    "{:<17}: {} Gwei",
-                    "Gas Price",
-                    format_units(gas_price, "gwei").unwrap()
-                ))
-                .fg(Color::White),
+                if app.transaction_detail_list_state.selected()
+                    == Some(SelectableTransactionDetailItem::AccessList.into())
+                {
+                    access_list_span.add_modifier(Modifier::BOLD)
+                } else {
+                    access_list_span
+                },
             ));
+
+            if app.is_access_list_expanded {
+                for item in access_list.0.iter() {
+                    details.push(Line::from(vec![
+                        Span::raw(format!("{:<19}", "")),
+                        Span::raw(format!("{:#x}", item.address)).fg(Color::Cyan),
+                    ]));
+                    for storage_key in item.storage_keys.iter() {
+                        details.push(Line::from(
+                            Span::raw(format!("{:<21}{:#x}", "", storage_key)).fg(Color::White),
+                        ));
+                    }
+                }
+            }
         }
 
         details.push(Line::from(
@@ -269,6 +351,75 @@ pub fn render<B: Backend>(
             },
         ));
 
+        let call_trace_span = Span::raw(format!("{:<17}: ▶", "Call Trace")).fg(Color::White);
+        details.push(Line::from(
+            if app.transaction_detail_list_state.selected()
+                == Some(SelectableTransactionDetailItem::CallTrace.into())
+            {
+                call_trace_span.add_modifier(Modifier::BOLD)
+            } else {
+                call_trace_span
+            },
+        ));
+
+        let state_changes_span = Span::raw(format!(
+            "{:<17}: {}",
+            "State Changes",
+            if let RouteId::StateChangesOfTransaction(_) = app.get_current_route().get_id() {
+                "▼"
+            } else {
+                "▶"
+            }
+        ))
+        .fg(Color::White);
+        details.push(Line::from(
+            if app.transaction_detail_list_state.selected()
+                == Some(SelectableTransactionDetailItem::StateChanges.into())
+            {
+                state_changes_span.add_modifier(Modifier::BOLD)
+            } else {
+                state_changes_span
+            },
+        ));
+
+        let blob_count = blob_versioned_hashes(&transaction).len();
+        if blob_count > 0 {
+            let blobs_span = Span::raw(format!(
+                "{:<17}: {} {} blobs",
+                "Blobs",
+                if let RouteId::BlobsOfTransaction(_) = app.get_current_route().get_id() {
+                    "▼"
+                } else {
+                    "▶"
+                },
+                blob_count
+            ))
+            .fg(Color::White);
+            details.push(Line::from(
+                if app.transaction_detail_list_state.selected()
+                    == Some(SelectableTransactionDetailItem::Blobs.into())
+                {
+                    blobs_span.add_modifier(Modifier::BOLD)
+                } else {
+                    blobs_span
+                },
+            ));
+        }
+
+        let [detail_rect, input_data_rect] = *Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Max(details.len() as u16 + 4),
+                    Constraint::Min(1),
+                ]
+                .as_ref(),
+            )
+            .split(rect)
+        else {
+            return;
+        };
+
         let input_data = transaction
             .input
             .to_string()
@@ -305,7 +456,87 @@ pub fn render<B: Backend>(
             .decoded_input_data_scroll_state
             .content_length(raw_decoded_input_data.len() as u16);
 
-        if app.is_toggled {
+        if let RouteId::BlobsOfTransaction(_) = app.get_current_route().get_id() {
+            let blob_lines = match blob_sidecars.as_ref() {
+                Some(Ok(blob_sidecars)) => create_blob_lines(blob_sidecars),
+                Some(Err(err)) => vec![Line::from(Span::raw(err.to_owned()).fg(Color::Red))],
+                None => vec![Line::from(
+                    Span::raw(format!("Loading {}", Spinner::default().to_string()))
+                        .fg(Color::White),
+                )],
+            };
+
+            app.blobs_scroll_state = app
+                .blobs_scroll_state
+                .content_length(blob_lines.len() as u16);
+
+            let block = Block::default().padding(Padding::new(1, 1, 0, 1));
+            f.render_widget(
+                Paragraph::new(blob_lines)
+                    .alignment(Alignment::Left)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .green()
+                            .title(Span::styled(
+                                "BLOBS",
+                                Style::default().add_modifier(Modifier::BOLD).green(),
+                            )),
+                    )
+                    .scroll((app.blobs_scroll, 0))
+                    .wrap(Wrap { trim: false }),
+                block.inner(input_data_rect),
+            );
+
+            f.render_stateful_widget(
+                Scrollbar::default()
+                    .orientation(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(Some("▲"))
+                    .end_symbol(Some("▼")),
+                block.inner(input_data_rect),
+                &mut app.blobs_scroll_state,
+            );
+        } else if let RouteId::StateChangesOfTransaction(_) = app.get_current_route().get_id() {
+            let state_changes_lines = match state_changes.as_ref() {
+                Some(Ok(state_changes)) => create_state_changes_lines(app, state_changes),
+                Some(Err(err)) => vec![Line::from(Span::raw(err.to_owned()).fg(Color::Red))],
+                None => vec![Line::from(
+                    Span::raw(format!("Loading {}", Spinner::default().to_string()))
+                        .fg(Color::White),
+                )],
+            };
+
+            app.state_changes_scroll_state = app
+                .state_changes_scroll_state
+                .content_length(state_changes_lines.len() as u16);
+
+            let block = Block::default().padding(Padding::new(1, 1, 0, 1));
+            f.render_widget(
+                Paragraph::new(state_changes_lines)
+                    .alignment(Alignment::Left)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .green()
+                            .title(Span::styled(
+                                "STATE CHANGES",
+                                Style::default().add_modifier(Modifier::BOLD).green(),
+                            )),
+                    )
+                    .scroll((app.state_changes_scroll, 0))
+                    .wrap(Wrap { trim: false }),
+                block.inner(input_data_rect),
+            );
+
+            f.render_stateful_widget(
+                Scrollbar::default()
+                    .orientation(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(Some("▲"))
+                    .end_symbol(Some("▼")),
+                block.inner(input_data_rect),
+                &mut app.state_changes_scroll_state,
+            );
+        } else if app.is_toggled {
             let chunks = Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
@@ -514,3 +745,261 @@ pub fn render<B: Backend>(
         f.render_widget(detail_block, rect);
     }
 }
+
+fn create_state_changes_lines<'a>(app: &App, state_changes: &StateChanges) -> Vec<Line<'a>> {
+    let mut lines = vec![];
+
+    for account in &state_changes.accounts {
+        lines.push(Line::from(vec![
+            Span::raw(format!("{:<9}: ", "Account")).fg(Color::White),
+            Span::raw(address_label(app, account.address)).fg(Color::Cyan),
+        ]));
+
+        if let Some((before, after)) = account.balance {
+            lines.push(Line::from(
+                Span::raw(format!(
+                    "  {:<9}: {} ETH → {} ETH ({} ETH)",
+                    "Balance",
+                    format_ether(before),
+                    format_ether(after),
+                    format_ether(I256::from_raw(after) - I256::from_raw(before))
+                ))
+                .fg(Color::White),
+            ));
+        }
+
+        if let Some((before, after)) = account.nonce {
+            lines.push(Line::from(
+                Span::raw(format!("  {:<9}: {} → {}", "Nonce", before, after)).fg(Color::White),
+            ));
+        }
+
+        if let Some((_, after)) = account.code.as_ref() {
+            lines.push(Line::from(
+                Span::raw(format!(
+                    "  {:<9}: {} bytes deployed",
+                    "Code",
+                    after
+                        .as_ref()
+                        .map_or(0, |code| code.trim_start_matches("0x").len() / 2)
+                ))
+                .fg(Color::White),
+            ));
+        }
+
+        for change in &account.storage {
+            lines.push(Line::from(vec![
+                Span::raw(format!("  {:<9}: ", "Storage")).fg(Color::White),
+                Span::raw(format!("{:#x}", change.slot)).fg(Color::Gray),
+            ]));
+            lines.push(Line::from(vec![
+                Span::raw(format!("  {:<9}  ", "")).fg(Color::White),
+                Span::raw(format!("{:#x}", change.before)).fg(Color::Red),
+                Span::raw(" → ").fg(Color::White),
+                Span::raw(format!("{:#x}", change.after)).fg(Color::Green),
+            ]));
+        }
+    }
+
+    if !state_changes.token_balance_changes.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(
+            Span::raw("Token Balance Changes").add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    for change in &state_changes.token_balance_changes {
+        let token = ERC20Token::find_by_address(&app.erc20_tokens, change.token);
+        let amount = token
+            .as_ref()
+            .and_then(|token| token.decimals)
+            .and_then(|decimals| format_units(change.delta, u32::from(decimals)).ok())
+            .unwrap_or(change.delta.to_string());
+
+        lines.push(Line::from(vec![
+            Span::raw(format!(
+                "  {:<9}: ",
+                token.map_or(format!("{:#x}", change.token), |token| token.ticker)
+            ))
+            .fg(Color::White),
+            Span::raw(address_label(app, change.holder)).fg(Color::Cyan),
+            Span::raw(format!(" {amount}")).fg(if change.delta.is_negative() {
+                Color::Red
+            } else {
+                Color::Green
+            }),
+        ]));
+    }
+
+    lines
+}
+
+/// Fee parameters of the transaction type and the split of the fee between the burnt base fee
+/// and the tip paid to the fee recipient.
+fn create_fee_lines(
+    transaction: &Transaction,
+    transaction_receipt: &TransactionReceipt,
+    base_fee_per_gas: Option<U256>,
+    fee: Option<TransactionFee>,
+) -> Vec<Line<'static>> {
+    let gwei = |value: U256| format_units(value, "gwei").unwrap_or_default();
+    let line = |label: &str, value: String| {
+        Line::from(Span::raw(format!("{:<17}: {}", label, value)).fg(Color::White))
+    };
+    let mut lines = vec![];
+
+    if let Some(fee) = fee {
+        lines.push(line(
+            "Gas Used",
+            format!(
+                "{} ({:.2}%)",
+                fee.gas_used,
+                fee.gas_used.as_u128() as f64 / transaction.gas.as_u128().max(1) as f64 * 100.0
+            ),
+        ));
+        lines.push(line(
+            "Gas Price",
+            format!("{} Gwei", gwei(fee.effective_gas_price)),
+        ));
+    }
+
+    if let Some(max_fee_per_gas) = transaction.max_fee_per_gas {
+        lines.push(line(
+            "Max Fee Per Gas",
+            format!("{} Gwei", gwei(max_fee_per_gas)),
+        ));
+    }
+    if let Some(max_priority_fee_per_gas) = transaction.max_priority_fee_per_gas {
+        lines.push(line(
+            "Max Priority Fee",
+            format!("{} Gwei", gwei(max_priority_fee_per_gas)),
+        ));
+    }
+    if let Some(base_fee_per_gas) = base_fee_per_gas {
+        lines.push(line(
+            "Base Fee Per Gas",
+            format!("{} Gwei", gwei(base_fee_per_gas)),
+        ));
+    }
+
+    if let Some(fee) = fee {
+        if let Some(burnt) = fee.burnt {
+            lines.push(line("Burnt Fee", format!("{} ETH", format_ether(burnt))));
+        }
+        if let Some(tip) = fee.tip {
+            lines.push(line(
+                "Tip",
+                format!("{} ETH (paid to the fee recipient)", format_ether(tip)),
+            ));
+        }
+        if let Some(l1) = fee.l1 {
+            lines.push(line("L1 Data Fee", format!("{} ETH", format_ether(l1))));
+        }
+    }
+
+    // Blob fields are not part of the ethers types yet
+    let max_fee_per_blob_gas = transaction
+        .other
+        .get_deserialized::<U256>("maxFeePerBlobGas")
+        .and_then(Result::ok);
+    let blob_versioned_hashes = blob_versioned_hashes(transaction);
+    let blob_gas_used = transaction_receipt
+        .other
+        .get_deserialized::<U256>("blobGasUsed")
+        .and_then(Result::ok);
+    let blob_gas_price = transaction_receipt
+        .other
+        .get_deserialized::<U256>("blobGasPrice")
+        .and_then(Result::ok);
+
+    if let Some(max_fee_per_blob_gas) = max_fee_per_blob_gas {
+        lines.push(line(
+            "Max Fee Per Blob",
+            format!("{} Gwei", gwei(max_fee_per_blob_gas)),
+        ));
+    }
+    if let Some(blob_gas_used) = blob_gas_used {
+        lines.push(line("Blob Gas Used", blob_gas_used.to_string()));
+    }
+    if let Some(blob_gas_price) = blob_gas_price {
+        lines.push(line(
+            "Blob Gas Price",
+            format!("{} Gwei", gwei(blob_gas_price)),
+        ));
+    }
+    if let Some(blob) = fee.and_then(|fee| fee.blob) {
+        lines.push(line(
+            "Blob Fee",
+            format!("{} ETH (burnt)", format_ether(blob)),
+        ));
+    }
+    for (i, blob_versioned_hash) in blob_versioned_hashes.iter().enumerate() {
+        lines.push(line(
+            if i == 0 { "Blob Hashes" } else { "" },
+            format!("{:#x}", blob_versioned_hash),
+        ));
+    }
+
+    lines
+}
+
+const BLOB_PREVIEW_BYTES: usize = 64;
+
+fn create_blob_lines<'a>(blob_sidecars: &[BlobSidecar]) -> Vec<Line<'a>> {
+    let mut lines = vec![];
+    for blob_sidecar in blob_sidecars {
+        lines.push(Line::from(
+            Span::raw(format!("Blob #{}", blob_sidecar.index)).add_modifier(Modifier::BOLD),
+        ));
+        lines.push(Line::from(vec![
+            Span::raw(format!("{:<17}: ", "Versioned Hash")).fg(Color::White),
+            Span::raw(format!("{:#x}", blob_sidecar.versioned_hash)).fg(Color::Cyan),
+        ]));
+        lines.push(Line::from(
+            Span::raw(format!(
+                "{:<17}: {}",
+                "KZG Commitment", blob_sidecar.kzg_commitment
+            ))
+            .fg(Color::White),
+        ));
+        lines.push(Line::from(
+            Span::raw(format!(
+                "{:<17}: {} bytes ({} bytes without trailing zeros)",
+                "Size",
+                blob_sidecar.blob.len(),
+                blob_sidecar.size()
+            ))
+            .fg(Color::White),
+        ));
+
+        let preview = &blob_sidecar.blob[..blob_sidecar.blob.len().min(BLOB_PREVIEW_BYTES)];
+        for (i, chunk) in preview.chunks(16).enumerate() {
+            lines.push(Line::from(vec![
+                Span::raw(format!("{:<17}  {:04x}  ", "", i * 16)).fg(Color::Gray),
+                Span::raw(format!(
+                    "{:<48}",
+                    chunk
+                        .iter()
+                        .map(|byte| format!("{:02x}", byte))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                ))
+                .fg(Color::White),
+                Span::raw(format!(
+                    " |{}|",
+                    chunk
+                        .iter()
+                        .map(|byte| if byte.is_ascii_graphic() || *byte == b' ' {
+                            *byte as char
+                        } else {
+                            '.'
+                        })
+                        .collect::<String>()
+                ))
+                .fg(Color::Gray),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+    lines
+}
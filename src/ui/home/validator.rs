@@ -0,0 +1,304 @@
+use crate::{app::App, beacon::Validator, ethers::withdrawals::WithdrawalScan, route::ActiveBlock};
+use ethers::core::{types::U256, utils::format_units};
+use ratatui::{prelude::*, widgets::*};
+
+pub fn render<B: Backend>(
+    f: &mut Frame<B>,
+    app: &mut App,
+    validator: Result<Validator, String>,
+    rect: Rect,
+) {
+    let border_style = if let ActiveBlock::Main = app.get_current_route().get_active_block() {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    let validator = match validator {
+        Ok(validator) => validator,
+        Err(err) => {
+            let detail_block = Block::default()
+                .title("Validator Not Found")
+                .border_style(border_style)
+                .padding(Padding::new(2, 2, 1, 1))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain);
+
+            f.render_widget(
+                Paragraph::new(Span::raw(err).fg(Color::Red))
+                    .block(detail_block)
+                    .wrap(Wrap { trim: false }),
+                rect,
+            );
+            return;
+        }
+    };
+
+    let (details_rect, withdrawals_rect) = if validator.withdrawals.is_some() {
+        let [details_rect, withdrawals_rect] = *Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(12)].as_ref())
+            .split(rect)
+        else {
+            return;
+        };
+        (details_rect, Some(withdrawals_rect))
+    } else {
+        (rect, None)
+    };
+
+    let detail_block = Block::default()
+        .title(format!("Validator #{}", validator.index))
+        .border_style(border_style)
+        .padding(Padding::new(2, 2, 1, 1))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Plain);
+
+    let epoch = |epoch: Option<u64>| epoch.map_or("-".to_string(), |epoch| epoch.to_string());
+
+    let mut details = vec![
+        Line::from(
+            Span::raw(format!("{:<20}: {}", "Public Key", validator.pubkey)).fg(Color::White),
+        ),
+        Line::from(vec![
+            Span::raw(format!("{:<20}: ", "Status")).fg(Color::White),
+            Span::raw(validator.status.to_owned()).fg(if validator.status.starts_with("active") {
+                Color::Green
+            } else {
+                Color::Yellow
+            }),
+            if validator.slashed {
+                Span::raw(" (slashed)").fg(Color::Red)
+            } else {
+                Span::raw("")
+            },
+        ]),
+        Line::from(
+            Span::raw(format!(
+                "{:<20}: {} ETH",
+                "Balance",
+                format_gwei(validator.balance)
+            ))
+            .fg(Color::White),
+        ),
+        Line::from(
+            Span::raw(format!(
+                "{:<20}: {} ETH",
+                "Effective Balance",
+                format_gwei(validator.effective_balance)
+            ))
+            .fg(Color::White),
+        ),
+        Line::from(
+            Span::raw(format!(
+                "{:<20}: {}",
+                "Eligible Epoch",
+                epoch(validator.activation_eligibility_epoch)
+            ))
+            .fg(Color::White),
+        ),
+        Line::from(
+            Span::raw(format!(
+                "{:<20}: {}",
+                "Activation Epoch",
+                epoch(validator.activation_epoch)
+            ))
+            .fg(Color::White),
+        ),
+        Line::from(
+            Span::raw(format!(
+                "{:<20}: {}",
+                "Exit Epoch",
+                epoch(validator.exit_epoch)
+            ))
+            .fg(Color::White),
+        ),
+        Line::from(
+            Span::raw(format!(
+                "{:<20}: {}",
+                "Withdrawable Epoch",
+                epoch(validator.withdrawable_epoch)
+            ))
+            .fg(Color::White),
+        ),
+        Line::from(
+            Span::raw(format!(
+                "{:<20}: {:#x}",
+                "Withdrawal Creds", validator.withdrawal_credentials
+            ))
+            .fg(Color::White),
+        ),
+    ];
+
+    if let Some(address) = validator.withdrawal_address() {
+        details.push(Line::from(vec![
+            Span::raw(format!("{:<20}: ", "Withdrawal Address")).fg(Color::White),
+            Span::raw(format!("{:#x}", address))
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+            Span::raw(" <enter>: Open").fg(Color::Gray),
+        ]));
+    }
+
+    details.push(Line::from(""));
+    details.push(Line::from(
+        Span::raw(format!(
+            "Proposals (epochs {}-{})",
+            validator.proposals_from_epoch, validator.current_epoch
+        ))
+        .add_modifier(Modifier::BOLD),
+    ));
+    if validator.proposals.is_empty() {
+        details.push(Line::from(Span::raw("No proposer duties").fg(Color::Gray)));
+    }
+    for proposal in validator.proposals.iter() {
+        details.push(Line::from(vec![
+            Span::raw(format!("  slot {:<12}", proposal.slot)).fg(Color::White),
+            match proposal.is_proposed {
+                Some(true) => Span::raw("proposed").fg(Color::Green),
+                Some(false) => Span::raw("missed").fg(Color::Red),
+                None => Span::raw("scheduled").fg(Color::Gray),
+            },
+        ]));
+    }
+
+    details.push(Line::from(""));
+    details.push(Line::from(
+        Span::raw("Attestation Rewards (Gwei)").add_modifier(Modifier::BOLD),
+    ));
+    if validator.attestation_rewards.is_empty() {
+        details.push(Line::from(
+            Span::raw("Not available on this beacon node").fg(Color::Gray),
+        ));
+    }
+    for reward in validator.attestation_rewards.iter() {
+        let reward_span = |label: &str, reward: i64| {
+            Span::raw(format!("{label} {:<10}", reward)).fg(if reward < 0 {
+                Color::Red
+            } else {
+                Color::White
+            })
+        };
+        details.push(Line::from(vec![
+            Span::raw(format!("  epoch {:<11}", reward.epoch)).fg(Color::White),
+            reward_span("head", reward.head),
+            reward_span("target", reward.target),
+            reward_span("source", reward.source),
+        ]));
+    }
+
+    f.render_widget(
+        Paragraph::new(details)
+            .block(detail_block)
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: true }),
+        details_rect,
+    );
+
+    if let (Some(withdrawals), Some(withdrawals_rect)) =
+        (validator.withdrawals.as_ref(), withdrawals_rect)
+    {
+        render_withdrawals(f, app, withdrawals, border_style, withdrawals_rect);
+    }
+}
+
+/// Withdrawals of the validator in the scanned blocks. `<enter>` opens the withdrawals of the
+/// block of the selected row.
+fn render_withdrawals<B: Backend>(
+    f: &mut Frame<B>,
+    app: &mut App,
+    withdrawals: &Result<WithdrawalScan, String>,
+    border_style: Style,
+    rect: Rect,
+) {
+    let withdrawal_scan = match withdrawals {
+        Ok(withdrawal_scan) => withdrawal_scan,
+        Err(err) => {
+            f.render_widget(
+                Paragraph::new(Span::raw(err.to_owned()).fg(Color::Red))
+                    .block(
+                        Block::default()
+                            .title("Withdrawals")
+                            .border_style(border_style)
+                            .padding(Padding::horizontal(2))
+                            .borders(Borders::ALL)
+                            .border_type(BorderType::Plain),
+                    )
+                    .wrap(Wrap { trim: false }),
+                rect,
+            );
+            return;
+        }
+    };
+
+    let block = Block::default()
+        .title(format!(
+            "Withdrawals (blocks #{} - #{}) - <enter>: Block",
+            withdrawal_scan.from_block, withdrawal_scan.to_block
+        ))
+        .border_style(border_style)
+        .padding(Padding::horizontal(2))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Plain);
+
+    if withdrawal_scan.withdrawals.is_empty() {
+        f.render_widget(
+            Paragraph::new(
+                Span::raw("No withdrawals in these blocks. Raise --withdrawal-blocks to scan more")
+                    .fg(Color::Gray),
+            )
+            .block(block),
+            rect,
+        );
+        return;
+    }
+
+    let rows = withdrawal_scan.withdrawals.iter().map(|scanned| {
+        Row::new(vec![
+            Cell::from(format!("#{}", scanned.block_number)).fg(Color::Cyan),
+            Cell::from(scanned.withdrawal.index.to_string()).fg(Color::White),
+            Cell::from(format_units(scanned.withdrawal.amount, "gwei").unwrap_or_default())
+                .fg(Color::White),
+            if scanned.is_full_exit() {
+                Cell::from("full exit").fg(Color::Yellow)
+            } else {
+                Cell::from("partial").fg(Color::White)
+            },
+        ])
+    });
+
+    let table = Table::new(rows)
+        .header(
+            Row::new(
+                [
+                    "Block",
+                    "Index",
+                    "Amount (ETH)",
+                    if withdrawal_scan.is_estimate {
+                        "Kind (est.)"
+                    } else {
+                        "Kind"
+                    },
+                ]
+                .iter()
+                .map(|h| Cell::from(*h).style(Style::default().add_modifier(Modifier::BOLD))),
+            )
+            .style(Style::default().fg(Color::White))
+            .height(1)
+            .bottom_margin(1),
+        )
+        .block(block)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+        .widths(&[
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Length(22),
+            Constraint::Length(12),
+        ]);
+
+    f.render_stateful_widget(table, rect, &mut app.validator_withdrawals_table_state);
+}
+
+fn format_gwei(gwei: u64) -> String {
+    format_units(U256::from(gwei), "gwei").unwrap_or_default()
+}
@@ -0,0 +1,258 @@
+use crate::{
+    app::{filter::FilterTarget, App},
+    ethers::withdrawals::{WithdrawalScan, WithdrawalTotals},
+    route::ActiveBlock,
+    ui::home::filter_bar,
+};
+use ethers::core::{types::U256, utils::format_units};
+use ratatui::{prelude::*, widgets::*};
+
+pub fn render<B: Backend>(
+    f: &mut Frame<B>,
+    app: &mut App,
+    withdrawal_scan: Option<WithdrawalScan>,
+    rect: Rect,
+) {
+    let border_style = if let ActiveBlock::Main = app.get_current_route().get_active_block() {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    let Some(withdrawal_scan) = withdrawal_scan else {
+        let detail_block = Block::default()
+            .title("Withdrawals Not Found")
+            .border_style(border_style)
+            .padding(Padding::new(2, 2, 1, 1))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Plain);
+
+        f.render_widget(detail_block, rect);
+        return;
+    };
+
+    let total = withdrawal_scan.withdrawals.len();
+    let withdrawal_scan = app.filter_withdrawal_scan(&withdrawal_scan);
+    let filter_title = filter_bar::title(
+        app,
+        FilterTarget::Withdrawals,
+        withdrawal_scan.withdrawals.len(),
+        total,
+    );
+
+    let [summary_rect, tables_rect] = *Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(6), Constraint::Min(0)].as_ref())
+        .split(rect)
+    else {
+        return;
+    };
+
+    let totals = withdrawal_scan.totals();
+    let mut summary = vec![
+        Line::from(
+            Span::raw(format!(
+                "{:<20}: #{} - #{}",
+                "Blocks", withdrawal_scan.from_block, withdrawal_scan.to_block
+            ))
+            .fg(Color::White),
+        ),
+        Line::from(
+            Span::raw(format!(
+                "{:<20}: {} withdrawals, {} ETH",
+                "Partial Skims",
+                totals.partial_count,
+                format_gwei(totals.partial_amount)
+            ))
+            .fg(Color::White),
+        ),
+        Line::from(
+            Span::raw(format!(
+                "{:<20}: {} withdrawals, {} ETH",
+                if withdrawal_scan.is_estimate {
+                    "Full Exits (est.)"
+                } else {
+                    "Full Exits"
+                },
+                totals.exit_count,
+                format_gwei(totals.exit_amount)
+            ))
+            .fg(Color::White),
+        ),
+    ];
+    if !withdrawal_scan.missing_blocks.is_empty() {
+        summary.push(Line::from(
+            Span::raw(format!(
+                "{:<20}: {} blocks could not be fetched ({})",
+                "Missing Blocks",
+                withdrawal_scan.missing_blocks.len(),
+                withdrawal_scan
+                    .missing_blocks
+                    .iter()
+                    .map(|number| format!("#{number}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+            .fg(Color::Red),
+        ));
+    }
+
+    f.render_widget(
+        Paragraph::new(summary)
+            .block(
+                Block::default()
+                    .title(
+                        withdrawal_scan
+                            .address
+                            .map_or("Withdrawals".to_string(), |address| {
+                                format!("Withdrawals to {:#x}", address)
+                            })
+                            + &filter_title,
+                    )
+                    .border_style(border_style)
+                    .padding(Padding::horizontal(2))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Plain),
+            )
+            .alignment(Alignment::Left),
+        summary_rect,
+    );
+
+    let header = |cells: &[&'static str]| {
+        Row::new(
+            cells
+                .iter()
+                .map(|h| Cell::from(*h).style(Style::default().add_modifier(Modifier::BOLD))),
+        )
+        .style(Style::default().fg(Color::White))
+        .height(1)
+        .bottom_margin(1)
+    };
+    let table_block = |title: &'static str| {
+        Block::default()
+            .title(title)
+            .border_style(border_style)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Plain)
+    };
+
+    // Full exits are only estimated from the amounts without the beacon node
+    let (kind_header, exits_header) = if withdrawal_scan.is_estimate {
+        ("Kind (est.)", "Exits (est.)")
+    } else {
+        ("Kind", "Exits")
+    };
+
+    if withdrawal_scan.address.is_some() {
+        let rows = withdrawal_scan.withdrawals.iter().map(|scanned| {
+            Row::new(vec![
+                Cell::from(format!("#{}", scanned.block_number)).fg(Color::White),
+                Cell::from(scanned.withdrawal.index.to_string()).fg(Color::White),
+                Cell::from(scanned.withdrawal.validator_index.to_string()).fg(Color::Cyan),
+                Cell::from(format_gwei(scanned.withdrawal.amount)).fg(Color::White),
+                if scanned.is_full_exit() {
+                    Cell::from("full exit").fg(Color::Yellow)
+                } else {
+                    Cell::from("partial").fg(Color::White)
+                },
+            ])
+        });
+
+        let table = Table::new(rows)
+            .header(header(&[
+                "Block",
+                "Index",
+                "Validator Index",
+                "Amount (ETH)",
+                kind_header,
+            ]))
+            .block(table_block("Received Withdrawals - <enter>: Validator"))
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .widths(&[
+                Constraint::Length(12),
+                Constraint::Length(12),
+                Constraint::Length(16),
+                Constraint::Length(22),
+                Constraint::Length(12),
+            ]);
+
+        f.render_stateful_widget(table, tables_rect, &mut app.withdrawal_scan_table_state);
+    } else {
+        let [validators_rect, addresses_rect] = *Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)].as_ref())
+            .split(tables_rect)
+        else {
+            return;
+        };
+
+        let validators = Table::new(withdrawal_scan.by_validator().into_iter().map(
+            |(validator_index, totals)| {
+                Row::new(
+                    [
+                        vec![Cell::from(validator_index.to_string()).fg(Color::Cyan)],
+                        totals_cells(&totals),
+                    ]
+                    .concat(),
+                )
+            },
+        ))
+        .header(header(&[
+            "Validator",
+            "Partial",
+            exits_header,
+            "Total (ETH)",
+        ]))
+        .block(table_block("By Validator - <enter>: Validator"))
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+        .widths(&[
+            Constraint::Length(10),
+            Constraint::Length(8),
+            Constraint::Length(12),
+            Constraint::Length(22),
+        ]);
+
+        let addresses = Table::new(withdrawal_scan.by_address().into_iter().map(
+            |(address, totals)| {
+                Row::new(
+                    [
+                        vec![Cell::from(format!("{}", address)).fg(Color::Cyan)],
+                        totals_cells(&totals),
+                    ]
+                    .concat(),
+                )
+            },
+        ))
+        .header(header(&["Address", "Partial", exits_header, "Total (ETH)"]))
+        .block(table_block("By Address"))
+        .widths(&[
+            Constraint::Length(14),
+            Constraint::Length(8),
+            Constraint::Length(12),
+            Constraint::Length(22),
+        ]);
+
+        f.render_stateful_widget(
+            validators,
+            validators_rect,
+            &mut app.withdrawal_scan_table_state,
+        );
+        f.render_widget(addresses, addresses_rect);
+    }
+}
+
+fn totals_cells(totals: &WithdrawalTotals) -> Vec<Cell<'static>> {
+    vec![
+        Cell::from(totals.partial_count.to_string()).fg(Color::White),
+        Cell::from(totals.exit_count.to_string()).fg(if totals.exit_count > 0 {
+            Color::Yellow
+        } else {
+            Color::White
+        }),
+        Cell::from(format_gwei(totals.total())).fg(Color::White),
+    ]
+}
+
+fn format_gwei(gwei: U256) -> String {
+    format_units(gwei, "gwei").unwrap_or_default()
+}
@@ -29,6 +29,8 @@ pub struct StatefulList<T> {
     pub state: ListState,
     pub items: Vec<T>,
     pub header_size: usize,
+    /// Indices of the items shown when the list is filtered.
+    pub visible: Option<Vec<usize>>,
 }
 
 impl<T> StatefulList<T> {
@@ -37,13 +39,36 @@ impl<T> StatefulList<T> {
             state: ListState::default(),
             items,
             header_size: 2,
+            visible: None,
         }
     }
 
+    fn len(&self) -> usize {
+        self.visible
+            .as_ref()
+            .map_or(self.items.len(), |visible| visible.len())
+    }
+
+    pub fn visible_items(&self) -> Vec<&T> {
+        match self.visible.as_ref() {
+            Some(visible) => visible.iter().map(|i| &self.items[*i]).collect(),
+            None => self.items.iter().collect(),
+        }
+    }
+
+    /// Shows only the items at `visible`, or all of them with `None`, and clears the selection.
+    pub fn set_visible(&mut self, visible: Option<Vec<usize>>) {
+        self.visible = visible;
+        self.state.select(None);
+    }
+
     pub fn next(&mut self) {
+        if self.len() == 0 {
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
-                if i >= self.items.len() - 1 + self.header_size {
+                if i >= self.len() - 1 + self.header_size {
                     self.header_size
                 } else {
                     i + 1
@@ -55,10 +80,13 @@ impl<T> StatefulList<T> {
     }
 
     pub fn previous(&mut self) {
+        if self.len() == 0 {
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
                 if i <= self.header_size {
-                    self.items.len() - 1 + self.header_size
+                    self.len() - 1 + self.header_size
                 } else {
                     i - 1
                 }
@@ -68,7 +96,107 @@ impl<T> StatefulList<T> {
         self.state.select(Some(i));
     }
 
+    /// Index into `items` of the selected item.
     pub fn get_selected_item_index(&self) -> Option<usize> {
-        self.state.selected().map(|state| state - self.header_size)
+        let i = self.state.selected()? - self.header_size;
+        match self.visible.as_ref() {
+            Some(visible) => visible.get(i).copied(),
+            None => Some(i),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct TextField {
+    pub label: String,
+    pub value: String,
+    /// Position of cursor in the field.
+    pub cursor_position: usize,
+}
+
+impl TextField {
+    pub fn new(label: &str, value: &str) -> Self {
+        Self {
+            label: label.to_owned(),
+            value: value.to_owned(),
+            cursor_position: value.chars().count(),
+        }
+    }
+
+    pub fn move_cursor_left(&mut self) {
+        self.cursor_position = self.cursor_position.saturating_sub(1);
+    }
+
+    pub fn move_cursor_right(&mut self) {
+        self.cursor_position = self
+            .cursor_position
+            .saturating_add(1)
+            .clamp(0, self.value.chars().count());
+    }
+
+    pub fn enter_char(&mut self, new_char: char) {
+        let byte_index = self
+            .value
+            .char_indices()
+            .nth(self.cursor_position)
+            .map_or(self.value.len(), |(i, _)| i);
+        self.value.insert(byte_index, new_char);
+        self.move_cursor_right();
+    }
+
+    pub fn paste(&mut self, data: &str) {
+        for c in data.chars() {
+            self.enter_char(c);
+        }
+    }
+
+    pub fn delete_char(&mut self) {
+        if self.cursor_position != 0 {
+            let before_char_to_delete = self.value.chars().take(self.cursor_position - 1);
+            let after_char_to_delete = self.value.chars().skip(self.cursor_position);
+            self.value = before_char_to_delete.chain(after_char_to_delete).collect();
+            self.move_cursor_left();
+        }
+    }
+}
+
+/// A list of text fields where one field is selected and can be edited at a time.
+#[derive(Clone, Default)]
+pub struct Form {
+    pub fields: Vec<TextField>,
+    pub selected: usize,
+    pub is_editing: bool,
+}
+
+impl Form {
+    pub fn with_fields(fields: Vec<TextField>) -> Self {
+        Self {
+            fields,
+            selected: 0,
+            is_editing: false,
+        }
+    }
+
+    pub fn next(&mut self) {
+        if !self.fields.is_empty() {
+            self.selected = (self.selected + 1) % self.fields.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.fields.is_empty() {
+            self.selected = (self.selected + self.fields.len() - 1) % self.fields.len();
+        }
+    }
+
+    pub fn selected_field_mut(&mut self) -> Option<&mut TextField> {
+        self.fields.get_mut(self.selected)
+    }
+
+    pub fn values(&self) -> Vec<String> {
+        self.fields
+            .iter()
+            .map(|field| field.value.trim().to_owned())
+            .collect()
     }
 }